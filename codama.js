@@ -24,7 +24,7 @@ if (!existsSync("target/idl")) {
 // Generate IDL using shank
 console.log("📝 Generating IDL with shank...");
 try {
-  execSync("shank idl -o target/idl -r solana-program", { stdio: "inherit" });
+  execSync("shank idl -o target/idl -r solana-program -p StaKE6XNKVVhG8Qu9hDJBqCW3eRe7MDGLz17nJZetLT", { stdio: "inherit" });
 } catch (error) {
   console.error("❌ Failed to generate IDL with shank:", error.message);
   process.exit(1);
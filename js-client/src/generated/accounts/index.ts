@@ -7,4 +7,5 @@
  */
 
 export * from './pendingWithdraw';
+export * from './stakerAccount';
 export * from './state';
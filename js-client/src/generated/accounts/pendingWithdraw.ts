@@ -54,22 +54,30 @@ export function getPendingWithdrawDiscriminatorBytes() {
 
 export type PendingWithdraw = {
   discriminator: AccountDiscriminator;
+  stakerIndexed: number;
+  version: number;
   padding1: ReadonlyUint8Array;
   bump: number;
   withdrawIndex: number;
   unstaker: Address;
   withdrawableOrcaAmount: bigint;
   withdrawableTimestamp: bigint;
+  owner: Address;
+  stakerWithdrawIndex: bigint;
   padding2: ReadonlyUint8Array;
 };
 
 export type PendingWithdrawArgs = {
+  stakerIndexed: number;
+  version: number;
   padding1?: ReadonlyUint8Array;
   bump: number;
   withdrawIndex: number;
   unstaker: Address;
   withdrawableOrcaAmount: number | bigint;
   withdrawableTimestamp: number | bigint;
+  owner: Address;
+  stakerWithdrawIndex: number | bigint;
   padding2?: ReadonlyUint8Array;
 };
 
@@ -77,54 +85,27 @@ export function getPendingWithdrawEncoder(): FixedSizeEncoder<PendingWithdrawArg
   return transformEncoder(
     getStructEncoder([
       ['discriminator', getAccountDiscriminatorEncoder()],
-      ['padding1', fixEncoderSize(getBytesEncoder(), 5)],
+      ['stakerIndexed', getU8Encoder()],
+      ['version', getU8Encoder()],
+      ['padding1', fixEncoderSize(getBytesEncoder(), 3)],
       ['bump', getU8Encoder()],
       ['withdrawIndex', getU8Encoder()],
       ['unstaker', getAddressEncoder()],
       ['withdrawableOrcaAmount', getU64Encoder()],
       ['withdrawableTimestamp', getI64Encoder()],
-      ['padding2', fixEncoderSize(getBytesEncoder(), 968)],
+      ['owner', getAddressEncoder()],
+      ['stakerWithdrawIndex', getU64Encoder()],
+      ['padding2', fixEncoderSize(getBytesEncoder(), 32)],
     ]),
     (value) => ({
       ...value,
       discriminator: PENDING_WITHDRAW_DISCRIMINATOR,
-      padding1: value.padding1 ?? new Uint8Array([0, 0, 0, 0, 0]),
+      padding1: value.padding1 ?? new Uint8Array([0, 0, 0]),
       padding2:
         value.padding2 ??
         new Uint8Array([
           0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0,
+          0, 0,
         ]),
     })
   );
@@ -133,13 +114,17 @@ export function getPendingWithdrawEncoder(): FixedSizeEncoder<PendingWithdrawArg
 export function getPendingWithdrawDecoder(): FixedSizeDecoder<PendingWithdraw> {
   return getStructDecoder([
     ['discriminator', getAccountDiscriminatorDecoder()],
-    ['padding1', fixDecoderSize(getBytesDecoder(), 5)],
+    ['stakerIndexed', getU8Decoder()],
+    ['version', getU8Decoder()],
+    ['padding1', fixDecoderSize(getBytesDecoder(), 3)],
     ['bump', getU8Decoder()],
     ['withdrawIndex', getU8Decoder()],
     ['unstaker', getAddressDecoder()],
     ['withdrawableOrcaAmount', getU64Decoder()],
     ['withdrawableTimestamp', getI64Decoder()],
-    ['padding2', fixDecoderSize(getBytesDecoder(), 968)],
+    ['owner', getAddressDecoder()],
+    ['stakerWithdrawIndex', getU64Decoder()],
+    ['padding2', fixDecoderSize(getBytesDecoder(), 32)],
   ]);
 }
 
@@ -201,5 +186,5 @@ export async function fetchAllMaybePendingWithdraw(
 }
 
 export function getPendingWithdrawSize(): number {
-  return 1024;
+  return 128;
 }
@@ -23,6 +23,8 @@ import {
   getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -54,22 +56,64 @@ export function getStateDiscriminatorBytes() {
 
 export type State = {
   discriminator: AccountDiscriminator;
+  version: number;
   padding1: ReadonlyUint8Array;
   bump: number;
   vaultBump: number;
   escrowedOrcaAmount: bigint;
   coolDownPeriodS: bigint;
   updateAuthority: Address;
+  instantUnstakeFeeBps: number;
+  pauseFlags: number;
+  pendingUpdateAuthority: Address;
+  padding3: ReadonlyUint8Array;
+  pendingCoolDownPeriodS: bigint;
+  pendingConfigActivationTimestamp: bigint;
+  vestingRewardsAmount: bigint;
+  vestingStartTimestamp: bigint;
+  vestingEndTimestamp: bigint;
+  rewardsAuthority: Address;
+  totalRewardsDeposited: bigint;
+  treasury: Address;
+  feeCheckpointOrcaAmount: bigint;
+  feeCheckpointXorcaSupply: bigint;
+  performanceFeeBps: number;
+  padding4: ReadonlyUint8Array;
+  maxVaultOrca: bigint;
+  maxStakePerTx: bigint;
+  minStakeOrca: bigint;
+  minUnstakeXorca: bigint;
   padding2: ReadonlyUint8Array;
 };
 
 export type StateArgs = {
+  version: number;
   padding1?: ReadonlyUint8Array;
   bump: number;
   vaultBump: number;
   escrowedOrcaAmount: number | bigint;
   coolDownPeriodS: number | bigint;
   updateAuthority: Address;
+  instantUnstakeFeeBps: number;
+  pauseFlags: number;
+  pendingUpdateAuthority: Address;
+  padding3?: ReadonlyUint8Array;
+  pendingCoolDownPeriodS: number | bigint;
+  pendingConfigActivationTimestamp: number | bigint;
+  vestingRewardsAmount: number | bigint;
+  vestingStartTimestamp: number | bigint;
+  vestingEndTimestamp: number | bigint;
+  rewardsAuthority: Address;
+  totalRewardsDeposited: number | bigint;
+  treasury: Address;
+  feeCheckpointOrcaAmount: number | bigint;
+  feeCheckpointXorcaSupply: number | bigint;
+  performanceFeeBps: number;
+  padding4?: ReadonlyUint8Array;
+  maxVaultOrca: number | bigint;
+  maxStakePerTx: number | bigint;
+  minStakeOrca: number | bigint;
+  minUnstakeXorca: number | bigint;
   padding2?: ReadonlyUint8Array;
 };
 
@@ -77,18 +121,41 @@ export function getStateEncoder(): FixedSizeEncoder<StateArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', getAccountDiscriminatorEncoder()],
-      ['padding1', fixEncoderSize(getBytesEncoder(), 5)],
+      ['version', getU8Encoder()],
+      ['padding1', fixEncoderSize(getBytesEncoder(), 4)],
       ['bump', getU8Encoder()],
       ['vaultBump', getU8Encoder()],
       ['escrowedOrcaAmount', getU64Encoder()],
       ['coolDownPeriodS', getI64Encoder()],
       ['updateAuthority', getAddressEncoder()],
-      ['padding2', fixEncoderSize(getBytesEncoder(), 1992)],
+      ['instantUnstakeFeeBps', getU16Encoder()],
+      ['pauseFlags', getU16Encoder()],
+      ['pendingUpdateAuthority', getAddressEncoder()],
+      ['padding3', fixEncoderSize(getBytesEncoder(), 4)],
+      ['pendingCoolDownPeriodS', getI64Encoder()],
+      ['pendingConfigActivationTimestamp', getI64Encoder()],
+      ['vestingRewardsAmount', getU64Encoder()],
+      ['vestingStartTimestamp', getI64Encoder()],
+      ['vestingEndTimestamp', getI64Encoder()],
+      ['rewardsAuthority', getAddressEncoder()],
+      ['totalRewardsDeposited', getU64Encoder()],
+      ['treasury', getAddressEncoder()],
+      ['feeCheckpointOrcaAmount', getU64Encoder()],
+      ['feeCheckpointXorcaSupply', getU64Encoder()],
+      ['performanceFeeBps', getU16Encoder()],
+      ['padding4', fixEncoderSize(getBytesEncoder(), 6)],
+      ['maxVaultOrca', getU64Encoder()],
+      ['maxStakePerTx', getU64Encoder()],
+      ['minStakeOrca', getU64Encoder()],
+      ['minUnstakeXorca', getU64Encoder()],
+      ['padding2', fixEncoderSize(getBytesEncoder(), 1784)],
     ]),
     (value) => ({
       ...value,
       discriminator: STATE_DISCRIMINATOR,
-      padding1: value.padding1 ?? new Uint8Array([0, 0, 0, 0, 0]),
+      padding1: value.padding1 ?? new Uint8Array([0, 0, 0, 0]),
+      padding3: value.padding3 ?? new Uint8Array([0, 0, 0, 0]),
+      padding4: value.padding4 ?? new Uint8Array([0, 0, 0, 0, 0, 0]),
       padding2:
         value.padding2 ??
         new Uint8Array([
@@ -151,14 +218,7 @@ export function getStateEncoder(): FixedSizeEncoder<StateArgs> {
           0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
           0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
           0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+          0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ]),
     })
   );
@@ -167,13 +227,34 @@ export function getStateEncoder(): FixedSizeEncoder<StateArgs> {
 export function getStateDecoder(): FixedSizeDecoder<State> {
   return getStructDecoder([
     ['discriminator', getAccountDiscriminatorDecoder()],
-    ['padding1', fixDecoderSize(getBytesDecoder(), 5)],
+    ['version', getU8Decoder()],
+    ['padding1', fixDecoderSize(getBytesDecoder(), 4)],
     ['bump', getU8Decoder()],
     ['vaultBump', getU8Decoder()],
     ['escrowedOrcaAmount', getU64Decoder()],
     ['coolDownPeriodS', getI64Decoder()],
     ['updateAuthority', getAddressDecoder()],
-    ['padding2', fixDecoderSize(getBytesDecoder(), 1992)],
+    ['instantUnstakeFeeBps', getU16Decoder()],
+    ['pauseFlags', getU16Decoder()],
+    ['pendingUpdateAuthority', getAddressDecoder()],
+    ['padding3', fixDecoderSize(getBytesDecoder(), 4)],
+    ['pendingCoolDownPeriodS', getI64Decoder()],
+    ['pendingConfigActivationTimestamp', getI64Decoder()],
+    ['vestingRewardsAmount', getU64Decoder()],
+    ['vestingStartTimestamp', getI64Decoder()],
+    ['vestingEndTimestamp', getI64Decoder()],
+    ['rewardsAuthority', getAddressDecoder()],
+    ['totalRewardsDeposited', getU64Decoder()],
+    ['treasury', getAddressDecoder()],
+    ['feeCheckpointOrcaAmount', getU64Decoder()],
+    ['feeCheckpointXorcaSupply', getU64Decoder()],
+    ['performanceFeeBps', getU16Decoder()],
+    ['padding4', fixDecoderSize(getBytesDecoder(), 6)],
+    ['maxVaultOrca', getU64Decoder()],
+    ['maxStakePerTx', getU64Decoder()],
+    ['minStakeOrca', getU64Decoder()],
+    ['minUnstakeXorca', getU64Decoder()],
+    ['padding2', fixDecoderSize(getBytesDecoder(), 1784)],
   ]);
 }
 
@@ -40,7 +40,7 @@ export const XORCA_STAKING_PROGRAM_ERROR__STATE_ACCOUNT_ALREADY_INITIALIZED = 0x
 export const XORCA_STAKING_PROGRAM_ERROR__COOL_DOWN_PERIOD_STILL_ACTIVE = 0x177b; // 6011
 /** EmitEventError: Event serialization failed */
 export const XORCA_STAKING_PROGRAM_ERROR__EMIT_EVENT_ERROR = 0x177c; // 6012
-/** InvalidCoolDownPeriod: Invalid cooldown period: must be non-negative */
+/** InvalidCoolDownPeriod: Invalid cooldown period: must be non-negative and not exceed the maximum */
 export const XORCA_STAKING_PROGRAM_ERROR__INVALID_COOL_DOWN_PERIOD = 0x177d; // 6013
 /** InsufficientStakeAmount: Stake amount too small to mint any xORCA */
 export const XORCA_STAKING_PROGRAM_ERROR__INSUFFICIENT_STAKE_AMOUNT = 0x177e; // 6014
@@ -54,11 +54,52 @@ export const XORCA_STAKING_PROGRAM_ERROR__COOL_DOWN_OVERFLOW = 0x1781; // 6017
 export const XORCA_STAKING_PROGRAM_ERROR__INSUFFICIENT_VAULT_BACKING = 0x1782; // 6018
 /** UnauthorizedDeployerAccess: Unauthorized deployer access */
 export const XORCA_STAKING_PROGRAM_ERROR__UNAUTHORIZED_DEPLOYER_ACCESS = 0x1783; // 6019
+/** InvalidInstantUnstakeFee: Invalid instant unstake fee: must not exceed 10000 bps */
+export const XORCA_STAKING_PROGRAM_ERROR__INVALID_INSTANT_UNSTAKE_FEE = 0x1784; // 6020
+/** InvalidWithdrawAmount: Invalid withdraw amount: must be non-zero and not exceed the pending withdraw */
+export const XORCA_STAKING_PROGRAM_ERROR__INVALID_WITHDRAW_AMOUNT = 0x1785; // 6021
+/** SlippageExceeded: Slippage exceeded: output is below the requested minimum */
+export const XORCA_STAKING_PROGRAM_ERROR__SLIPPAGE_EXCEEDED = 0x1786; // 6022
+/** DeadlineExceeded: Transaction deadline exceeded */
+export const XORCA_STAKING_PROGRAM_ERROR__DEADLINE_EXCEEDED = 0x1787; // 6023
+/** ProgramPaused: Program is paused for this instruction */
+export const XORCA_STAKING_PROGRAM_ERROR__PROGRAM_PAUSED = 0x1788; // 6024
+/** NoPendingConfig: No pending config change to apply */
+export const XORCA_STAKING_PROGRAM_ERROR__NO_PENDING_CONFIG = 0x1789; // 6025
+/** ConfigTimelockActive: Pending config change is still timelocked */
+export const XORCA_STAKING_PROGRAM_ERROR__CONFIG_TIMELOCK_ACTIVE = 0x178a; // 6026
+/** InvalidVestingPeriod: Invalid vesting period: must be non-negative and not exceed the maximum */
+export const XORCA_STAKING_PROGRAM_ERROR__INVALID_VESTING_PERIOD = 0x178b; // 6027
+/** InvalidRewardsAmount: Invalid rewards amount: must be non-zero */
+export const XORCA_STAKING_PROGRAM_ERROR__INVALID_REWARDS_AMOUNT = 0x178c; // 6028
+/** InvalidPerformanceFee: Invalid performance fee: must not exceed 10000 bps */
+export const XORCA_STAKING_PROGRAM_ERROR__INVALID_PERFORMANCE_FEE = 0x178d; // 6029
+/** MaxVaultOrcaExceeded: Stake would exceed the vault ORCA cap */
+export const XORCA_STAKING_PROGRAM_ERROR__MAX_VAULT_ORCA_EXCEEDED = 0x178e; // 6030
+/** MaxStakePerTxExceeded: Stake amount exceeds the per-transaction cap */
+export const XORCA_STAKING_PROGRAM_ERROR__MAX_STAKE_PER_TX_EXCEEDED = 0x178f; // 6031
+/** StakeAmountBelowMinimum: Stake amount is below the configured minimum */
+export const XORCA_STAKING_PROGRAM_ERROR__STAKE_AMOUNT_BELOW_MINIMUM = 0x1790; // 6032
+/** UnstakeAmountBelowMinimum: Unstake amount is below the configured minimum */
+export const XORCA_STAKING_PROGRAM_ERROR__UNSTAKE_AMOUNT_BELOW_MINIMUM = 0x1791; // 6033
+/** InvalidPendingWithdrawOwner: Signer does not own the pending withdraw */
+export const XORCA_STAKING_PROGRAM_ERROR__INVALID_PENDING_WITHDRAW_OWNER = 0x1792; // 6034
+/** StakerAccountRequired: Pending withdraw is indexed by a staker account that was not provided */
+export const XORCA_STAKING_PROGRAM_ERROR__STAKER_ACCOUNT_REQUIRED = 0x1793; // 6035
+/** UnsupportedAccountVersion: Account layout version is not supported by this program */
+export const XORCA_STAKING_PROGRAM_ERROR__UNSUPPORTED_ACCOUNT_VERSION = 0x1794; // 6036
+/** InvalidStakeCaps: Invalid stake caps: the per-transaction cap must not exceed the vault ORCA cap */
+export const XORCA_STAKING_PROGRAM_ERROR__INVALID_STAKE_CAPS = 0x1795; // 6037
+/** DeprecatedInstruction: Instruction is deprecated and no longer supported */
+export const XORCA_STAKING_PROGRAM_ERROR__DEPRECATED_INSTRUCTION = 0x1796; // 6038
 
 export type XorcaStakingProgramError =
   | typeof XORCA_STAKING_PROGRAM_ERROR__ARITHMETIC_ERROR
+  | typeof XORCA_STAKING_PROGRAM_ERROR__CONFIG_TIMELOCK_ACTIVE
   | typeof XORCA_STAKING_PROGRAM_ERROR__COOL_DOWN_OVERFLOW
   | typeof XORCA_STAKING_PROGRAM_ERROR__COOL_DOWN_PERIOD_STILL_ACTIVE
+  | typeof XORCA_STAKING_PROGRAM_ERROR__DEADLINE_EXCEEDED
+  | typeof XORCA_STAKING_PROGRAM_ERROR__DEPRECATED_INSTRUCTION
   | typeof XORCA_STAKING_PROGRAM_ERROR__EMIT_EVENT_ERROR
   | typeof XORCA_STAKING_PROGRAM_ERROR__INCORRECT_ACCOUNT_ADDRESS
   | typeof XORCA_STAKING_PROGRAM_ERROR__INCORRECT_OWNER
@@ -71,18 +112,37 @@ export type XorcaStakingProgramError =
   | typeof XORCA_STAKING_PROGRAM_ERROR__INVALID_ACCOUNT_DATA
   | typeof XORCA_STAKING_PROGRAM_ERROR__INVALID_ACCOUNT_ROLE
   | typeof XORCA_STAKING_PROGRAM_ERROR__INVALID_COOL_DOWN_PERIOD
+  | typeof XORCA_STAKING_PROGRAM_ERROR__INVALID_INSTANT_UNSTAKE_FEE
+  | typeof XORCA_STAKING_PROGRAM_ERROR__INVALID_PENDING_WITHDRAW_OWNER
+  | typeof XORCA_STAKING_PROGRAM_ERROR__INVALID_PERFORMANCE_FEE
+  | typeof XORCA_STAKING_PROGRAM_ERROR__INVALID_REWARDS_AMOUNT
   | typeof XORCA_STAKING_PROGRAM_ERROR__INVALID_SEEDS
+  | typeof XORCA_STAKING_PROGRAM_ERROR__INVALID_STAKE_CAPS
+  | typeof XORCA_STAKING_PROGRAM_ERROR__INVALID_VESTING_PERIOD
+  | typeof XORCA_STAKING_PROGRAM_ERROR__INVALID_WITHDRAW_AMOUNT
+  | typeof XORCA_STAKING_PROGRAM_ERROR__MAX_STAKE_PER_TX_EXCEEDED
+  | typeof XORCA_STAKING_PROGRAM_ERROR__MAX_VAULT_ORCA_EXCEEDED
   | typeof XORCA_STAKING_PROGRAM_ERROR__NOT_ENOUGH_ACCOUNT_KEYS
+  | typeof XORCA_STAKING_PROGRAM_ERROR__NO_PENDING_CONFIG
+  | typeof XORCA_STAKING_PROGRAM_ERROR__PROGRAM_PAUSED
+  | typeof XORCA_STAKING_PROGRAM_ERROR__SLIPPAGE_EXCEEDED
+  | typeof XORCA_STAKING_PROGRAM_ERROR__STAKER_ACCOUNT_REQUIRED
+  | typeof XORCA_STAKING_PROGRAM_ERROR__STAKE_AMOUNT_BELOW_MINIMUM
   | typeof XORCA_STAKING_PROGRAM_ERROR__STATE_ACCOUNT_ALREADY_INITIALIZED
   | typeof XORCA_STAKING_PROGRAM_ERROR__UNAUTHORIZED_DEPLOYER_ACCESS
-  | typeof XORCA_STAKING_PROGRAM_ERROR__UNKNOWN_INSTRUCTION_DISCRIMINATOR;
+  | typeof XORCA_STAKING_PROGRAM_ERROR__UNKNOWN_INSTRUCTION_DISCRIMINATOR
+  | typeof XORCA_STAKING_PROGRAM_ERROR__UNSTAKE_AMOUNT_BELOW_MINIMUM
+  | typeof XORCA_STAKING_PROGRAM_ERROR__UNSUPPORTED_ACCOUNT_VERSION;
 
 let xorcaStakingProgramErrorMessages: Record<XorcaStakingProgramError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   xorcaStakingProgramErrorMessages = {
     [XORCA_STAKING_PROGRAM_ERROR__ARITHMETIC_ERROR]: `Arithmetic error`,
+    [XORCA_STAKING_PROGRAM_ERROR__CONFIG_TIMELOCK_ACTIVE]: `Pending config change is still timelocked`,
     [XORCA_STAKING_PROGRAM_ERROR__COOL_DOWN_OVERFLOW]: `Cooldown timestamp overflowed`,
     [XORCA_STAKING_PROGRAM_ERROR__COOL_DOWN_PERIOD_STILL_ACTIVE]: `Cool down period still active`,
+    [XORCA_STAKING_PROGRAM_ERROR__DEADLINE_EXCEEDED]: `Transaction deadline exceeded`,
+    [XORCA_STAKING_PROGRAM_ERROR__DEPRECATED_INSTRUCTION]: `Instruction is deprecated and no longer supported`,
     [XORCA_STAKING_PROGRAM_ERROR__EMIT_EVENT_ERROR]: `Event serialization failed`,
     [XORCA_STAKING_PROGRAM_ERROR__INCORRECT_ACCOUNT_ADDRESS]: `Invalid account address`,
     [XORCA_STAKING_PROGRAM_ERROR__INCORRECT_OWNER]: `Incorrect owner`,
@@ -94,12 +154,28 @@ if (process.env.NODE_ENV !== 'production') {
     [XORCA_STAKING_PROGRAM_ERROR__INSUFFICIENT_VAULT_BACKING]: `Insufficient vault backing (vault < escrow)`,
     [XORCA_STAKING_PROGRAM_ERROR__INVALID_ACCOUNT_DATA]: `Invalid account data`,
     [XORCA_STAKING_PROGRAM_ERROR__INVALID_ACCOUNT_ROLE]: `Invalid account role`,
-    [XORCA_STAKING_PROGRAM_ERROR__INVALID_COOL_DOWN_PERIOD]: `Invalid cooldown period: must be non-negative`,
+    [XORCA_STAKING_PROGRAM_ERROR__INVALID_COOL_DOWN_PERIOD]: `Invalid cooldown period: must be non-negative and not exceed the maximum`,
+    [XORCA_STAKING_PROGRAM_ERROR__INVALID_INSTANT_UNSTAKE_FEE]: `Invalid instant unstake fee: must not exceed 10000 bps`,
+    [XORCA_STAKING_PROGRAM_ERROR__INVALID_PENDING_WITHDRAW_OWNER]: `Signer does not own the pending withdraw`,
+    [XORCA_STAKING_PROGRAM_ERROR__INVALID_PERFORMANCE_FEE]: `Invalid performance fee: must not exceed 10000 bps`,
+    [XORCA_STAKING_PROGRAM_ERROR__INVALID_REWARDS_AMOUNT]: `Invalid rewards amount: must be non-zero`,
     [XORCA_STAKING_PROGRAM_ERROR__INVALID_SEEDS]: `Invalid seeds`,
+    [XORCA_STAKING_PROGRAM_ERROR__INVALID_STAKE_CAPS]: `Invalid stake caps: the per-transaction cap must not exceed the vault ORCA cap`,
+    [XORCA_STAKING_PROGRAM_ERROR__INVALID_VESTING_PERIOD]: `Invalid vesting period: must be non-negative and not exceed the maximum`,
+    [XORCA_STAKING_PROGRAM_ERROR__INVALID_WITHDRAW_AMOUNT]: `Invalid withdraw amount: must be non-zero and not exceed the pending withdraw`,
+    [XORCA_STAKING_PROGRAM_ERROR__MAX_STAKE_PER_TX_EXCEEDED]: `Stake amount exceeds the per-transaction cap`,
+    [XORCA_STAKING_PROGRAM_ERROR__MAX_VAULT_ORCA_EXCEEDED]: `Stake would exceed the vault ORCA cap`,
     [XORCA_STAKING_PROGRAM_ERROR__NOT_ENOUGH_ACCOUNT_KEYS]: `Not enough account keys`,
+    [XORCA_STAKING_PROGRAM_ERROR__NO_PENDING_CONFIG]: `No pending config change to apply`,
+    [XORCA_STAKING_PROGRAM_ERROR__PROGRAM_PAUSED]: `Program is paused for this instruction`,
+    [XORCA_STAKING_PROGRAM_ERROR__SLIPPAGE_EXCEEDED]: `Slippage exceeded: output is below the requested minimum`,
+    [XORCA_STAKING_PROGRAM_ERROR__STAKER_ACCOUNT_REQUIRED]: `Pending withdraw is indexed by a staker account that was not provided`,
+    [XORCA_STAKING_PROGRAM_ERROR__STAKE_AMOUNT_BELOW_MINIMUM]: `Stake amount is below the configured minimum`,
     [XORCA_STAKING_PROGRAM_ERROR__STATE_ACCOUNT_ALREADY_INITIALIZED]: `State account already initialized`,
     [XORCA_STAKING_PROGRAM_ERROR__UNAUTHORIZED_DEPLOYER_ACCESS]: `Unauthorized deployer access`,
     [XORCA_STAKING_PROGRAM_ERROR__UNKNOWN_INSTRUCTION_DISCRIMINATOR]: `Unknown instruction discriminator`,
+    [XORCA_STAKING_PROGRAM_ERROR__UNSTAKE_AMOUNT_BELOW_MINIMUM]: `Unstake amount is below the configured minimum`,
+    [XORCA_STAKING_PROGRAM_ERROR__UNSUPPORTED_ACCOUNT_VERSION]: `Account layout version is not supported by this program`,
   };
 }
 
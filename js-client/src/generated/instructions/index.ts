@@ -6,8 +6,27 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptUpdateAuthority';
+export * from './applyPendingConfig';
+export * from './cancelUnstake';
+export * from './crankWithdraw';
+export * from './depositRewards';
+export * from './harvest';
 export * from './initialize';
+export * from './initializeStakerAccount';
+export * from './instantUnstake';
+export * from './mergePendingWithdraws';
+export * from './migrate';
 export * from './set';
 export * from './stake';
+export * from './stakeFor';
+export * from './stakeForExactXorca';
+export * from './stakeWithSlippage';
+export * from './transferPendingWithdraw';
 export * from './unstake';
+export * from './unstakeForExactOrca';
+export * from './unstakeWithSlippage';
 export * from './withdraw';
+export * from './withdrawMany';
+export * from './withdrawPartial';
+export * from './withdrawTo';
@@ -50,6 +50,7 @@ export type InitializeInstruction<
   TAccountSystemProgramAccount extends string | AccountMeta<string> = string,
   TAccountTokenProgramAccount extends string | AccountMeta<string> = string,
   TAccountAssociatedTokenProgramAccount extends string | AccountMeta<string> = string,
+  TAccountProgramDataAccount extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -83,6 +84,9 @@ export type InitializeInstruction<
       TAccountAssociatedTokenProgramAccount extends string
         ? ReadonlyAccount<TAccountAssociatedTokenProgramAccount>
         : TAccountAssociatedTokenProgramAccount,
+      TAccountProgramDataAccount extends string
+        ? ReadonlyAccount<TAccountProgramDataAccount>
+        : TAccountProgramDataAccount,
       ...TRemainingAccounts,
     ]
   >;
@@ -130,6 +134,7 @@ export type InitializeInput<
   TAccountSystemProgramAccount extends string = string,
   TAccountTokenProgramAccount extends string = string,
   TAccountAssociatedTokenProgramAccount extends string = string,
+  TAccountProgramDataAccount extends string = string,
 > = {
   payerAccount: TransactionSigner<TAccountPayerAccount>;
   updateAuthorityAccount: TransactionSigner<TAccountUpdateAuthorityAccount>;
@@ -140,6 +145,7 @@ export type InitializeInput<
   systemProgramAccount: Address<TAccountSystemProgramAccount>;
   tokenProgramAccount: Address<TAccountTokenProgramAccount>;
   associatedTokenProgramAccount: Address<TAccountAssociatedTokenProgramAccount>;
+  programDataAccount: Address<TAccountProgramDataAccount>;
   coolDownPeriodS: InitializeInstructionDataArgs['coolDownPeriodS'];
 };
 
@@ -153,6 +159,7 @@ export function getInitializeInstruction<
   TAccountSystemProgramAccount extends string,
   TAccountTokenProgramAccount extends string,
   TAccountAssociatedTokenProgramAccount extends string,
+  TAccountProgramDataAccount extends string,
   TProgramAddress extends Address = typeof XORCA_STAKING_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: InitializeInput<
@@ -164,7 +171,8 @@ export function getInitializeInstruction<
     TAccountOrcaMintAccount,
     TAccountSystemProgramAccount,
     TAccountTokenProgramAccount,
-    TAccountAssociatedTokenProgramAccount
+    TAccountAssociatedTokenProgramAccount,
+    TAccountProgramDataAccount
   >,
   config?: { programAddress?: TProgramAddress }
 ): InitializeInstruction<
@@ -177,7 +185,8 @@ export function getInitializeInstruction<
   TAccountOrcaMintAccount,
   TAccountSystemProgramAccount,
   TAccountTokenProgramAccount,
-  TAccountAssociatedTokenProgramAccount
+  TAccountAssociatedTokenProgramAccount,
+  TAccountProgramDataAccount
 > {
   // Program address.
   const programAddress = config?.programAddress ?? XORCA_STAKING_PROGRAM_PROGRAM_ADDRESS;
@@ -211,6 +220,10 @@ export function getInitializeInstruction<
       value: input.associatedTokenProgramAccount ?? null,
       isWritable: false,
     },
+    programDataAccount: {
+      value: input.programDataAccount ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<keyof typeof originalAccounts, ResolvedAccount>;
 
@@ -229,6 +242,7 @@ export function getInitializeInstruction<
       getAccountMeta(accounts.systemProgramAccount),
       getAccountMeta(accounts.tokenProgramAccount),
       getAccountMeta(accounts.associatedTokenProgramAccount),
+      getAccountMeta(accounts.programDataAccount),
     ],
     data: getInitializeInstructionDataEncoder().encode(args as InitializeInstructionDataArgs),
     programAddress,
@@ -242,7 +256,8 @@ export function getInitializeInstruction<
     TAccountOrcaMintAccount,
     TAccountSystemProgramAccount,
     TAccountTokenProgramAccount,
-    TAccountAssociatedTokenProgramAccount
+    TAccountAssociatedTokenProgramAccount,
+    TAccountProgramDataAccount
   >);
 }
 
@@ -261,6 +276,7 @@ export type ParsedInitializeInstruction<
     systemProgramAccount: TAccountMetas[6];
     tokenProgramAccount: TAccountMetas[7];
     associatedTokenProgramAccount: TAccountMetas[8];
+    programDataAccount: TAccountMetas[9];
   };
   data: InitializeInstructionData;
 };
@@ -273,7 +289,7 @@ export function parseInitializeInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedInitializeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -295,6 +311,7 @@ export function parseInitializeInstruction<
       systemProgramAccount: getNextAccount(),
       tokenProgramAccount: getNextAccount(),
       associatedTokenProgramAccount: getNextAccount(),
+      programDataAccount: getNextAccount(),
     },
     data: getInitializeInstructionDataDecoder().decode(instruction.data),
   };
@@ -50,6 +50,8 @@ export type UnstakeInstruction<
   TAccountVaultAccount extends string | AccountMeta<string> = string,
   TAccountSystemProgramAccount extends string | AccountMeta<string> = string,
   TAccountTokenProgramAccount extends string | AccountMeta<string> = string,
+  TAccountRentPayerAccount extends string | AccountMeta<string> = string,
+  TAccountStakerAccountPda extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -83,6 +85,13 @@ export type UnstakeInstruction<
       TAccountTokenProgramAccount extends string
         ? ReadonlyAccount<TAccountTokenProgramAccount>
         : TAccountTokenProgramAccount,
+      TAccountRentPayerAccount extends string
+        ? WritableSignerAccount<TAccountRentPayerAccount> &
+            AccountSignerMeta<TAccountRentPayerAccount>
+        : TAccountRentPayerAccount,
+      TAccountStakerAccountPda extends string
+        ? WritableAccount<TAccountStakerAccountPda>
+        : TAccountStakerAccountPda,
       ...TRemainingAccounts,
     ]
   >;
@@ -134,6 +143,8 @@ export type UnstakeInput<
   TAccountVaultAccount extends string = string,
   TAccountSystemProgramAccount extends string = string,
   TAccountTokenProgramAccount extends string = string,
+  TAccountRentPayerAccount extends string = string,
+  TAccountStakerAccountPda extends string = string,
 > = {
   unstakerAccount: TransactionSigner<TAccountUnstakerAccount>;
   stateAccount: Address<TAccountStateAccount>;
@@ -144,6 +155,8 @@ export type UnstakeInput<
   vaultAccount: Address<TAccountVaultAccount>;
   systemProgramAccount: Address<TAccountSystemProgramAccount>;
   tokenProgramAccount: Address<TAccountTokenProgramAccount>;
+  rentPayerAccount?: TransactionSigner<TAccountRentPayerAccount>;
+  stakerAccountPda?: Address<TAccountStakerAccountPda>;
   xorcaUnstakeAmount: UnstakeInstructionDataArgs['xorcaUnstakeAmount'];
   withdrawIndex: UnstakeInstructionDataArgs['withdrawIndex'];
 };
@@ -158,6 +171,8 @@ export function getUnstakeInstruction<
   TAccountVaultAccount extends string,
   TAccountSystemProgramAccount extends string,
   TAccountTokenProgramAccount extends string,
+  TAccountRentPayerAccount extends string,
+  TAccountStakerAccountPda extends string,
   TProgramAddress extends Address = typeof XORCA_STAKING_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: UnstakeInput<
@@ -169,7 +184,9 @@ export function getUnstakeInstruction<
     TAccountOrcaMintAccount,
     TAccountVaultAccount,
     TAccountSystemProgramAccount,
-    TAccountTokenProgramAccount
+    TAccountTokenProgramAccount,
+    TAccountRentPayerAccount,
+    TAccountStakerAccountPda
   >,
   config?: { programAddress?: TProgramAddress }
 ): UnstakeInstruction<
@@ -182,7 +199,9 @@ export function getUnstakeInstruction<
   TAccountOrcaMintAccount,
   TAccountVaultAccount,
   TAccountSystemProgramAccount,
-  TAccountTokenProgramAccount
+  TAccountTokenProgramAccount,
+  TAccountRentPayerAccount,
+  TAccountStakerAccountPda
 > {
   // Program address.
   const programAddress = config?.programAddress ?? XORCA_STAKING_PROGRAM_PROGRAM_ADDRESS;
@@ -216,6 +235,14 @@ export function getUnstakeInstruction<
       value: input.tokenProgramAccount ?? null,
       isWritable: false,
     },
+    rentPayerAccount: {
+      value: input.rentPayerAccount ?? null,
+      isWritable: true,
+    },
+    stakerAccountPda: {
+      value: input.stakerAccountPda ?? null,
+      isWritable: true,
+    },
   };
   const accounts = originalAccounts as Record<keyof typeof originalAccounts, ResolvedAccount>;
 
@@ -234,6 +261,8 @@ export function getUnstakeInstruction<
       getAccountMeta(accounts.vaultAccount),
       getAccountMeta(accounts.systemProgramAccount),
       getAccountMeta(accounts.tokenProgramAccount),
+      getAccountMeta(accounts.rentPayerAccount),
+      getAccountMeta(accounts.stakerAccountPda),
     ],
     data: getUnstakeInstructionDataEncoder().encode(args as UnstakeInstructionDataArgs),
     programAddress,
@@ -247,7 +276,9 @@ export function getUnstakeInstruction<
     TAccountOrcaMintAccount,
     TAccountVaultAccount,
     TAccountSystemProgramAccount,
-    TAccountTokenProgramAccount
+    TAccountTokenProgramAccount,
+    TAccountRentPayerAccount,
+    TAccountStakerAccountPda
   >);
 }
 
@@ -266,6 +297,8 @@ export type ParsedUnstakeInstruction<
     vaultAccount: TAccountMetas[6];
     systemProgramAccount: TAccountMetas[7];
     tokenProgramAccount: TAccountMetas[8];
+    rentPayerAccount?: TAccountMetas[9] | undefined;
+    stakerAccountPda?: TAccountMetas[10] | undefined;
   };
   data: UnstakeInstructionData;
 };
@@ -278,7 +311,7 @@ export function parseUnstakeInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUnstakeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -288,6 +321,10 @@ export function parseUnstakeInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === XORCA_STAKING_PROGRAM_PROGRAM_ADDRESS ? undefined : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -300,6 +337,8 @@ export function parseUnstakeInstruction<
       vaultAccount: getNextAccount(),
       systemProgramAccount: getNextAccount(),
       tokenProgramAccount: getNextAccount(),
+      rentPayerAccount: getNextOptionalAccount(),
+      stakerAccountPda: getNextOptionalAccount(),
     },
     data: getUnstakeInstructionDataDecoder().decode(instruction.data),
   };
@@ -8,11 +8,30 @@
 
 import { containsBytes, getU8Encoder, type Address, type ReadonlyUint8Array } from '@solana/kit';
 import {
+  type ParsedAcceptUpdateAuthorityInstruction,
+  type ParsedApplyPendingConfigInstruction,
+  type ParsedCancelUnstakeInstruction,
+  type ParsedCrankWithdrawInstruction,
+  type ParsedDepositRewardsInstruction,
+  type ParsedHarvestInstruction,
   type ParsedInitializeInstruction,
+  type ParsedInitializeStakerAccountInstruction,
+  type ParsedInstantUnstakeInstruction,
+  type ParsedMergePendingWithdrawsInstruction,
+  type ParsedMigrateInstruction,
   type ParsedSetInstruction,
+  type ParsedStakeForExactXorcaInstruction,
+  type ParsedStakeForInstruction,
   type ParsedStakeInstruction,
+  type ParsedStakeWithSlippageInstruction,
+  type ParsedTransferPendingWithdrawInstruction,
+  type ParsedUnstakeForExactOrcaInstruction,
   type ParsedUnstakeInstruction,
+  type ParsedUnstakeWithSlippageInstruction,
   type ParsedWithdrawInstruction,
+  type ParsedWithdrawManyInstruction,
+  type ParsedWithdrawPartialInstruction,
+  type ParsedWithdrawToInstruction,
 } from '../instructions';
 import { AccountDiscriminator, getAccountDiscriminatorEncoder } from '../types';
 
@@ -21,6 +40,7 @@ export const XORCA_STAKING_PROGRAM_PROGRAM_ADDRESS =
 
 export enum XorcaStakingProgramAccount {
   PendingWithdraw,
+  StakerAccount,
   State,
 }
 
@@ -37,6 +57,15 @@ export function identifyXorcaStakingProgramAccount(
   ) {
     return XorcaStakingProgramAccount.PendingWithdraw;
   }
+  if (
+    containsBytes(
+      data,
+      getAccountDiscriminatorEncoder().encode(AccountDiscriminator.StakerAccount),
+      0
+    )
+  ) {
+    return XorcaStakingProgramAccount.StakerAccount;
+  }
   if (containsBytes(data, getAccountDiscriminatorEncoder().encode(AccountDiscriminator.State), 0)) {
     return XorcaStakingProgramAccount.State;
   }
@@ -49,6 +78,25 @@ export enum XorcaStakingProgramInstruction {
   Withdraw,
   Initialize,
   Set,
+  InstantUnstake,
+  CancelUnstake,
+  WithdrawPartial,
+  MergePendingWithdraws,
+  WithdrawMany,
+  StakeWithSlippage,
+  UnstakeWithSlippage,
+  StakeForExactXorca,
+  UnstakeForExactOrca,
+  AcceptUpdateAuthority,
+  ApplyPendingConfig,
+  DepositRewards,
+  Harvest,
+  StakeFor,
+  WithdrawTo,
+  CrankWithdraw,
+  TransferPendingWithdraw,
+  InitializeStakerAccount,
+  Migrate,
 }
 
 export function identifyXorcaStakingProgramInstruction(
@@ -70,6 +118,63 @@ export function identifyXorcaStakingProgramInstruction(
   if (containsBytes(data, getU8Encoder().encode(4), 0)) {
     return XorcaStakingProgramInstruction.Set;
   }
+  if (containsBytes(data, getU8Encoder().encode(5), 0)) {
+    return XorcaStakingProgramInstruction.InstantUnstake;
+  }
+  if (containsBytes(data, getU8Encoder().encode(6), 0)) {
+    return XorcaStakingProgramInstruction.CancelUnstake;
+  }
+  if (containsBytes(data, getU8Encoder().encode(7), 0)) {
+    return XorcaStakingProgramInstruction.WithdrawPartial;
+  }
+  if (containsBytes(data, getU8Encoder().encode(8), 0)) {
+    return XorcaStakingProgramInstruction.MergePendingWithdraws;
+  }
+  if (containsBytes(data, getU8Encoder().encode(9), 0)) {
+    return XorcaStakingProgramInstruction.WithdrawMany;
+  }
+  if (containsBytes(data, getU8Encoder().encode(10), 0)) {
+    return XorcaStakingProgramInstruction.StakeWithSlippage;
+  }
+  if (containsBytes(data, getU8Encoder().encode(11), 0)) {
+    return XorcaStakingProgramInstruction.UnstakeWithSlippage;
+  }
+  if (containsBytes(data, getU8Encoder().encode(12), 0)) {
+    return XorcaStakingProgramInstruction.StakeForExactXorca;
+  }
+  if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+    return XorcaStakingProgramInstruction.UnstakeForExactOrca;
+  }
+  if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+    return XorcaStakingProgramInstruction.AcceptUpdateAuthority;
+  }
+  if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+    return XorcaStakingProgramInstruction.ApplyPendingConfig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+    return XorcaStakingProgramInstruction.DepositRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+    return XorcaStakingProgramInstruction.Harvest;
+  }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return XorcaStakingProgramInstruction.StakeFor;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return XorcaStakingProgramInstruction.WithdrawTo;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return XorcaStakingProgramInstruction.CrankWithdraw;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return XorcaStakingProgramInstruction.TransferPendingWithdraw;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return XorcaStakingProgramInstruction.InitializeStakerAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return XorcaStakingProgramInstruction.Migrate;
+  }
   throw new Error(
     'The provided instruction could not be identified as a xorcaStakingProgram instruction.'
   );
@@ -92,4 +197,61 @@ export type ParsedXorcaStakingProgramInstruction<
     } & ParsedInitializeInstruction<TProgram>)
   | ({
       instructionType: XorcaStakingProgramInstruction.Set;
-    } & ParsedSetInstruction<TProgram>);
+    } & ParsedSetInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.InstantUnstake;
+    } & ParsedInstantUnstakeInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.CancelUnstake;
+    } & ParsedCancelUnstakeInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.WithdrawPartial;
+    } & ParsedWithdrawPartialInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.MergePendingWithdraws;
+    } & ParsedMergePendingWithdrawsInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.WithdrawMany;
+    } & ParsedWithdrawManyInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.StakeWithSlippage;
+    } & ParsedStakeWithSlippageInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.UnstakeWithSlippage;
+    } & ParsedUnstakeWithSlippageInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.StakeForExactXorca;
+    } & ParsedStakeForExactXorcaInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.UnstakeForExactOrca;
+    } & ParsedUnstakeForExactOrcaInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.AcceptUpdateAuthority;
+    } & ParsedAcceptUpdateAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.ApplyPendingConfig;
+    } & ParsedApplyPendingConfigInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.DepositRewards;
+    } & ParsedDepositRewardsInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.Harvest;
+    } & ParsedHarvestInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.StakeFor;
+    } & ParsedStakeForInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.WithdrawTo;
+    } & ParsedWithdrawToInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.CrankWithdraw;
+    } & ParsedCrankWithdrawInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.TransferPendingWithdraw;
+    } & ParsedTransferPendingWithdrawInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.InitializeStakerAccount;
+    } & ParsedInitializeStakerAccountInstruction<TProgram>)
+  | ({
+      instructionType: XorcaStakingProgramInstruction.Migrate;
+    } & ParsedMigrateInstruction<TProgram>);
@@ -20,6 +20,7 @@ export enum AccountDiscriminator {
   State,
   PendingWithdraw,
   Closed,
+  StakerAccount,
 }
 
 export type AccountDiscriminatorArgs = AccountDiscriminator;
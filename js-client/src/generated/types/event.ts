@@ -14,8 +14,12 @@ import {
   getDiscriminatedUnionEncoder,
   getI64Decoder,
   getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -26,6 +30,8 @@ import {
   type Encoder,
   type GetDiscriminatedUnionVariant,
   type GetDiscriminatedUnionVariantContent,
+  type Option,
+  type OptionOrNullable,
 } from '@solana/kit';
 
 export type Event =
@@ -36,6 +42,8 @@ export type Event =
       vaultEscrowedOrcaAmount: bigint;
       xorcaMintSupply: bigint;
       xorcaToMint: bigint;
+      payer: Address;
+      beneficiary: Address;
     }
   | {
       __kind: 'Unstake';
@@ -46,6 +54,7 @@ export type Event =
       withdrawableOrcaAmount: bigint;
       coolDownPeriodS: bigint;
       withdrawIndex: number;
+      stakerWithdrawIndex: Option<bigint>;
     }
   | {
       __kind: 'Withdraw';
@@ -53,8 +62,144 @@ export type Event =
       withdrawableOrcaAmount: bigint;
       coolDownPeriodS: bigint;
       withdrawIndex: number;
+      destination: Address;
+      stakerWithdrawIndex: Option<bigint>;
     }
-  | { __kind: 'UpdateAuthoritySet'; newAuthority: Address; setBy: Address };
+  | { __kind: 'UpdateAuthoritySet'; newAuthority: Address; setBy: Address }
+  | {
+      __kind: 'InstantUnstake';
+      xorcaUnstakeAmount: bigint;
+      vaultOrcaAmount: bigint;
+      vaultEscrowedOrcaAmount: bigint;
+      xorcaMintSupply: bigint;
+      orcaUnstakeAmount: bigint;
+      feeOrcaAmount: bigint;
+      instantUnstakeFeeBps: number;
+    }
+  | {
+      __kind: 'CancelUnstake';
+      withdrawableOrcaAmount: bigint;
+      vaultOrcaAmount: bigint;
+      vaultEscrowedOrcaAmount: bigint;
+      xorcaMintSupply: bigint;
+      xorcaToMint: bigint;
+      withdrawIndex: number;
+    }
+  | {
+      __kind: 'WithdrawPartial';
+      vaultEscrowedOrcaAmount: bigint;
+      orcaWithdrawAmount: bigint;
+      remainingOrcaAmount: bigint;
+      withdrawIndex: number;
+    }
+  | {
+      __kind: 'MergePendingWithdraws';
+      withdrawableOrcaAmount: bigint;
+      withdrawableTimestamp: bigint;
+      mergedCount: number;
+      withdrawIndex: number;
+    }
+  | {
+      __kind: 'WithdrawMany';
+      vaultEscrowedOrcaAmount: bigint;
+      withdrawableOrcaAmount: bigint;
+      withdrawCount: number;
+    }
+  | {
+      __kind: 'PauseFlagsSet';
+      newPauseFlags: number;
+      oldPauseFlags: number;
+      setBy: Address;
+    }
+  | {
+      __kind: 'UpdateAuthorityProposed';
+      proposedAuthority: Address;
+      proposedBy: Address;
+    }
+  | {
+      __kind: 'CoolDownPeriodChangeQueued';
+      newCoolDownPeriodS: bigint;
+      activationTimestamp: bigint;
+      queuedBy: Address;
+    }
+  | {
+      __kind: 'PendingConfigApplied';
+      oldCoolDownPeriodS: bigint;
+      newCoolDownPeriodS: bigint;
+    }
+  | {
+      __kind: 'RewardsDeposited';
+      rewardsAmount: bigint;
+      vaultOrcaAmount: bigint;
+      vestingRewardsAmount: bigint;
+      vestingEndTimestamp: bigint;
+      totalRewardsDeposited: bigint;
+      nonEscrowedOrcaAmount: bigint;
+      xorcaMintSupply: bigint;
+    }
+  | {
+      __kind: 'RewardsAuthoritySet';
+      newRewardsAuthority: Address;
+      setBy: Address;
+    }
+  | {
+      __kind: 'FeeMinted';
+      yieldOrcaAmount: bigint;
+      feeOrcaAmount: bigint;
+      feeXorcaAmount: bigint;
+      xorcaMintSupply: bigint;
+      treasury: Address;
+    }
+  | {
+      __kind: 'PendingWithdrawTransferred';
+      unstaker: Address;
+      withdrawIndex: number;
+      previousOwner: Address;
+      newOwner: Address;
+    }
+  | { __kind: 'AccountsMigrated'; migratedCount: number; migratedBy: Address }
+  | {
+      __kind: 'InstantUnstakeFeeSet';
+      newFeeBps: number;
+      oldFeeBps: number;
+      setBy: Address;
+    }
+  | {
+      __kind: 'PerformanceFeeSet';
+      newFeeBps: number;
+      oldFeeBps: number;
+      setBy: Address;
+    }
+  | {
+      __kind: 'TreasurySet';
+      newTreasury: Address;
+      oldTreasury: Address;
+      setBy: Address;
+    }
+  | {
+      __kind: 'MaxVaultOrcaSet';
+      newMaxVaultOrca: bigint;
+      oldMaxVaultOrca: bigint;
+      setBy: Address;
+    }
+  | {
+      __kind: 'MaxStakePerTxSet';
+      newMaxStakePerTx: bigint;
+      oldMaxStakePerTx: bigint;
+      setBy: Address;
+    }
+  | {
+      __kind: 'MinStakeOrcaSet';
+      newMinStakeOrca: bigint;
+      oldMinStakeOrca: bigint;
+      setBy: Address;
+    }
+  | {
+      __kind: 'MinUnstakeXorcaSet';
+      newMinUnstakeXorca: bigint;
+      oldMinUnstakeXorca: bigint;
+      setBy: Address;
+    };
 
 export type EventArgs =
   | {
@@ -64,6 +209,8 @@ export type EventArgs =
       vaultEscrowedOrcaAmount: number | bigint;
       xorcaMintSupply: number | bigint;
       xorcaToMint: number | bigint;
+      payer: Address;
+      beneficiary: Address;
     }
   | {
       __kind: 'Unstake';
@@ -74,6 +221,7 @@ export type EventArgs =
       withdrawableOrcaAmount: number | bigint;
       coolDownPeriodS: number | bigint;
       withdrawIndex: number;
+      stakerWithdrawIndex: OptionOrNullable<number | bigint>;
     }
   | {
       __kind: 'Withdraw';
@@ -81,8 +229,144 @@ export type EventArgs =
       withdrawableOrcaAmount: number | bigint;
       coolDownPeriodS: number | bigint;
       withdrawIndex: number;
+      destination: Address;
+      stakerWithdrawIndex: OptionOrNullable<number | bigint>;
+    }
+  | { __kind: 'UpdateAuthoritySet'; newAuthority: Address; setBy: Address }
+  | {
+      __kind: 'InstantUnstake';
+      xorcaUnstakeAmount: number | bigint;
+      vaultOrcaAmount: number | bigint;
+      vaultEscrowedOrcaAmount: number | bigint;
+      xorcaMintSupply: number | bigint;
+      orcaUnstakeAmount: number | bigint;
+      feeOrcaAmount: number | bigint;
+      instantUnstakeFeeBps: number;
+    }
+  | {
+      __kind: 'CancelUnstake';
+      withdrawableOrcaAmount: number | bigint;
+      vaultOrcaAmount: number | bigint;
+      vaultEscrowedOrcaAmount: number | bigint;
+      xorcaMintSupply: number | bigint;
+      xorcaToMint: number | bigint;
+      withdrawIndex: number;
+    }
+  | {
+      __kind: 'WithdrawPartial';
+      vaultEscrowedOrcaAmount: number | bigint;
+      orcaWithdrawAmount: number | bigint;
+      remainingOrcaAmount: number | bigint;
+      withdrawIndex: number;
+    }
+  | {
+      __kind: 'MergePendingWithdraws';
+      withdrawableOrcaAmount: number | bigint;
+      withdrawableTimestamp: number | bigint;
+      mergedCount: number;
+      withdrawIndex: number;
     }
-  | { __kind: 'UpdateAuthoritySet'; newAuthority: Address; setBy: Address };
+  | {
+      __kind: 'WithdrawMany';
+      vaultEscrowedOrcaAmount: number | bigint;
+      withdrawableOrcaAmount: number | bigint;
+      withdrawCount: number;
+    }
+  | {
+      __kind: 'PauseFlagsSet';
+      newPauseFlags: number;
+      oldPauseFlags: number;
+      setBy: Address;
+    }
+  | {
+      __kind: 'UpdateAuthorityProposed';
+      proposedAuthority: Address;
+      proposedBy: Address;
+    }
+  | {
+      __kind: 'CoolDownPeriodChangeQueued';
+      newCoolDownPeriodS: number | bigint;
+      activationTimestamp: number | bigint;
+      queuedBy: Address;
+    }
+  | {
+      __kind: 'PendingConfigApplied';
+      oldCoolDownPeriodS: number | bigint;
+      newCoolDownPeriodS: number | bigint;
+    }
+  | {
+      __kind: 'RewardsDeposited';
+      rewardsAmount: number | bigint;
+      vaultOrcaAmount: number | bigint;
+      vestingRewardsAmount: number | bigint;
+      vestingEndTimestamp: number | bigint;
+      totalRewardsDeposited: number | bigint;
+      nonEscrowedOrcaAmount: number | bigint;
+      xorcaMintSupply: number | bigint;
+    }
+  | {
+      __kind: 'RewardsAuthoritySet';
+      newRewardsAuthority: Address;
+      setBy: Address;
+    }
+  | {
+      __kind: 'FeeMinted';
+      yieldOrcaAmount: number | bigint;
+      feeOrcaAmount: number | bigint;
+      feeXorcaAmount: number | bigint;
+      xorcaMintSupply: number | bigint;
+      treasury: Address;
+    }
+  | {
+      __kind: 'PendingWithdrawTransferred';
+      unstaker: Address;
+      withdrawIndex: number;
+      previousOwner: Address;
+      newOwner: Address;
+    }
+  | { __kind: 'AccountsMigrated'; migratedCount: number; migratedBy: Address }
+  | {
+      __kind: 'InstantUnstakeFeeSet';
+      newFeeBps: number;
+      oldFeeBps: number;
+      setBy: Address;
+    }
+  | {
+      __kind: 'PerformanceFeeSet';
+      newFeeBps: number;
+      oldFeeBps: number;
+      setBy: Address;
+    }
+  | {
+      __kind: 'TreasurySet';
+      newTreasury: Address;
+      oldTreasury: Address;
+      setBy: Address;
+    }
+  | {
+      __kind: 'MaxVaultOrcaSet';
+      newMaxVaultOrca: number | bigint;
+      oldMaxVaultOrca: number | bigint;
+      setBy: Address;
+    }
+  | {
+      __kind: 'MaxStakePerTxSet';
+      newMaxStakePerTx: number | bigint;
+      oldMaxStakePerTx: number | bigint;
+      setBy: Address;
+    }
+  | {
+      __kind: 'MinStakeOrcaSet';
+      newMinStakeOrca: number | bigint;
+      oldMinStakeOrca: number | bigint;
+      setBy: Address;
+    }
+  | {
+      __kind: 'MinUnstakeXorcaSet';
+      newMinUnstakeXorca: number | bigint;
+      oldMinUnstakeXorca: number | bigint;
+      setBy: Address;
+    };
 
 export function getEventEncoder(): Encoder<EventArgs> {
   return getDiscriminatedUnionEncoder([
@@ -94,6 +378,8 @@ export function getEventEncoder(): Encoder<EventArgs> {
         ['vaultEscrowedOrcaAmount', getU64Encoder()],
         ['xorcaMintSupply', getU64Encoder()],
         ['xorcaToMint', getU64Encoder()],
+        ['payer', getAddressEncoder()],
+        ['beneficiary', getAddressEncoder()],
       ]),
     ],
     [
@@ -106,6 +392,7 @@ export function getEventEncoder(): Encoder<EventArgs> {
         ['withdrawableOrcaAmount', getU64Encoder()],
         ['coolDownPeriodS', getI64Encoder()],
         ['withdrawIndex', getU8Encoder()],
+        ['stakerWithdrawIndex', getOptionEncoder(getU64Encoder())],
       ]),
     ],
     [
@@ -115,6 +402,8 @@ export function getEventEncoder(): Encoder<EventArgs> {
         ['withdrawableOrcaAmount', getU64Encoder()],
         ['coolDownPeriodS', getI64Encoder()],
         ['withdrawIndex', getU8Encoder()],
+        ['destination', getAddressEncoder()],
+        ['stakerWithdrawIndex', getOptionEncoder(getU64Encoder())],
       ]),
     ],
     [
@@ -124,6 +413,186 @@ export function getEventEncoder(): Encoder<EventArgs> {
         ['setBy', getAddressEncoder()],
       ]),
     ],
+    [
+      'InstantUnstake',
+      getStructEncoder([
+        ['xorcaUnstakeAmount', getU64Encoder()],
+        ['vaultOrcaAmount', getU64Encoder()],
+        ['vaultEscrowedOrcaAmount', getU64Encoder()],
+        ['xorcaMintSupply', getU64Encoder()],
+        ['orcaUnstakeAmount', getU64Encoder()],
+        ['feeOrcaAmount', getU64Encoder()],
+        ['instantUnstakeFeeBps', getU16Encoder()],
+      ]),
+    ],
+    [
+      'CancelUnstake',
+      getStructEncoder([
+        ['withdrawableOrcaAmount', getU64Encoder()],
+        ['vaultOrcaAmount', getU64Encoder()],
+        ['vaultEscrowedOrcaAmount', getU64Encoder()],
+        ['xorcaMintSupply', getU64Encoder()],
+        ['xorcaToMint', getU64Encoder()],
+        ['withdrawIndex', getU8Encoder()],
+      ]),
+    ],
+    [
+      'WithdrawPartial',
+      getStructEncoder([
+        ['vaultEscrowedOrcaAmount', getU64Encoder()],
+        ['orcaWithdrawAmount', getU64Encoder()],
+        ['remainingOrcaAmount', getU64Encoder()],
+        ['withdrawIndex', getU8Encoder()],
+      ]),
+    ],
+    [
+      'MergePendingWithdraws',
+      getStructEncoder([
+        ['withdrawableOrcaAmount', getU64Encoder()],
+        ['withdrawableTimestamp', getI64Encoder()],
+        ['mergedCount', getU16Encoder()],
+        ['withdrawIndex', getU8Encoder()],
+      ]),
+    ],
+    [
+      'WithdrawMany',
+      getStructEncoder([
+        ['vaultEscrowedOrcaAmount', getU64Encoder()],
+        ['withdrawableOrcaAmount', getU64Encoder()],
+        ['withdrawCount', getU16Encoder()],
+      ]),
+    ],
+    [
+      'PauseFlagsSet',
+      getStructEncoder([
+        ['newPauseFlags', getU16Encoder()],
+        ['oldPauseFlags', getU16Encoder()],
+        ['setBy', getAddressEncoder()],
+      ]),
+    ],
+    [
+      'UpdateAuthorityProposed',
+      getStructEncoder([
+        ['proposedAuthority', getAddressEncoder()],
+        ['proposedBy', getAddressEncoder()],
+      ]),
+    ],
+    [
+      'CoolDownPeriodChangeQueued',
+      getStructEncoder([
+        ['newCoolDownPeriodS', getI64Encoder()],
+        ['activationTimestamp', getI64Encoder()],
+        ['queuedBy', getAddressEncoder()],
+      ]),
+    ],
+    [
+      'PendingConfigApplied',
+      getStructEncoder([
+        ['oldCoolDownPeriodS', getI64Encoder()],
+        ['newCoolDownPeriodS', getI64Encoder()],
+      ]),
+    ],
+    [
+      'RewardsDeposited',
+      getStructEncoder([
+        ['rewardsAmount', getU64Encoder()],
+        ['vaultOrcaAmount', getU64Encoder()],
+        ['vestingRewardsAmount', getU64Encoder()],
+        ['vestingEndTimestamp', getI64Encoder()],
+        ['totalRewardsDeposited', getU64Encoder()],
+        ['nonEscrowedOrcaAmount', getU64Encoder()],
+        ['xorcaMintSupply', getU64Encoder()],
+      ]),
+    ],
+    [
+      'RewardsAuthoritySet',
+      getStructEncoder([
+        ['newRewardsAuthority', getAddressEncoder()],
+        ['setBy', getAddressEncoder()],
+      ]),
+    ],
+    [
+      'FeeMinted',
+      getStructEncoder([
+        ['yieldOrcaAmount', getU64Encoder()],
+        ['feeOrcaAmount', getU64Encoder()],
+        ['feeXorcaAmount', getU64Encoder()],
+        ['xorcaMintSupply', getU64Encoder()],
+        ['treasury', getAddressEncoder()],
+      ]),
+    ],
+    [
+      'PendingWithdrawTransferred',
+      getStructEncoder([
+        ['unstaker', getAddressEncoder()],
+        ['withdrawIndex', getU8Encoder()],
+        ['previousOwner', getAddressEncoder()],
+        ['newOwner', getAddressEncoder()],
+      ]),
+    ],
+    [
+      'AccountsMigrated',
+      getStructEncoder([
+        ['migratedCount', getU16Encoder()],
+        ['migratedBy', getAddressEncoder()],
+      ]),
+    ],
+    [
+      'InstantUnstakeFeeSet',
+      getStructEncoder([
+        ['newFeeBps', getU16Encoder()],
+        ['oldFeeBps', getU16Encoder()],
+        ['setBy', getAddressEncoder()],
+      ]),
+    ],
+    [
+      'PerformanceFeeSet',
+      getStructEncoder([
+        ['newFeeBps', getU16Encoder()],
+        ['oldFeeBps', getU16Encoder()],
+        ['setBy', getAddressEncoder()],
+      ]),
+    ],
+    [
+      'TreasurySet',
+      getStructEncoder([
+        ['newTreasury', getAddressEncoder()],
+        ['oldTreasury', getAddressEncoder()],
+        ['setBy', getAddressEncoder()],
+      ]),
+    ],
+    [
+      'MaxVaultOrcaSet',
+      getStructEncoder([
+        ['newMaxVaultOrca', getU64Encoder()],
+        ['oldMaxVaultOrca', getU64Encoder()],
+        ['setBy', getAddressEncoder()],
+      ]),
+    ],
+    [
+      'MaxStakePerTxSet',
+      getStructEncoder([
+        ['newMaxStakePerTx', getU64Encoder()],
+        ['oldMaxStakePerTx', getU64Encoder()],
+        ['setBy', getAddressEncoder()],
+      ]),
+    ],
+    [
+      'MinStakeOrcaSet',
+      getStructEncoder([
+        ['newMinStakeOrca', getU64Encoder()],
+        ['oldMinStakeOrca', getU64Encoder()],
+        ['setBy', getAddressEncoder()],
+      ]),
+    ],
+    [
+      'MinUnstakeXorcaSet',
+      getStructEncoder([
+        ['newMinUnstakeXorca', getU64Encoder()],
+        ['oldMinUnstakeXorca', getU64Encoder()],
+        ['setBy', getAddressEncoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -137,6 +606,8 @@ export function getEventDecoder(): Decoder<Event> {
         ['vaultEscrowedOrcaAmount', getU64Decoder()],
         ['xorcaMintSupply', getU64Decoder()],
         ['xorcaToMint', getU64Decoder()],
+        ['payer', getAddressDecoder()],
+        ['beneficiary', getAddressDecoder()],
       ]),
     ],
     [
@@ -149,6 +620,7 @@ export function getEventDecoder(): Decoder<Event> {
         ['withdrawableOrcaAmount', getU64Decoder()],
         ['coolDownPeriodS', getI64Decoder()],
         ['withdrawIndex', getU8Decoder()],
+        ['stakerWithdrawIndex', getOptionDecoder(getU64Decoder())],
       ]),
     ],
     [
@@ -158,6 +630,8 @@ export function getEventDecoder(): Decoder<Event> {
         ['withdrawableOrcaAmount', getU64Decoder()],
         ['coolDownPeriodS', getI64Decoder()],
         ['withdrawIndex', getU8Decoder()],
+        ['destination', getAddressDecoder()],
+        ['stakerWithdrawIndex', getOptionDecoder(getU64Decoder())],
       ]),
     ],
     [
@@ -167,6 +641,186 @@ export function getEventDecoder(): Decoder<Event> {
         ['setBy', getAddressDecoder()],
       ]),
     ],
+    [
+      'InstantUnstake',
+      getStructDecoder([
+        ['xorcaUnstakeAmount', getU64Decoder()],
+        ['vaultOrcaAmount', getU64Decoder()],
+        ['vaultEscrowedOrcaAmount', getU64Decoder()],
+        ['xorcaMintSupply', getU64Decoder()],
+        ['orcaUnstakeAmount', getU64Decoder()],
+        ['feeOrcaAmount', getU64Decoder()],
+        ['instantUnstakeFeeBps', getU16Decoder()],
+      ]),
+    ],
+    [
+      'CancelUnstake',
+      getStructDecoder([
+        ['withdrawableOrcaAmount', getU64Decoder()],
+        ['vaultOrcaAmount', getU64Decoder()],
+        ['vaultEscrowedOrcaAmount', getU64Decoder()],
+        ['xorcaMintSupply', getU64Decoder()],
+        ['xorcaToMint', getU64Decoder()],
+        ['withdrawIndex', getU8Decoder()],
+      ]),
+    ],
+    [
+      'WithdrawPartial',
+      getStructDecoder([
+        ['vaultEscrowedOrcaAmount', getU64Decoder()],
+        ['orcaWithdrawAmount', getU64Decoder()],
+        ['remainingOrcaAmount', getU64Decoder()],
+        ['withdrawIndex', getU8Decoder()],
+      ]),
+    ],
+    [
+      'MergePendingWithdraws',
+      getStructDecoder([
+        ['withdrawableOrcaAmount', getU64Decoder()],
+        ['withdrawableTimestamp', getI64Decoder()],
+        ['mergedCount', getU16Decoder()],
+        ['withdrawIndex', getU8Decoder()],
+      ]),
+    ],
+    [
+      'WithdrawMany',
+      getStructDecoder([
+        ['vaultEscrowedOrcaAmount', getU64Decoder()],
+        ['withdrawableOrcaAmount', getU64Decoder()],
+        ['withdrawCount', getU16Decoder()],
+      ]),
+    ],
+    [
+      'PauseFlagsSet',
+      getStructDecoder([
+        ['newPauseFlags', getU16Decoder()],
+        ['oldPauseFlags', getU16Decoder()],
+        ['setBy', getAddressDecoder()],
+      ]),
+    ],
+    [
+      'UpdateAuthorityProposed',
+      getStructDecoder([
+        ['proposedAuthority', getAddressDecoder()],
+        ['proposedBy', getAddressDecoder()],
+      ]),
+    ],
+    [
+      'CoolDownPeriodChangeQueued',
+      getStructDecoder([
+        ['newCoolDownPeriodS', getI64Decoder()],
+        ['activationTimestamp', getI64Decoder()],
+        ['queuedBy', getAddressDecoder()],
+      ]),
+    ],
+    [
+      'PendingConfigApplied',
+      getStructDecoder([
+        ['oldCoolDownPeriodS', getI64Decoder()],
+        ['newCoolDownPeriodS', getI64Decoder()],
+      ]),
+    ],
+    [
+      'RewardsDeposited',
+      getStructDecoder([
+        ['rewardsAmount', getU64Decoder()],
+        ['vaultOrcaAmount', getU64Decoder()],
+        ['vestingRewardsAmount', getU64Decoder()],
+        ['vestingEndTimestamp', getI64Decoder()],
+        ['totalRewardsDeposited', getU64Decoder()],
+        ['nonEscrowedOrcaAmount', getU64Decoder()],
+        ['xorcaMintSupply', getU64Decoder()],
+      ]),
+    ],
+    [
+      'RewardsAuthoritySet',
+      getStructDecoder([
+        ['newRewardsAuthority', getAddressDecoder()],
+        ['setBy', getAddressDecoder()],
+      ]),
+    ],
+    [
+      'FeeMinted',
+      getStructDecoder([
+        ['yieldOrcaAmount', getU64Decoder()],
+        ['feeOrcaAmount', getU64Decoder()],
+        ['feeXorcaAmount', getU64Decoder()],
+        ['xorcaMintSupply', getU64Decoder()],
+        ['treasury', getAddressDecoder()],
+      ]),
+    ],
+    [
+      'PendingWithdrawTransferred',
+      getStructDecoder([
+        ['unstaker', getAddressDecoder()],
+        ['withdrawIndex', getU8Decoder()],
+        ['previousOwner', getAddressDecoder()],
+        ['newOwner', getAddressDecoder()],
+      ]),
+    ],
+    [
+      'AccountsMigrated',
+      getStructDecoder([
+        ['migratedCount', getU16Decoder()],
+        ['migratedBy', getAddressDecoder()],
+      ]),
+    ],
+    [
+      'InstantUnstakeFeeSet',
+      getStructDecoder([
+        ['newFeeBps', getU16Decoder()],
+        ['oldFeeBps', getU16Decoder()],
+        ['setBy', getAddressDecoder()],
+      ]),
+    ],
+    [
+      'PerformanceFeeSet',
+      getStructDecoder([
+        ['newFeeBps', getU16Decoder()],
+        ['oldFeeBps', getU16Decoder()],
+        ['setBy', getAddressDecoder()],
+      ]),
+    ],
+    [
+      'TreasurySet',
+      getStructDecoder([
+        ['newTreasury', getAddressDecoder()],
+        ['oldTreasury', getAddressDecoder()],
+        ['setBy', getAddressDecoder()],
+      ]),
+    ],
+    [
+      'MaxVaultOrcaSet',
+      getStructDecoder([
+        ['newMaxVaultOrca', getU64Decoder()],
+        ['oldMaxVaultOrca', getU64Decoder()],
+        ['setBy', getAddressDecoder()],
+      ]),
+    ],
+    [
+      'MaxStakePerTxSet',
+      getStructDecoder([
+        ['newMaxStakePerTx', getU64Decoder()],
+        ['oldMaxStakePerTx', getU64Decoder()],
+        ['setBy', getAddressDecoder()],
+      ]),
+    ],
+    [
+      'MinStakeOrcaSet',
+      getStructDecoder([
+        ['newMinStakeOrca', getU64Decoder()],
+        ['oldMinStakeOrca', getU64Decoder()],
+        ['setBy', getAddressDecoder()],
+      ]),
+    ],
+    [
+      'MinUnstakeXorcaSet',
+      getStructDecoder([
+        ['newMinUnstakeXorca', getU64Decoder()],
+        ['oldMinUnstakeXorca', getU64Decoder()],
+        ['setBy', getAddressDecoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -191,6 +845,90 @@ export function event(
   kind: 'UpdateAuthoritySet',
   data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'UpdateAuthoritySet'>
 ): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'UpdateAuthoritySet'>;
+export function event(
+  kind: 'InstantUnstake',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'InstantUnstake'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'InstantUnstake'>;
+export function event(
+  kind: 'CancelUnstake',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'CancelUnstake'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'CancelUnstake'>;
+export function event(
+  kind: 'WithdrawPartial',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'WithdrawPartial'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'WithdrawPartial'>;
+export function event(
+  kind: 'MergePendingWithdraws',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'MergePendingWithdraws'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'MergePendingWithdraws'>;
+export function event(
+  kind: 'WithdrawMany',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'WithdrawMany'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'WithdrawMany'>;
+export function event(
+  kind: 'PauseFlagsSet',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'PauseFlagsSet'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'PauseFlagsSet'>;
+export function event(
+  kind: 'UpdateAuthorityProposed',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'UpdateAuthorityProposed'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'UpdateAuthorityProposed'>;
+export function event(
+  kind: 'CoolDownPeriodChangeQueued',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'CoolDownPeriodChangeQueued'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'CoolDownPeriodChangeQueued'>;
+export function event(
+  kind: 'PendingConfigApplied',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'PendingConfigApplied'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'PendingConfigApplied'>;
+export function event(
+  kind: 'RewardsDeposited',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'RewardsDeposited'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'RewardsDeposited'>;
+export function event(
+  kind: 'RewardsAuthoritySet',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'RewardsAuthoritySet'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'RewardsAuthoritySet'>;
+export function event(
+  kind: 'FeeMinted',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'FeeMinted'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'FeeMinted'>;
+export function event(
+  kind: 'PendingWithdrawTransferred',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'PendingWithdrawTransferred'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'PendingWithdrawTransferred'>;
+export function event(
+  kind: 'AccountsMigrated',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'AccountsMigrated'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'AccountsMigrated'>;
+export function event(
+  kind: 'InstantUnstakeFeeSet',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'InstantUnstakeFeeSet'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'InstantUnstakeFeeSet'>;
+export function event(
+  kind: 'PerformanceFeeSet',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'PerformanceFeeSet'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'PerformanceFeeSet'>;
+export function event(
+  kind: 'TreasurySet',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'TreasurySet'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'TreasurySet'>;
+export function event(
+  kind: 'MaxVaultOrcaSet',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'MaxVaultOrcaSet'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'MaxVaultOrcaSet'>;
+export function event(
+  kind: 'MaxStakePerTxSet',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'MaxStakePerTxSet'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'MaxStakePerTxSet'>;
+export function event(
+  kind: 'MinStakeOrcaSet',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'MinStakeOrcaSet'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'MinStakeOrcaSet'>;
+export function event(
+  kind: 'MinUnstakeXorcaSet',
+  data: GetDiscriminatedUnionVariantContent<EventArgs, '__kind', 'MinUnstakeXorcaSet'>
+): GetDiscriminatedUnionVariant<EventArgs, '__kind', 'MinUnstakeXorcaSet'>;
 export function event<K extends EventArgs['__kind'], Data>(kind: K, data?: Data) {
   return Array.isArray(data) ? { __kind: kind, fields: data } : { __kind: kind, ...(data ?? {}) };
 }
@@ -8,6 +8,7 @@
 
 export * from './accountDiscriminator';
 export * from './event';
+export * from './programData';
 export * from './stateUpdateInstruction';
 export * from './tokenAccount';
 export * from './tokenAccountState';
@@ -16,6 +16,10 @@ import {
   getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
   type Address,
   type Codec,
   type Decoder,
@@ -26,16 +30,46 @@ import {
 
 export type StateUpdateInstruction =
   | { __kind: 'UpdateCoolDownPeriod'; newCoolDownPeriodS: bigint }
-  | { __kind: 'UpdateUpdateAuthority'; newAuthority: Address };
+  | { __kind: 'UpdateUpdateAuthority'; newAuthority: Address }
+  | { __kind: 'UpdateInstantUnstakeFee'; newInstantUnstakeFeeBps: number }
+  | { __kind: 'SetPauseFlags'; newPauseFlags: number }
+  | { __kind: 'ProposeUpdateAuthority'; newAuthority: Address }
+  | { __kind: 'UpdateRewardsAuthority'; newRewardsAuthority: Address }
+  | { __kind: 'UpdatePerformanceFee'; newPerformanceFeeBps: number }
+  | { __kind: 'UpdateTreasury'; newTreasury: Address }
+  | { __kind: 'UpdateMaxVaultOrca'; newMaxVaultOrca: bigint }
+  | { __kind: 'UpdateMaxStakePerTx'; newMaxStakePerTx: bigint }
+  | { __kind: 'UpdateMinStakeOrca'; newMinStakeOrca: bigint }
+  | { __kind: 'UpdateMinUnstakeXorca'; newMinUnstakeXorca: bigint };
 
 export type StateUpdateInstructionArgs =
   | { __kind: 'UpdateCoolDownPeriod'; newCoolDownPeriodS: number | bigint }
-  | { __kind: 'UpdateUpdateAuthority'; newAuthority: Address };
+  | { __kind: 'UpdateUpdateAuthority'; newAuthority: Address }
+  | { __kind: 'UpdateInstantUnstakeFee'; newInstantUnstakeFeeBps: number }
+  | { __kind: 'SetPauseFlags'; newPauseFlags: number }
+  | { __kind: 'ProposeUpdateAuthority'; newAuthority: Address }
+  | { __kind: 'UpdateRewardsAuthority'; newRewardsAuthority: Address }
+  | { __kind: 'UpdatePerformanceFee'; newPerformanceFeeBps: number }
+  | { __kind: 'UpdateTreasury'; newTreasury: Address }
+  | { __kind: 'UpdateMaxVaultOrca'; newMaxVaultOrca: number | bigint }
+  | { __kind: 'UpdateMaxStakePerTx'; newMaxStakePerTx: number | bigint }
+  | { __kind: 'UpdateMinStakeOrca'; newMinStakeOrca: number | bigint }
+  | { __kind: 'UpdateMinUnstakeXorca'; newMinUnstakeXorca: number | bigint };
 
 export function getStateUpdateInstructionEncoder(): Encoder<StateUpdateInstructionArgs> {
   return getDiscriminatedUnionEncoder([
     ['UpdateCoolDownPeriod', getStructEncoder([['newCoolDownPeriodS', getI64Encoder()]])],
     ['UpdateUpdateAuthority', getStructEncoder([['newAuthority', getAddressEncoder()]])],
+    ['UpdateInstantUnstakeFee', getStructEncoder([['newInstantUnstakeFeeBps', getU16Encoder()]])],
+    ['SetPauseFlags', getStructEncoder([['newPauseFlags', getU16Encoder()]])],
+    ['ProposeUpdateAuthority', getStructEncoder([['newAuthority', getAddressEncoder()]])],
+    ['UpdateRewardsAuthority', getStructEncoder([['newRewardsAuthority', getAddressEncoder()]])],
+    ['UpdatePerformanceFee', getStructEncoder([['newPerformanceFeeBps', getU16Encoder()]])],
+    ['UpdateTreasury', getStructEncoder([['newTreasury', getAddressEncoder()]])],
+    ['UpdateMaxVaultOrca', getStructEncoder([['newMaxVaultOrca', getU64Encoder()]])],
+    ['UpdateMaxStakePerTx', getStructEncoder([['newMaxStakePerTx', getU64Encoder()]])],
+    ['UpdateMinStakeOrca', getStructEncoder([['newMinStakeOrca', getU64Encoder()]])],
+    ['UpdateMinUnstakeXorca', getStructEncoder([['newMinUnstakeXorca', getU64Encoder()]])],
   ]);
 }
 
@@ -43,6 +77,16 @@ export function getStateUpdateInstructionDecoder(): Decoder<StateUpdateInstructi
   return getDiscriminatedUnionDecoder([
     ['UpdateCoolDownPeriod', getStructDecoder([['newCoolDownPeriodS', getI64Decoder()]])],
     ['UpdateUpdateAuthority', getStructDecoder([['newAuthority', getAddressDecoder()]])],
+    ['UpdateInstantUnstakeFee', getStructDecoder([['newInstantUnstakeFeeBps', getU16Decoder()]])],
+    ['SetPauseFlags', getStructDecoder([['newPauseFlags', getU16Decoder()]])],
+    ['ProposeUpdateAuthority', getStructDecoder([['newAuthority', getAddressDecoder()]])],
+    ['UpdateRewardsAuthority', getStructDecoder([['newRewardsAuthority', getAddressDecoder()]])],
+    ['UpdatePerformanceFee', getStructDecoder([['newPerformanceFeeBps', getU16Decoder()]])],
+    ['UpdateTreasury', getStructDecoder([['newTreasury', getAddressDecoder()]])],
+    ['UpdateMaxVaultOrca', getStructDecoder([['newMaxVaultOrca', getU64Decoder()]])],
+    ['UpdateMaxStakePerTx', getStructDecoder([['newMaxStakePerTx', getU64Decoder()]])],
+    ['UpdateMinStakeOrca', getStructDecoder([['newMinStakeOrca', getU64Decoder()]])],
+    ['UpdateMinUnstakeXorca', getStructDecoder([['newMinUnstakeXorca', getU64Decoder()]])],
   ]);
 }
 
@@ -70,6 +114,78 @@ export function stateUpdateInstruction(
     'UpdateUpdateAuthority'
   >
 ): GetDiscriminatedUnionVariant<StateUpdateInstructionArgs, '__kind', 'UpdateUpdateAuthority'>;
+export function stateUpdateInstruction(
+  kind: 'UpdateInstantUnstakeFee',
+  data: GetDiscriminatedUnionVariantContent<
+    StateUpdateInstructionArgs,
+    '__kind',
+    'UpdateInstantUnstakeFee'
+  >
+): GetDiscriminatedUnionVariant<StateUpdateInstructionArgs, '__kind', 'UpdateInstantUnstakeFee'>;
+export function stateUpdateInstruction(
+  kind: 'SetPauseFlags',
+  data: GetDiscriminatedUnionVariantContent<StateUpdateInstructionArgs, '__kind', 'SetPauseFlags'>
+): GetDiscriminatedUnionVariant<StateUpdateInstructionArgs, '__kind', 'SetPauseFlags'>;
+export function stateUpdateInstruction(
+  kind: 'ProposeUpdateAuthority',
+  data: GetDiscriminatedUnionVariantContent<
+    StateUpdateInstructionArgs,
+    '__kind',
+    'ProposeUpdateAuthority'
+  >
+): GetDiscriminatedUnionVariant<StateUpdateInstructionArgs, '__kind', 'ProposeUpdateAuthority'>;
+export function stateUpdateInstruction(
+  kind: 'UpdateRewardsAuthority',
+  data: GetDiscriminatedUnionVariantContent<
+    StateUpdateInstructionArgs,
+    '__kind',
+    'UpdateRewardsAuthority'
+  >
+): GetDiscriminatedUnionVariant<StateUpdateInstructionArgs, '__kind', 'UpdateRewardsAuthority'>;
+export function stateUpdateInstruction(
+  kind: 'UpdatePerformanceFee',
+  data: GetDiscriminatedUnionVariantContent<
+    StateUpdateInstructionArgs,
+    '__kind',
+    'UpdatePerformanceFee'
+  >
+): GetDiscriminatedUnionVariant<StateUpdateInstructionArgs, '__kind', 'UpdatePerformanceFee'>;
+export function stateUpdateInstruction(
+  kind: 'UpdateTreasury',
+  data: GetDiscriminatedUnionVariantContent<StateUpdateInstructionArgs, '__kind', 'UpdateTreasury'>
+): GetDiscriminatedUnionVariant<StateUpdateInstructionArgs, '__kind', 'UpdateTreasury'>;
+export function stateUpdateInstruction(
+  kind: 'UpdateMaxVaultOrca',
+  data: GetDiscriminatedUnionVariantContent<
+    StateUpdateInstructionArgs,
+    '__kind',
+    'UpdateMaxVaultOrca'
+  >
+): GetDiscriminatedUnionVariant<StateUpdateInstructionArgs, '__kind', 'UpdateMaxVaultOrca'>;
+export function stateUpdateInstruction(
+  kind: 'UpdateMaxStakePerTx',
+  data: GetDiscriminatedUnionVariantContent<
+    StateUpdateInstructionArgs,
+    '__kind',
+    'UpdateMaxStakePerTx'
+  >
+): GetDiscriminatedUnionVariant<StateUpdateInstructionArgs, '__kind', 'UpdateMaxStakePerTx'>;
+export function stateUpdateInstruction(
+  kind: 'UpdateMinStakeOrca',
+  data: GetDiscriminatedUnionVariantContent<
+    StateUpdateInstructionArgs,
+    '__kind',
+    'UpdateMinStakeOrca'
+  >
+): GetDiscriminatedUnionVariant<StateUpdateInstructionArgs, '__kind', 'UpdateMinStakeOrca'>;
+export function stateUpdateInstruction(
+  kind: 'UpdateMinUnstakeXorca',
+  data: GetDiscriminatedUnionVariantContent<
+    StateUpdateInstructionArgs,
+    '__kind',
+    'UpdateMinUnstakeXorca'
+  >
+): GetDiscriminatedUnionVariant<StateUpdateInstructionArgs, '__kind', 'UpdateMinUnstakeXorca'>;
 export function stateUpdateInstruction<K extends StateUpdateInstructionArgs['__kind'], Data>(
   kind: K,
   data?: Data
@@ -0,0 +1,95 @@
+use crate::generated::instructions::{WithdrawMany, WithdrawManyBuilder};
+use crate::pda::find_pending_withdraw_pda;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+
+fn pending_withdraw_account_metas(
+    unstaker: &Pubkey,
+    withdraw_indexes: &[u8],
+) -> Result<Vec<AccountMeta>, ProgramError> {
+    withdraw_indexes
+        .iter()
+        .map(|withdraw_index| {
+            let (pending_withdraw, _) = find_pending_withdraw_pda(unstaker, withdraw_index)?;
+            Ok(AccountMeta::new(pending_withdraw, false))
+        })
+        .collect()
+}
+
+impl WithdrawMany {
+    /// Builds the instruction with the pending withdraw PDAs of `unstaker_account` for the given indexes.
+    pub fn instruction_with_withdraw_indexes(
+        &self,
+        withdraw_indexes: &[u8],
+    ) -> Result<Instruction, ProgramError> {
+        let remaining_accounts =
+            pending_withdraw_account_metas(&self.unstaker_account, withdraw_indexes)?;
+        Ok(self.instruction_with_remaining_accounts(&remaining_accounts))
+    }
+}
+
+impl WithdrawManyBuilder {
+    /// Adds the pending withdraw PDAs of `unstaker` for the given indexes as remaining accounts.
+    pub fn withdraw_indexes(
+        &mut self,
+        unstaker: &Pubkey,
+        withdraw_indexes: &[u8],
+    ) -> Result<&mut Self, ProgramError> {
+        let remaining_accounts = pending_withdraw_account_metas(unstaker, withdraw_indexes)?;
+        Ok(self.add_remaining_accounts(&remaining_accounts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::pubkey;
+
+    #[test]
+    fn test_withdraw_many_builder_adds_pending_withdraws_in_order() {
+        let unstaker = pubkey!("A1tYHa3233WKDX5fZuZNmHMUVTSB12sR1RoVeGT8XV85");
+        let ix = WithdrawManyBuilder::new()
+            .unstaker_account(unstaker)
+            .state_account(Pubkey::new_unique())
+            .unstaker_orca_ata(Pubkey::new_unique())
+            .vault_account(Pubkey::new_unique())
+            .orca_mint_account(Pubkey::new_unique())
+            .system_program_account(Pubkey::new_unique())
+            .token_program_account(Pubkey::new_unique())
+            .withdraw_indexes(&unstaker, &[0, 1])
+            .unwrap()
+            .instruction();
+        assert_eq!(ix.accounts.len(), 9);
+        assert_eq!(
+            ix.accounts[7].pubkey,
+            pubkey!("7hA1R5rPjcj6m7G2HcxnQ82aNumKT9FKQ6ALS2yYXeq6")
+        );
+        assert_eq!(
+            ix.accounts[8].pubkey,
+            pubkey!("D6i7r2sBNozTvdKuSSE2HdfqPb9sTd7gNd8jsCtR8quW")
+        );
+        assert!(ix.accounts[7].is_writable && !ix.accounts[7].is_signer);
+    }
+
+    #[test]
+    fn test_withdraw_many_instruction_with_withdraw_indexes() {
+        let unstaker = pubkey!("A1tYHa3233WKDX5fZuZNmHMUVTSB12sR1RoVeGT8XV85");
+        let ix = WithdrawMany {
+            unstaker_account: unstaker,
+            state_account: Pubkey::new_unique(),
+            unstaker_orca_ata: Pubkey::new_unique(),
+            vault_account: Pubkey::new_unique(),
+            orca_mint_account: Pubkey::new_unique(),
+            system_program_account: Pubkey::new_unique(),
+            token_program_account: Pubkey::new_unique(),
+        }
+        .instruction_with_withdraw_indexes(&[1])
+        .unwrap();
+        assert_eq!(ix.accounts.len(), 8);
+        assert_eq!(
+            ix.accounts[7].pubkey,
+            pubkey!("D6i7r2sBNozTvdKuSSE2HdfqPb9sTd7gNd8jsCtR8quW")
+        );
+    }
+}
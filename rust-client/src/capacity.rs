@@ -0,0 +1,61 @@
+//! Deposit cap helpers mirroring the checks `Stake` and `StakeForExactXorca` run on-chain,
+//! so clients can show remaining capacity and warn before a stake would fail.
+
+use crate::State;
+
+/// The largest ORCA amount a single stake can deposit right now, or `None` when neither
+/// `max_vault_orca` nor `max_stake_per_tx` is set. `vault_orca_amount` is the current
+/// balance of the vault token account, including escrowed ORCA.
+pub fn remaining_stake_capacity(state: &State, vault_orca_amount: u64) -> Option<u64> {
+    calculate_remaining_stake_capacity(
+        state.max_vault_orca,
+        state.max_stake_per_tx,
+        vault_orca_amount,
+    )
+}
+
+/// Same as [`remaining_stake_capacity`] for raw state values. A cap of 0 means uncapped.
+pub fn calculate_remaining_stake_capacity(
+    max_vault_orca: u64,
+    max_stake_per_tx: u64,
+    vault_orca_amount: u64,
+) -> Option<u64> {
+    let vault_capacity =
+        (max_vault_orca != 0).then(|| max_vault_orca.saturating_sub(vault_orca_amount));
+    let per_tx_capacity = (max_stake_per_tx != 0).then_some(max_stake_per_tx);
+
+    match (vault_capacity, per_tx_capacity) {
+        (Some(vault), Some(per_tx)) => Some(vault.min(per_tx)),
+        (vault, per_tx) => vault.or(per_tx),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uncapped_has_no_limit() {
+        assert_eq!(calculate_remaining_stake_capacity(0, 0, 1_000), None);
+    }
+
+    #[test]
+    fn vault_cap_limits_capacity() {
+        assert_eq!(calculate_remaining_stake_capacity(1_000, 0, 400), Some(600));
+        // A vault already over the cap has no capacity left
+        assert_eq!(calculate_remaining_stake_capacity(1_000, 0, 1_200), Some(0));
+    }
+
+    #[test]
+    fn tighter_cap_wins() {
+        assert_eq!(
+            calculate_remaining_stake_capacity(1_000, 100, 400),
+            Some(100)
+        );
+        assert_eq!(
+            calculate_remaining_stake_capacity(1_000, 700, 400),
+            Some(600)
+        );
+        assert_eq!(calculate_remaining_stake_capacity(0, 100, 400), Some(100));
+    }
+}
@@ -0,0 +1,54 @@
+//! Addresses of the cluster the client is built for.
+//!
+//! They default to mainnet. Clients of a devnet or localnet deployment set all three at build
+//! time, with the same values the program was built with:
+//!
+//! ```sh
+//! XORCA_PROGRAM_ID=<program> XORCA_ORCA_MINT=<orca mint> XORCA_XORCA_MINT=<xorca mint> cargo build
+//! ```
+//!
+//! The override replaces the generated `XORCA_STAKING_PROGRAM_ID`, so instruction builders,
+//! account owner checks and PDA helpers all follow the configured cluster.
+
+use solana_pubkey::Pubkey;
+
+pub const MAINNET_ORCA_MINT: &str = "orcaEKTdK7LKz57vaAYr9QeNsVEPfiu6QeMU1kektZE";
+pub const MAINNET_XORCA_MINT: &str = "xorcaYqbXUNz3474ubUMJAdu2xgPsew3rUCe5ughT3N";
+
+// A partial override would mix clusters, e.g. devnet instructions against mainnet mints
+const _: () = assert!(
+    option_env!("XORCA_PROGRAM_ID").is_some() == option_env!("XORCA_ORCA_MINT").is_some()
+        && option_env!("XORCA_PROGRAM_ID").is_some() == option_env!("XORCA_XORCA_MINT").is_some(),
+    "XORCA_PROGRAM_ID, XORCA_ORCA_MINT and XORCA_XORCA_MINT must be set together"
+);
+
+pub const ORCA_MINT_ADDRESS: &str = match option_env!("XORCA_ORCA_MINT") {
+    Some(address) => address,
+    None => MAINNET_ORCA_MINT,
+};
+pub const XORCA_MINT_ADDRESS: &str = match option_env!("XORCA_XORCA_MINT") {
+    Some(address) => address,
+    None => MAINNET_XORCA_MINT,
+};
+
+/// `xorca_staking_program` program ID of the configured cluster. Mainnet uses the generated ID.
+pub const XORCA_STAKING_PROGRAM_ID: Pubkey = match option_env!("XORCA_PROGRAM_ID") {
+    Some(address) => Pubkey::from_str_const(address),
+    None => crate::generated::programs::XORCA_STAKING_PROGRAM_ID,
+};
+pub const ORCA_MINT_ID: Pubkey = Pubkey::from_str_const(ORCA_MINT_ADDRESS);
+pub const XORCA_MINT_ID: Pubkey = Pubkey::from_str_const(XORCA_MINT_ADDRESS);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cluster_addresses_parse() {
+        if let Some(address) = option_env!("XORCA_PROGRAM_ID") {
+            assert_eq!(XORCA_STAKING_PROGRAM_ID.to_string(), address);
+        }
+        assert_eq!(ORCA_MINT_ID.to_string(), ORCA_MINT_ADDRESS);
+        assert_eq!(XORCA_MINT_ID.to_string(), XORCA_MINT_ADDRESS);
+    }
+}
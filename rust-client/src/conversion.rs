@@ -56,6 +56,106 @@ pub fn convert_xorca_to_orca(
     out_orca.try_into().map_err(|_| ConversionError::Arithmetic)
 }
 
+/// Round-up counterpart of [`convert_orca_to_xorca`]. Returns the smallest xORCA amount
+/// that converts back to at least `orca_amount_to_convert` ORCA.
+pub fn convert_orca_to_xorca_round_up(
+    orca_amount_to_convert: u64,
+    non_escrowed_orca_amount: u64,
+    xorca_supply: u64,
+) -> Result<u64, ConversionError> {
+    if xorca_supply == 0 || non_escrowed_orca_amount == 0 {
+        return Ok(orca_amount_to_convert);
+    }
+
+    let (non_escrowed_with_virtual_offset, xorca_supply_with_virtual_offset) =
+        apply_virtual_offsets(non_escrowed_orca_amount, xorca_supply)?;
+
+    let out_xorca = (orca_amount_to_convert as u128)
+        .checked_mul(xorca_supply_with_virtual_offset)
+        .ok_or(ConversionError::Arithmetic)?
+        .checked_add(non_escrowed_with_virtual_offset - 1)
+        .ok_or(ConversionError::Arithmetic)?
+        .checked_div(non_escrowed_with_virtual_offset)
+        .ok_or(ConversionError::Arithmetic)?;
+
+    out_xorca
+        .try_into()
+        .map_err(|_| ConversionError::Arithmetic)
+}
+
+/// Round-up counterpart of [`convert_xorca_to_orca`]. Returns the smallest ORCA amount
+/// that stakes into at least `xorca_amount_to_convert` xORCA. An empty vault stakes 1:1.
+pub fn convert_xorca_to_orca_round_up(
+    xorca_amount_to_convert: u64,
+    non_escrowed_orca_amount: u64,
+    xorca_supply: u64,
+) -> Result<u64, ConversionError> {
+    if xorca_supply == 0 || non_escrowed_orca_amount == 0 {
+        return Ok(xorca_amount_to_convert);
+    }
+
+    let (non_escrowed_with_virtual_offset, xorca_supply_with_virtual_offset) =
+        apply_virtual_offsets(non_escrowed_orca_amount, xorca_supply)?;
+
+    let out_orca = (xorca_amount_to_convert as u128)
+        .checked_mul(non_escrowed_with_virtual_offset)
+        .ok_or(ConversionError::Arithmetic)?
+        .checked_add(xorca_supply_with_virtual_offset - 1)
+        .ok_or(ConversionError::Arithmetic)?
+        .checked_div(xorca_supply_with_virtual_offset)
+        .ok_or(ConversionError::Arithmetic)?;
+
+    out_orca.try_into().map_err(|_| ConversionError::Arithmetic)
+}
+
+/// Compute the instant unstake fee for a gross ORCA amount, mirroring the on-chain
+/// program. The fee is rounded up so that dust stays in the vault.
+pub fn calculate_instant_unstake_fee(
+    orca_amount: u64,
+    instant_unstake_fee_bps: u16,
+) -> Result<u64, ConversionError> {
+    if instant_unstake_fee_bps > BPS_DENOMINATOR {
+        return Err(ConversionError::Arithmetic);
+    }
+
+    let bps_denominator = BPS_DENOMINATOR as u128;
+    let fee = (orca_amount as u128)
+        .checked_mul(instant_unstake_fee_bps as u128)
+        .ok_or(ConversionError::Arithmetic)?
+        .checked_add(bps_denominator - 1)
+        .ok_or(ConversionError::Arithmetic)?
+        .checked_div(bps_denominator)
+        .ok_or(ConversionError::Arithmetic)?;
+
+    fee.try_into().map_err(|_| ConversionError::Arithmetic)
+}
+
+/// Compute the portion of deposited rewards that is still vesting, mirroring the on-chain
+/// program. Subtract it from the non-escrowed vault amount before converting.
+pub fn calculate_unvested_rewards_amount(
+    vesting_rewards_amount: u64,
+    vesting_start_timestamp: i64,
+    vesting_end_timestamp: i64,
+    current_unix_timestamp: i64,
+) -> Result<u64, ConversionError> {
+    if vesting_rewards_amount == 0 || current_unix_timestamp >= vesting_end_timestamp {
+        return Ok(0);
+    }
+    if current_unix_timestamp <= vesting_start_timestamp {
+        return Ok(vesting_rewards_amount);
+    }
+
+    let remaining_s = (vesting_end_timestamp - current_unix_timestamp) as u128;
+    let duration_s = (vesting_end_timestamp - vesting_start_timestamp) as u128;
+    let unvested = (vesting_rewards_amount as u128)
+        .checked_mul(remaining_s)
+        .ok_or(ConversionError::Arithmetic)?
+        .checked_div(duration_s)
+        .ok_or(ConversionError::Arithmetic)?;
+
+    unvested.try_into().map_err(|_| ConversionError::Arithmetic)
+}
+
 // ----------------------------------
 // ERROR
 // ----------------------------------
@@ -77,6 +177,8 @@ pub enum ConversionError {
 pub const VIRTUAL_XORCA_SUPPLY: u128 = 100;
 /// Virtual ORCA offset applied to non-escrowed ORCA to defend against vault inflation.
 pub const VIRTUAL_NON_ESCROWED_ORCA_AMOUNT: u128 = 100;
+/// Denominator for all basis point values stored in the program state.
+pub const BPS_DENOMINATOR: u16 = 10_000;
 
 fn apply_virtual_offsets(
     non_escrowed_orca_amount: u64,
@@ -143,4 +245,80 @@ mod tests {
         let err = convert_orca_to_xorca(u64::MAX, u64::MAX, u64::MAX).unwrap_err();
         assert_eq!(err, ConversionError::Arithmetic);
     }
+
+    #[test]
+    fn orca_to_xorca_round_up_nominal() {
+        // 100_000_000_000 * 200_000_000_100 / 400_000_000_100 = 50_000_000_012.49... -> 50_000_000_013
+        let out = convert_orca_to_xorca_round_up(100_000_000_000, 400_000_000_000, 200_000_000_000)
+            .unwrap();
+        assert_eq!(out, 50_000_000_013);
+        // Burning the rounded-up xORCA amount pays out at least the requested ORCA
+        let orca = convert_xorca_to_orca(out, 400_000_000_000, 200_000_000_000).unwrap();
+        assert!(orca >= 100_000_000_000);
+    }
+
+    #[test]
+    fn xorca_to_orca_round_up_nominal() {
+        // 50_000_000_000 * 500_000_000_100 / 250_000_000_100 = 99_999_999_980.00... -> 99_999_999_981
+        let out = convert_xorca_to_orca_round_up(50_000_000_000, 500_000_000_000, 250_000_000_000)
+            .unwrap();
+        assert_eq!(out, 99_999_999_981);
+        // Staking the rounded-up ORCA amount mints at least the requested xORCA
+        let xorca = convert_orca_to_xorca(out, 500_000_000_000, 250_000_000_000).unwrap();
+        assert!(xorca >= 50_000_000_000);
+    }
+
+    #[test]
+    fn round_up_is_exact_on_even_division() {
+        assert_eq!(
+            convert_orca_to_xorca_round_up(1_000, 900, 900).unwrap(),
+            1_000
+        );
+        assert_eq!(
+            convert_xorca_to_orca_round_up(1_000, 900, 900).unwrap(),
+            1_000
+        );
+        assert_eq!(convert_xorca_to_orca_round_up(10, 0, 0).unwrap(), 10);
+        assert_eq!(convert_xorca_to_orca_round_up(10, 0, 1_000).unwrap(), 10);
+    }
+
+    #[test]
+    fn unvested_rewards_vest_linearly() {
+        assert_eq!(
+            calculate_unvested_rewards_amount(1_000, 100, 200, 100).unwrap(),
+            1_000
+        );
+        assert_eq!(
+            calculate_unvested_rewards_amount(1_000, 100, 200, 125).unwrap(),
+            750
+        );
+        assert_eq!(
+            calculate_unvested_rewards_amount(1_000, 100, 200, 200).unwrap(),
+            0
+        );
+        assert_eq!(
+            calculate_unvested_rewards_amount(0, 100, 200, 150).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn instant_unstake_fee_rounds_up() {
+        // 0.3% of 1_000_001 = 3_000.003 -> 3_001
+        assert_eq!(calculate_instant_unstake_fee(1_000_001, 30).unwrap(), 3_001);
+        assert_eq!(calculate_instant_unstake_fee(1_000_000, 30).unwrap(), 3_000);
+        assert_eq!(calculate_instant_unstake_fee(1_000_000, 0).unwrap(), 0);
+        assert_eq!(
+            calculate_instant_unstake_fee(1_000_000, 10_000).unwrap(),
+            1_000_000
+        );
+    }
+
+    #[test]
+    fn instant_unstake_fee_rejects_bps_above_denominator() {
+        assert_eq!(
+            calculate_instant_unstake_fee(1_000_000, 10_001).unwrap_err(),
+            ConversionError::Arithmetic
+        );
+    }
 }
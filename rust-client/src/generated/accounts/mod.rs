@@ -6,7 +6,9 @@
 //!
 
 pub(crate) mod r#pending_withdraw;
+pub(crate) mod r#staker_account;
 pub(crate) mod r#state;
 
 pub use self::r#pending_withdraw::*;
+pub use self::r#staker_account::*;
 pub use self::r#state::*;
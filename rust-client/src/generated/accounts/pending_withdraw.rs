@@ -14,7 +14,9 @@ use solana_pubkey::Pubkey;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PendingWithdraw {
     pub discriminator: AccountDiscriminator,
-    pub padding1: [u8; 5],
+    pub staker_indexed: u8,
+    pub version: u8,
+    pub padding1: [u8; 3],
     pub bump: u8,
     pub withdraw_index: u8,
     #[cfg_attr(
@@ -24,15 +26,20 @@ pub struct PendingWithdraw {
     pub unstaker: Pubkey,
     pub withdrawable_orca_amount: u64,
     pub withdrawable_timestamp: i64,
-    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub padding2: [u8; 968],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub owner: Pubkey,
+    pub staker_withdraw_index: u64,
+    pub padding2: [u8; 32],
 }
 
 pub const PENDING_WITHDRAW_DISCRIMINATOR: AccountDiscriminator =
     AccountDiscriminator::PendingWithdraw;
 
 impl PendingWithdraw {
-    pub const LEN: usize = 1024;
+    pub const LEN: usize = 128;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
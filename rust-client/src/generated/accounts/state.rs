@@ -14,7 +14,8 @@ use solana_pubkey::Pubkey;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct State {
     pub discriminator: AccountDiscriminator,
-    pub padding1: [u8; 5],
+    pub version: u8,
+    pub padding1: [u8; 4],
     pub bump: u8,
     pub vault_bump: u8,
     pub escrowed_orca_amount: u64,
@@ -24,8 +25,40 @@ pub struct State {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub update_authority: Pubkey,
+    pub instant_unstake_fee_bps: u16,
+    pub pause_flags: u16,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pending_update_authority: Pubkey,
+    pub padding3: [u8; 4],
+    pub pending_cool_down_period_s: i64,
+    pub pending_config_activation_timestamp: i64,
+    pub vesting_rewards_amount: u64,
+    pub vesting_start_timestamp: i64,
+    pub vesting_end_timestamp: i64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub rewards_authority: Pubkey,
+    pub total_rewards_deposited: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub treasury: Pubkey,
+    pub fee_checkpoint_orca_amount: u64,
+    pub fee_checkpoint_xorca_supply: u64,
+    pub performance_fee_bps: u16,
+    pub padding4: [u8; 6],
+    pub max_vault_orca: u64,
+    pub max_stake_per_tx: u64,
+    pub min_stake_orca: u64,
+    pub min_unstake_xorca: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub padding2: [u8; 1992],
+    pub padding2: [u8; 1784],
 }
 
 pub const STATE_DISCRIMINATOR: AccountDiscriminator = AccountDiscriminator::State;
@@ -49,8 +49,8 @@ pub enum XorcaStakingProgramError {
     /// 6012 - Event serialization failed
     #[error("Event serialization failed")]
     EmitEventError = 0x177C,
-    /// 6013 - Invalid cooldown period: must be non-negative
-    #[error("Invalid cooldown period: must be non-negative")]
+    /// 6013 - Invalid cooldown period: must be non-negative and not exceed the maximum
+    #[error("Invalid cooldown period: must be non-negative and not exceed the maximum")]
     InvalidCoolDownPeriod = 0x177D,
     /// 6014 - Stake amount too small to mint any xORCA
     #[error("Stake amount too small to mint any xORCA")]
@@ -70,6 +70,63 @@ pub enum XorcaStakingProgramError {
     /// 6019 - Unauthorized deployer access
     #[error("Unauthorized deployer access")]
     UnauthorizedDeployerAccess = 0x1783,
+    /// 6020 - Invalid instant unstake fee: must not exceed 10000 bps
+    #[error("Invalid instant unstake fee: must not exceed 10000 bps")]
+    InvalidInstantUnstakeFee = 0x1784,
+    /// 6021 - Invalid withdraw amount: must be non-zero and not exceed the pending withdraw
+    #[error("Invalid withdraw amount: must be non-zero and not exceed the pending withdraw")]
+    InvalidWithdrawAmount = 0x1785,
+    /// 6022 - Slippage exceeded: output is below the requested minimum
+    #[error("Slippage exceeded: output is below the requested minimum")]
+    SlippageExceeded = 0x1786,
+    /// 6023 - Transaction deadline exceeded
+    #[error("Transaction deadline exceeded")]
+    DeadlineExceeded = 0x1787,
+    /// 6024 - Program is paused for this instruction
+    #[error("Program is paused for this instruction")]
+    ProgramPaused = 0x1788,
+    /// 6025 - No pending config change to apply
+    #[error("No pending config change to apply")]
+    NoPendingConfig = 0x1789,
+    /// 6026 - Pending config change is still timelocked
+    #[error("Pending config change is still timelocked")]
+    ConfigTimelockActive = 0x178A,
+    /// 6027 - Invalid vesting period: must be non-negative and not exceed the maximum
+    #[error("Invalid vesting period: must be non-negative and not exceed the maximum")]
+    InvalidVestingPeriod = 0x178B,
+    /// 6028 - Invalid rewards amount: must be non-zero
+    #[error("Invalid rewards amount: must be non-zero")]
+    InvalidRewardsAmount = 0x178C,
+    /// 6029 - Invalid performance fee: must not exceed 10000 bps
+    #[error("Invalid performance fee: must not exceed 10000 bps")]
+    InvalidPerformanceFee = 0x178D,
+    /// 6030 - Stake would exceed the vault ORCA cap
+    #[error("Stake would exceed the vault ORCA cap")]
+    MaxVaultOrcaExceeded = 0x178E,
+    /// 6031 - Stake amount exceeds the per-transaction cap
+    #[error("Stake amount exceeds the per-transaction cap")]
+    MaxStakePerTxExceeded = 0x178F,
+    /// 6032 - Stake amount is below the configured minimum
+    #[error("Stake amount is below the configured minimum")]
+    StakeAmountBelowMinimum = 0x1790,
+    /// 6033 - Unstake amount is below the configured minimum
+    #[error("Unstake amount is below the configured minimum")]
+    UnstakeAmountBelowMinimum = 0x1791,
+    /// 6034 - Signer does not own the pending withdraw
+    #[error("Signer does not own the pending withdraw")]
+    InvalidPendingWithdrawOwner = 0x1792,
+    /// 6035 - Pending withdraw is indexed by a staker account that was not provided
+    #[error("Pending withdraw is indexed by a staker account that was not provided")]
+    StakerAccountRequired = 0x1793,
+    /// 6036 - Account layout version is not supported by this program
+    #[error("Account layout version is not supported by this program")]
+    UnsupportedAccountVersion = 0x1794,
+    /// 6037 - Invalid stake caps: the per-transaction cap must not exceed the vault ORCA cap
+    #[error("Invalid stake caps: the per-transaction cap must not exceed the vault ORCA cap")]
+    InvalidStakeCaps = 0x1795,
+    /// 6038 - Instruction is deprecated and no longer supported
+    #[error("Instruction is deprecated and no longer supported")]
+    DeprecatedInstruction = 0x1796,
 }
 
 impl From<XorcaStakingProgramError> for solana_program_error::ProgramError {
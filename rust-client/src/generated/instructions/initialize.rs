@@ -30,6 +30,8 @@ pub struct Initialize {
     pub token_program_account: solana_pubkey::Pubkey,
 
     pub associated_token_program_account: solana_pubkey::Pubkey,
+
+    pub program_data_account: solana_pubkey::Pubkey,
 }
 
 impl Initialize {
@@ -43,7 +45,7 @@ impl Initialize {
         args: InitializeInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             self.payer_account,
             true,
@@ -80,6 +82,10 @@ impl Initialize {
             self.associated_token_program_account,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program_data_account,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&InitializeInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -130,6 +136,7 @@ pub struct InitializeInstructionArgs {
 ///   6. `[]` system_program_account
 ///   7. `[]` token_program_account
 ///   8. `[]` associated_token_program_account
+///   9. `[]` program_data_account
 #[derive(Clone, Debug, Default)]
 pub struct InitializeBuilder {
     payer_account: Option<solana_pubkey::Pubkey>,
@@ -141,6 +148,7 @@ pub struct InitializeBuilder {
     system_program_account: Option<solana_pubkey::Pubkey>,
     token_program_account: Option<solana_pubkey::Pubkey>,
     associated_token_program_account: Option<solana_pubkey::Pubkey>,
+    program_data_account: Option<solana_pubkey::Pubkey>,
     cool_down_period_s: Option<i64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -207,6 +215,14 @@ impl InitializeBuilder {
         self
     }
     #[inline(always)]
+    pub fn program_data_account(
+        &mut self,
+        program_data_account: solana_pubkey::Pubkey,
+    ) -> &mut Self {
+        self.program_data_account = Some(program_data_account);
+        self
+    }
+    #[inline(always)]
     pub fn cool_down_period_s(&mut self, cool_down_period_s: i64) -> &mut Self {
         self.cool_down_period_s = Some(cool_down_period_s);
         self
@@ -250,6 +266,9 @@ impl InitializeBuilder {
             associated_token_program_account: self
                 .associated_token_program_account
                 .expect("associated_token_program_account is not set"),
+            program_data_account: self
+                .program_data_account
+                .expect("program_data_account is not set"),
         };
         let args = InitializeInstructionArgs {
             cool_down_period_s: self
@@ -281,6 +300,8 @@ pub struct InitializeCpiAccounts<'a, 'b> {
     pub token_program_account: &'b solana_account_info::AccountInfo<'a>,
 
     pub associated_token_program_account: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program_data_account: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `initialize` CPI instruction.
@@ -305,6 +326,8 @@ pub struct InitializeCpi<'a, 'b> {
     pub token_program_account: &'b solana_account_info::AccountInfo<'a>,
 
     pub associated_token_program_account: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program_data_account: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: InitializeInstructionArgs,
 }
@@ -326,6 +349,7 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
             system_program_account: accounts.system_program_account,
             token_program_account: accounts.token_program_account,
             associated_token_program_account: accounts.associated_token_program_account,
+            program_data_account: accounts.program_data_account,
             __args: args,
         }
     }
@@ -352,7 +376,7 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.payer_account.key,
             true,
@@ -389,6 +413,10 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
             *self.associated_token_program_account.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program_data_account.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -405,7 +433,7 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(11 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer_account.clone());
         account_infos.push(self.update_authority_account.clone());
@@ -416,6 +444,7 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
         account_infos.push(self.system_program_account.clone());
         account_infos.push(self.token_program_account.clone());
         account_infos.push(self.associated_token_program_account.clone());
+        account_infos.push(self.program_data_account.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -441,6 +470,7 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
 ///   6. `[]` system_program_account
 ///   7. `[]` token_program_account
 ///   8. `[]` associated_token_program_account
+///   9. `[]` program_data_account
 #[derive(Clone, Debug)]
 pub struct InitializeCpiBuilder<'a, 'b> {
     instruction: Box<InitializeCpiBuilderInstruction<'a, 'b>>,
@@ -459,6 +489,7 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
             system_program_account: None,
             token_program_account: None,
             associated_token_program_account: None,
+            program_data_account: None,
             cool_down_period_s: None,
             __remaining_accounts: Vec::new(),
         });
@@ -537,6 +568,14 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn program_data_account(
+        &mut self,
+        program_data_account: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program_data_account = Some(program_data_account);
+        self
+    }
+    #[inline(always)]
     pub fn cool_down_period_s(&mut self, cool_down_period_s: i64) -> &mut Self {
         self.instruction.cool_down_period_s = Some(cool_down_period_s);
         self
@@ -629,6 +668,11 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
                 .instruction
                 .associated_token_program_account
                 .expect("associated_token_program_account is not set"),
+
+            program_data_account: self
+                .instruction
+                .program_data_account
+                .expect("program_data_account is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -650,6 +694,7 @@ struct InitializeCpiBuilderInstruction<'a, 'b> {
     system_program_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     associated_token_program_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program_data_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     cool_down_period_s: Option<i64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
@@ -5,14 +5,52 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#accept_update_authority;
+pub(crate) mod r#apply_pending_config;
+pub(crate) mod r#cancel_unstake;
+pub(crate) mod r#crank_withdraw;
+pub(crate) mod r#deposit_rewards;
+pub(crate) mod r#harvest;
 pub(crate) mod r#initialize;
+pub(crate) mod r#initialize_staker_account;
+pub(crate) mod r#instant_unstake;
+pub(crate) mod r#merge_pending_withdraws;
+pub(crate) mod r#migrate;
 pub(crate) mod r#set;
 pub(crate) mod r#stake;
+pub(crate) mod r#stake_for;
+pub(crate) mod r#stake_for_exact_xorca;
+pub(crate) mod r#stake_with_slippage;
+pub(crate) mod r#transfer_pending_withdraw;
 pub(crate) mod r#unstake;
+pub(crate) mod r#unstake_for_exact_orca;
+pub(crate) mod r#unstake_with_slippage;
 pub(crate) mod r#withdraw;
+pub(crate) mod r#withdraw_many;
+pub(crate) mod r#withdraw_partial;
+pub(crate) mod r#withdraw_to;
 
+pub use self::r#accept_update_authority::*;
+pub use self::r#apply_pending_config::*;
+pub use self::r#cancel_unstake::*;
+pub use self::r#crank_withdraw::*;
+pub use self::r#deposit_rewards::*;
+pub use self::r#harvest::*;
 pub use self::r#initialize::*;
+pub use self::r#initialize_staker_account::*;
+pub use self::r#instant_unstake::*;
+pub use self::r#merge_pending_withdraws::*;
+pub use self::r#migrate::*;
 pub use self::r#set::*;
 pub use self::r#stake::*;
+pub use self::r#stake_for::*;
+pub use self::r#stake_for_exact_xorca::*;
+pub use self::r#stake_with_slippage::*;
+pub use self::r#transfer_pending_withdraw::*;
 pub use self::r#unstake::*;
+pub use self::r#unstake_for_exact_orca::*;
+pub use self::r#unstake_with_slippage::*;
 pub use self::r#withdraw::*;
+pub use self::r#withdraw_many::*;
+pub use self::r#withdraw_partial::*;
+pub use self::r#withdraw_to::*;
@@ -30,6 +30,10 @@ pub struct Unstake {
     pub system_program_account: solana_pubkey::Pubkey,
 
     pub token_program_account: solana_pubkey::Pubkey,
+
+    pub rent_payer_account: Option<solana_pubkey::Pubkey>,
+
+    pub staker_account_pda: Option<solana_pubkey::Pubkey>,
 }
 
 impl Unstake {
@@ -43,7 +47,7 @@ impl Unstake {
         args: UnstakeInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             self.unstaker_account,
             true,
@@ -80,6 +84,28 @@ impl Unstake {
             self.token_program_account,
             false,
         ));
+        if let Some(rent_payer_account) = self.rent_payer_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                rent_payer_account,
+                true,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::XORCA_STAKING_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(staker_account_pda) = self.staker_account_pda {
+            accounts.push(solana_instruction::AccountMeta::new(
+                staker_account_pda,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::XORCA_STAKING_PROGRAM_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&UnstakeInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -131,6 +157,8 @@ pub struct UnstakeInstructionArgs {
 ///   6. `[]` vault_account
 ///   7. `[]` system_program_account
 ///   8. `[]` token_program_account
+///   9. `[writable, signer, optional]` rent_payer_account
+///   10. `[writable, optional]` staker_account_pda
 #[derive(Clone, Debug, Default)]
 pub struct UnstakeBuilder {
     unstaker_account: Option<solana_pubkey::Pubkey>,
@@ -142,6 +170,8 @@ pub struct UnstakeBuilder {
     vault_account: Option<solana_pubkey::Pubkey>,
     system_program_account: Option<solana_pubkey::Pubkey>,
     token_program_account: Option<solana_pubkey::Pubkey>,
+    rent_payer_account: Option<solana_pubkey::Pubkey>,
+    staker_account_pda: Option<solana_pubkey::Pubkey>,
     xorca_unstake_amount: Option<u64>,
     withdraw_index: Option<u8>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
@@ -205,6 +235,24 @@ impl UnstakeBuilder {
         self.token_program_account = Some(token_program_account);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn rent_payer_account(
+        &mut self,
+        rent_payer_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.rent_payer_account = rent_payer_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn staker_account_pda(
+        &mut self,
+        staker_account_pda: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.staker_account_pda = staker_account_pda;
+        self
+    }
     #[inline(always)]
     pub fn xorca_unstake_amount(&mut self, xorca_unstake_amount: u64) -> &mut Self {
         self.xorca_unstake_amount = Some(xorca_unstake_amount);
@@ -254,6 +302,8 @@ impl UnstakeBuilder {
             token_program_account: self
                 .token_program_account
                 .expect("token_program_account is not set"),
+            rent_payer_account: self.rent_payer_account,
+            staker_account_pda: self.staker_account_pda,
         };
         let args = UnstakeInstructionArgs {
             xorca_unstake_amount: self
@@ -289,6 +339,10 @@ pub struct UnstakeCpiAccounts<'a, 'b> {
     pub system_program_account: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program_account: &'b solana_account_info::AccountInfo<'a>,
+
+    pub rent_payer_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub staker_account_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `unstake` CPI instruction.
@@ -313,6 +367,10 @@ pub struct UnstakeCpi<'a, 'b> {
     pub system_program_account: &'b solana_account_info::AccountInfo<'a>,
 
     pub token_program_account: &'b solana_account_info::AccountInfo<'a>,
+
+    pub rent_payer_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub staker_account_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: UnstakeInstructionArgs,
 }
@@ -334,6 +392,8 @@ impl<'a, 'b> UnstakeCpi<'a, 'b> {
             vault_account: accounts.vault_account,
             system_program_account: accounts.system_program_account,
             token_program_account: accounts.token_program_account,
+            rent_payer_account: accounts.rent_payer_account,
+            staker_account_pda: accounts.staker_account_pda,
             __args: args,
         }
     }
@@ -360,7 +420,7 @@ impl<'a, 'b> UnstakeCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.unstaker_account.key,
             true,
@@ -397,6 +457,28 @@ impl<'a, 'b> UnstakeCpi<'a, 'b> {
             *self.token_program_account.key,
             false,
         ));
+        if let Some(rent_payer_account) = self.rent_payer_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *rent_payer_account.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::XORCA_STAKING_PROGRAM_ID,
+                false,
+            ));
+        }
+        if let Some(staker_account_pda) = self.staker_account_pda {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *staker_account_pda.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::XORCA_STAKING_PROGRAM_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -413,7 +495,7 @@ impl<'a, 'b> UnstakeCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.unstaker_account.clone());
         account_infos.push(self.state_account.clone());
@@ -424,6 +506,12 @@ impl<'a, 'b> UnstakeCpi<'a, 'b> {
         account_infos.push(self.vault_account.clone());
         account_infos.push(self.system_program_account.clone());
         account_infos.push(self.token_program_account.clone());
+        if let Some(rent_payer_account) = self.rent_payer_account {
+            account_infos.push(rent_payer_account.clone());
+        }
+        if let Some(staker_account_pda) = self.staker_account_pda {
+            account_infos.push(staker_account_pda.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -449,6 +537,8 @@ impl<'a, 'b> UnstakeCpi<'a, 'b> {
 ///   6. `[]` vault_account
 ///   7. `[]` system_program_account
 ///   8. `[]` token_program_account
+///   9. `[writable, signer, optional]` rent_payer_account
+///   10. `[writable, optional]` staker_account_pda
 #[derive(Clone, Debug)]
 pub struct UnstakeCpiBuilder<'a, 'b> {
     instruction: Box<UnstakeCpiBuilderInstruction<'a, 'b>>,
@@ -467,6 +557,8 @@ impl<'a, 'b> UnstakeCpiBuilder<'a, 'b> {
             vault_account: None,
             system_program_account: None,
             token_program_account: None,
+            rent_payer_account: None,
+            staker_account_pda: None,
             xorca_unstake_amount: None,
             withdraw_index: None,
             __remaining_accounts: Vec::new(),
@@ -545,6 +637,24 @@ impl<'a, 'b> UnstakeCpiBuilder<'a, 'b> {
         self.instruction.token_program_account = Some(token_program_account);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn rent_payer_account(
+        &mut self,
+        rent_payer_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.rent_payer_account = rent_payer_account;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn staker_account_pda(
+        &mut self,
+        staker_account_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.staker_account_pda = staker_account_pda;
+        self
+    }
     #[inline(always)]
     pub fn xorca_unstake_amount(&mut self, xorca_unstake_amount: u64) -> &mut Self {
         self.instruction.xorca_unstake_amount = Some(xorca_unstake_amount);
@@ -648,6 +758,10 @@ impl<'a, 'b> UnstakeCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program_account
                 .expect("token_program_account is not set"),
+
+            rent_payer_account: self.instruction.rent_payer_account,
+
+            staker_account_pda: self.instruction.staker_account_pda,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -669,6 +783,8 @@ struct UnstakeCpiBuilderInstruction<'a, 'b> {
     vault_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program_account: Option<&'b solana_account_info::AccountInfo<'a>>,
     token_program_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    rent_payer_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    staker_account_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
     xorca_unstake_amount: Option<u64>,
     withdraw_index: Option<u8>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -27,4 +27,5 @@ pub enum AccountDiscriminator {
     State,
     PendingWithdraw,
     Closed,
+    StakerAccount,
 }
@@ -18,6 +18,16 @@ pub enum Event {
         vault_escrowed_orca_amount: u64,
         xorca_mint_supply: u64,
         xorca_to_mint: u64,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        payer: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        beneficiary: Pubkey,
     },
     Unstake {
         xorca_unstake_amount: u64,
@@ -27,12 +37,19 @@ pub enum Event {
         withdrawable_orca_amount: u64,
         cool_down_period_s: i64,
         withdraw_index: u8,
+        staker_withdraw_index: Option<u64>,
     },
     Withdraw {
         vault_escrowed_orca_amount: u64,
         withdrawable_orca_amount: u64,
         cool_down_period_s: i64,
         withdraw_index: u8,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        destination: Pubkey,
+        staker_withdraw_index: Option<u64>,
     },
     UpdateAuthoritySet {
         #[cfg_attr(
@@ -46,4 +63,201 @@ pub enum Event {
         )]
         set_by: Pubkey,
     },
+    InstantUnstake {
+        xorca_unstake_amount: u64,
+        vault_orca_amount: u64,
+        vault_escrowed_orca_amount: u64,
+        xorca_mint_supply: u64,
+        orca_unstake_amount: u64,
+        fee_orca_amount: u64,
+        instant_unstake_fee_bps: u16,
+    },
+    CancelUnstake {
+        withdrawable_orca_amount: u64,
+        vault_orca_amount: u64,
+        vault_escrowed_orca_amount: u64,
+        xorca_mint_supply: u64,
+        xorca_to_mint: u64,
+        withdraw_index: u8,
+    },
+    WithdrawPartial {
+        vault_escrowed_orca_amount: u64,
+        orca_withdraw_amount: u64,
+        remaining_orca_amount: u64,
+        withdraw_index: u8,
+    },
+    MergePendingWithdraws {
+        withdrawable_orca_amount: u64,
+        withdrawable_timestamp: i64,
+        merged_count: u16,
+        withdraw_index: u8,
+    },
+    WithdrawMany {
+        vault_escrowed_orca_amount: u64,
+        withdrawable_orca_amount: u64,
+        withdraw_count: u16,
+    },
+    PauseFlagsSet {
+        new_pause_flags: u16,
+        old_pause_flags: u16,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        set_by: Pubkey,
+    },
+    UpdateAuthorityProposed {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        proposed_authority: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        proposed_by: Pubkey,
+    },
+    CoolDownPeriodChangeQueued {
+        new_cool_down_period_s: i64,
+        activation_timestamp: i64,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        queued_by: Pubkey,
+    },
+    PendingConfigApplied {
+        old_cool_down_period_s: i64,
+        new_cool_down_period_s: i64,
+    },
+    RewardsDeposited {
+        rewards_amount: u64,
+        vault_orca_amount: u64,
+        vesting_rewards_amount: u64,
+        vesting_end_timestamp: i64,
+        total_rewards_deposited: u64,
+        non_escrowed_orca_amount: u64,
+        xorca_mint_supply: u64,
+    },
+    RewardsAuthoritySet {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        new_rewards_authority: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        set_by: Pubkey,
+    },
+    FeeMinted {
+        yield_orca_amount: u64,
+        fee_orca_amount: u64,
+        fee_xorca_amount: u64,
+        xorca_mint_supply: u64,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        treasury: Pubkey,
+    },
+    PendingWithdrawTransferred {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        unstaker: Pubkey,
+        withdraw_index: u8,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        previous_owner: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        new_owner: Pubkey,
+    },
+    AccountsMigrated {
+        migrated_count: u16,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        migrated_by: Pubkey,
+    },
+    InstantUnstakeFeeSet {
+        new_fee_bps: u16,
+        old_fee_bps: u16,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        set_by: Pubkey,
+    },
+    PerformanceFeeSet {
+        new_fee_bps: u16,
+        old_fee_bps: u16,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        set_by: Pubkey,
+    },
+    TreasurySet {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        new_treasury: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        old_treasury: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        set_by: Pubkey,
+    },
+    MaxVaultOrcaSet {
+        new_max_vault_orca: u64,
+        old_max_vault_orca: u64,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        set_by: Pubkey,
+    },
+    MaxStakePerTxSet {
+        new_max_stake_per_tx: u64,
+        old_max_stake_per_tx: u64,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        set_by: Pubkey,
+    },
+    MinStakeOrcaSet {
+        new_min_stake_orca: u64,
+        old_min_stake_orca: u64,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        set_by: Pubkey,
+    },
+    MinUnstakeXorcaSet {
+        new_min_unstake_xorca: u64,
+        old_min_unstake_xorca: u64,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        set_by: Pubkey,
+    },
 }
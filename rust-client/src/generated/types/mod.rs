@@ -7,6 +7,7 @@
 
 pub(crate) mod r#account_discriminator;
 pub(crate) mod r#event;
+pub(crate) mod r#program_data;
 pub(crate) mod r#state_update_instruction;
 pub(crate) mod r#token_account;
 pub(crate) mod r#token_account_state;
@@ -14,6 +15,7 @@ pub(crate) mod r#token_mint;
 
 pub use self::r#account_discriminator::*;
 pub use self::r#event::*;
+pub use self::r#program_data::*;
 pub use self::r#state_update_instruction::*;
 pub use self::r#token_account::*;
 pub use self::r#token_account_state::*;
@@ -22,4 +22,46 @@ pub enum StateUpdateInstruction {
         )]
         new_authority: Pubkey,
     },
+    UpdateInstantUnstakeFee {
+        new_instant_unstake_fee_bps: u16,
+    },
+    SetPauseFlags {
+        new_pause_flags: u16,
+    },
+    ProposeUpdateAuthority {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        new_authority: Pubkey,
+    },
+    UpdateRewardsAuthority {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        new_rewards_authority: Pubkey,
+    },
+    UpdatePerformanceFee {
+        new_performance_fee_bps: u16,
+    },
+    UpdateTreasury {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        new_treasury: Pubkey,
+    },
+    UpdateMaxVaultOrca {
+        new_max_vault_orca: u64,
+    },
+    UpdateMaxStakePerTx {
+        new_max_stake_per_tx: u64,
+    },
+    UpdateMinStakeOrca {
+        new_min_stake_orca: u64,
+    },
+    UpdateMinUnstakeXorca {
+        new_min_unstake_xorca: u64,
+    },
 }
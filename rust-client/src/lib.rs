@@ -32,17 +32,27 @@
 //! - `floats` - Enable floating-point math operations (default)
 //! - `wasm` - Enable WASM compilation for web use
 //!
+//! ## Clusters
+//!
+//! Program and mint addresses default to mainnet. Set `XORCA_PROGRAM_ID`, `XORCA_ORCA_MINT`
+//! and `XORCA_XORCA_MINT` at build time to target another cluster; see [`cluster`].
+//!
 //! ## License
 //!
 //! This project is licensed under a custom license. See [LICENSE](../LICENSE) for details.
 
 #![allow(unexpected_cfgs)]
 
+pub mod builders;
+pub mod capacity;
+pub mod cluster;
 pub mod conversion;
 #[allow(clippy::all, unused_imports)]
 mod generated;
 #[cfg(feature = "wasm")]
 mod math;
+pub mod ownership;
+pub mod pause;
 pub mod pda;
 #[cfg(feature = "fetch")]
 pub mod utils;
@@ -50,8 +60,9 @@ pub mod utils;
 pub use generated::accounts::*;
 pub use generated::errors::*;
 pub use generated::instructions::*;
-pub use generated::programs::XORCA_STAKING_PROGRAM_ID as ID;
-pub use generated::programs::*;
+// Addresses of the configured cluster, replacing the generated mainnet program ID
+pub use cluster::XORCA_STAKING_PROGRAM_ID as ID;
+pub use cluster::{ORCA_MINT_ID, XORCA_MINT_ID, XORCA_STAKING_PROGRAM_ID};
 #[cfg(feature = "fetch")]
 pub use generated::shared::*;
 pub use generated::types::*;
@@ -59,7 +70,10 @@ pub use generated::types::*;
 #[cfg(feature = "fetch")]
 pub(crate) use generated::*;
 
+pub use capacity::*;
 pub use conversion::*;
+pub use ownership::*;
+pub use pause::*;
 pub use pda::*;
 
 #[cfg(feature = "wasm")]
@@ -0,0 +1,52 @@
+//! Pending withdraw ownership, mirroring how the program resolves who may withdraw, cancel,
+//! merge or transfer a position.
+
+use crate::PendingWithdraw;
+use solana_pubkey::Pubkey;
+
+/// The key currently holding a pending withdraw. Accounts created before positions became
+/// transferable store no owner and belong to the unstaker that created them.
+pub fn pending_withdraw_owner(pending_withdraw: &PendingWithdraw) -> Pubkey {
+    if pending_withdraw.owner == Pubkey::default() {
+        pending_withdraw.unstaker
+    } else {
+        pending_withdraw.owner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AccountDiscriminator;
+
+    fn pending_withdraw(unstaker: Pubkey, owner: Pubkey) -> PendingWithdraw {
+        PendingWithdraw {
+            discriminator: AccountDiscriminator::PendingWithdraw,
+            staker_indexed: 0,
+            version: 2,
+            padding1: [0; 3],
+            bump: 255,
+            withdraw_index: 0,
+            unstaker,
+            withdrawable_orca_amount: 0,
+            withdrawable_timestamp: 0,
+            owner,
+            staker_withdraw_index: 0,
+            padding2: [0; 32],
+        }
+    }
+
+    #[test]
+    fn unset_owner_falls_back_to_unstaker() {
+        let unstaker = Pubkey::new_unique();
+        let pending_withdraw = pending_withdraw(unstaker, Pubkey::default());
+        assert_eq!(pending_withdraw_owner(&pending_withdraw), unstaker);
+    }
+
+    #[test]
+    fn stored_owner_takes_precedence() {
+        let owner = Pubkey::new_unique();
+        let pending_withdraw = pending_withdraw(Pubkey::new_unique(), owner);
+        assert_eq!(pending_withdraw_owner(&pending_withdraw), owner);
+    }
+}
@@ -0,0 +1,19 @@
+//! Bits of [`State::pause_flags`](crate::State), mirroring the on-chain program. Combine
+//! them with `|` when building a `StateUpdateInstruction::SetPauseFlags` update.
+
+/// Pauses `Stake`, `StakeWithSlippage`, `StakeForExactXorca` and `StakeFor`. `CancelUnstake`
+/// stakes the pending amount again and is paused by this bit as well.
+pub const PAUSE_STAKE: u16 = 1 << 0;
+/// Pauses `Unstake`, `UnstakeWithSlippage` and `UnstakeForExactOrca`.
+pub const PAUSE_UNSTAKE: u16 = 1 << 1;
+/// Pauses `Withdraw`, `WithdrawPartial`, `WithdrawMany`, `WithdrawTo`, `CrankWithdraw` and
+/// `MergePendingWithdraws`.
+pub const PAUSE_WITHDRAW: u16 = 1 << 2;
+/// Pauses `InstantUnstake`.
+pub const PAUSE_INSTANT_UNSTAKE: u16 = 1 << 3;
+/// Pauses `CancelUnstake`.
+pub const PAUSE_CANCEL_UNSTAKE: u16 = 1 << 4;
+/// Pauses `DepositRewards`.
+pub const PAUSE_DEPOSIT_REWARDS: u16 = 1 << 5;
+/// Pauses `Harvest`.
+pub const PAUSE_HARVEST: u16 = 1 << 6;
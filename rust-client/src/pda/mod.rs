@@ -1,9 +1,11 @@
-use crate::generated::programs::XORCA_STAKING_PROGRAM_ID;
+use crate::cluster::XORCA_STAKING_PROGRAM_ID;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
 const ATA_PROGRAM_ID: Pubkey =
     solana_program::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    solana_program::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
 
 pub fn find_state_address() -> Result<(Pubkey, u8), ProgramError> {
     Pubkey::try_find_program_address(&[b"state"], &XORCA_STAKING_PROGRAM_ID)
@@ -28,6 +30,35 @@ pub fn find_pending_withdraw_pda(
         .ok_or(ProgramError::InvalidSeeds)
 }
 
+pub fn find_staker_account_pda(staker: &Pubkey) -> Result<(Pubkey, u8), ProgramError> {
+    let seeds: &[&[u8]] = &[b"staker", staker.as_ref()];
+    Pubkey::try_find_program_address(seeds, &XORCA_STAKING_PROGRAM_ID)
+        .ok_or(ProgramError::InvalidSeeds)
+}
+
+/// Pending withdraw PDA for a withdraw index taken from a staker account. The `Unstake`
+/// `withdraw_index` argument is then the lowest byte of `staker_withdraw_index`.
+pub fn find_staker_pending_withdraw_pda(
+    unstaker: &Pubkey,
+    staker_withdraw_index: &u64,
+) -> Result<(Pubkey, u8), ProgramError> {
+    let seeds: &[&[u8]] = &[
+        b"pending_withdraw",
+        unstaker.as_ref(),
+        &staker_withdraw_index.to_le_bytes(),
+    ];
+    Pubkey::try_find_program_address(seeds, &XORCA_STAKING_PROGRAM_ID)
+        .ok_or(ProgramError::InvalidSeeds)
+}
+
+/// ProgramData account of the xORCA program, holding its upgrade authority. `Initialize` only
+/// accepts that authority as payer.
+pub fn find_program_data_address() -> Result<(Pubkey, u8), ProgramError> {
+    let seeds: &[&[u8]] = &[XORCA_STAKING_PROGRAM_ID.as_ref()];
+    Pubkey::try_find_program_address(seeds, &BPF_LOADER_UPGRADEABLE_ID)
+        .ok_or(ProgramError::InvalidSeeds)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,4 +85,14 @@ mod tests {
             pubkey!("D6i7r2sBNozTvdKuSSE2HdfqPb9sTd7gNd8jsCtR8quW");
         assert_eq!(address, expected_pending_withdraw_address);
     }
+
+    #[test]
+    fn test_find_staker_pending_withdraw_pda_differs_from_legacy_pda() {
+        let unstaker = pubkey!("A1tYHa3233WKDX5fZuZNmHMUVTSB12sR1RoVeGT8XV85");
+        let (legacy_address, _) = find_pending_withdraw_pda(&unstaker, &0).unwrap();
+        let (address, _) = find_staker_pending_withdraw_pda(&unstaker, &0).unwrap();
+        assert_ne!(address, legacy_address);
+        let (next_address, _) = find_staker_pending_withdraw_pda(&unstaker, &256).unwrap();
+        assert_ne!(next_address, address);
+    }
 }
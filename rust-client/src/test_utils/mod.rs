@@ -16,15 +16,18 @@ use spl_token_interface::state::{Account as TokenAccount, AccountState, Mint};
 use std::{collections::HashMap, path::Path, str::FromStr, u8};
 
 use crate::{
-    find_orca_vault_address, find_pending_withdraw_pda, find_state_address,
+    find_orca_vault_address, find_pending_withdraw_pda, find_staker_account_pda,
+    find_staker_pending_withdraw_pda, find_state_address,
     utils::{ORCA_MINT_ADDRESS, TOKEN_PROGRAM_ADDRESS, XORCA_MINT_ADDRESS},
-    PendingWithdraw, State, XORCA_STAKING_PROGRAM_ID,
+    PendingWithdraw, StakerAccount, State, XORCA_STAKING_PROGRAM_ID,
 };
 
 #[derive(Debug, Deserialize)]
 pub struct FixturesFile {
     pub staker: String,
     pub pending_indices_present: Vec<u8>,
+    #[serde(default)]
+    pub staker_account: Option<StakerAccountFixture>,
     pub state: StateFixture,
     pub vault: VaultFixture,
     pub xorca_mint: MintFixture,
@@ -37,6 +40,12 @@ pub struct StateFixture {
     pub update_authority: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct StakerAccountFixture {
+    pub next_withdraw_index: u64,
+    pub pending_indices_present: Vec<u64>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct VaultFixture {
     pub owner: String,
@@ -79,13 +88,34 @@ impl Fixtures {
         let state_bytes = {
             let state = State {
                 discriminator: crate::AccountDiscriminator::State,
-                padding1: [0u8; 5],
+                version: 1,
+                padding1: [0u8; 4],
                 bump: 1,
                 vault_bump: 1,
                 escrowed_orca_amount: json.state.escrowed_orca_amount,
                 cool_down_period_s: json.state.cool_down_period_s,
                 update_authority,
-                padding2: [0u8; 1992],
+                instant_unstake_fee_bps: 0,
+                pause_flags: 0,
+                pending_update_authority: Pubkey::default(),
+                padding3: [0u8; 4],
+                pending_cool_down_period_s: 0,
+                pending_config_activation_timestamp: 0,
+                vesting_rewards_amount: 0,
+                vesting_start_timestamp: 0,
+                vesting_end_timestamp: 0,
+                rewards_authority: Pubkey::default(),
+                total_rewards_deposited: 0,
+                treasury: Pubkey::default(),
+                fee_checkpoint_orca_amount: 0,
+                fee_checkpoint_xorca_supply: 0,
+                performance_fee_bps: 0,
+                padding4: [0u8; 6],
+                max_vault_orca: 0,
+                max_stake_per_tx: 0,
+                min_stake_orca: 0,
+                min_unstake_xorca: 0,
+                padding2: [0u8; 1784],
             };
             borsh::to_vec(&state)?
         };
@@ -153,13 +183,17 @@ impl Fixtures {
             let (addr, _) = find_pending_withdraw_pda(&staker, &idx)?;
             let pending = PendingWithdraw {
                 discriminator: crate::AccountDiscriminator::PendingWithdraw,
-                padding1: [0u8; 5],
+                staker_indexed: 0,
+                version: 2,
+                padding1: [0u8; 3],
                 bump: 1,
                 withdraw_index: idx,
                 unstaker: staker,
                 withdrawable_orca_amount: 1_000 + (idx as u64),
                 withdrawable_timestamp: 123_456 + (idx as i64),
-                padding2: [0u8; 968],
+                owner: staker,
+                staker_withdraw_index: 0,
+                padding2: [0u8; 32],
             };
             let bytes = borsh::to_vec(&pending)?;
             accounts.insert(
@@ -172,6 +206,57 @@ impl Fixtures {
             );
         }
 
+        // Staker account and the pending withdraw PDAs it indexes
+        if let Some(staker_account_fixture) = &json.staker_account {
+            let (addr, _) = find_staker_account_pda(&staker)?;
+            let staker_account = StakerAccount {
+                discriminator: crate::AccountDiscriminator::StakerAccount,
+                version: 1,
+                padding1: [0u8; 5],
+                bump: 1,
+                staker,
+                next_withdraw_index: staker_account_fixture.next_withdraw_index,
+                open_withdraw_count: staker_account_fixture.pending_indices_present.len() as u64,
+                total_xorca_unstaked: 0,
+                total_orca_unstaked: 0,
+                total_orca_withdrawn: 0,
+                padding2: [0u8; 432],
+            };
+            accounts.insert(
+                addr,
+                AccountBytes {
+                    owner: XORCA_STAKING_PROGRAM_ID,
+                    lamports: 1_000_000,
+                    data: borsh::to_vec(&staker_account)?,
+                },
+            );
+            for &idx in &staker_account_fixture.pending_indices_present {
+                let (addr, _) = find_staker_pending_withdraw_pda(&staker, &idx)?;
+                let pending = PendingWithdraw {
+                    discriminator: crate::AccountDiscriminator::PendingWithdraw,
+                    staker_indexed: 1,
+                    version: 2,
+                    padding1: [0u8; 3],
+                    bump: 1,
+                    withdraw_index: idx as u8,
+                    unstaker: staker,
+                    withdrawable_orca_amount: 1_000 + idx,
+                    withdrawable_timestamp: 123_456 + (idx as i64),
+                    owner: staker,
+                    staker_withdraw_index: idx,
+                    padding2: [0u8; 32],
+                };
+                accounts.insert(
+                    addr,
+                    AccountBytes {
+                        owner: XORCA_STAKING_PROGRAM_ID,
+                        lamports: 1_000_000,
+                        data: borsh::to_vec(&pending)?,
+                    },
+                );
+            }
+        }
+
         Ok(Self { accounts })
     }
 
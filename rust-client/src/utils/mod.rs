@@ -1,7 +1,10 @@
-use crate::generated::accounts::{fetch_all_maybe_pending_withdraw, fetch_state};
+use crate::generated::accounts::{
+    fetch_all_maybe_pending_withdraw, fetch_maybe_staker_account, fetch_state,
+};
 use crate::generated::shared;
 use crate::{
-    find_orca_vault_address, find_pending_withdraw_pda, find_state_address, PendingWithdraw, State,
+    find_orca_vault_address, find_pending_withdraw_pda, find_staker_account_pda,
+    find_staker_pending_withdraw_pda, find_state_address, PendingWithdraw, State,
 };
 use anyhow::Context;
 use solana_client::rpc_client::RpcClient;
@@ -12,8 +15,10 @@ use spl_token_interface::state::{Account, Mint};
 use std::str::FromStr;
 
 pub const DEFAULT_MAX_WITHDRAWALS_TO_SEARCH: u8 = 15;
-pub const ORCA_MINT_ADDRESS: &str = "orcaEKTdK7LKz57vaAYr9QeNsVEPfiu6QeMU1kektZE";
-pub const XORCA_MINT_ADDRESS: &str = "xorcaYqbXUNz3474ubUMJAdu2xgPsew3rUCe5ughT3N";
+/// Maximum number of accounts requested per `getMultipleAccounts` call
+pub const MAX_ACCOUNTS_PER_FETCH: usize = 100;
+pub const ORCA_MINT_ADDRESS: &str = crate::cluster::ORCA_MINT_ADDRESS;
+pub const XORCA_MINT_ADDRESS: &str = crate::cluster::XORCA_MINT_ADDRESS;
 pub const TOKEN_PROGRAM_ADDRESS: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
 
 #[derive(Debug, Clone)]
@@ -104,6 +109,56 @@ pub fn fetch_pending_withdraws_for_staker(
     Ok(pending_withdraws)
 }
 
+/// Fetches the open pending withdrawals indexed by a staker's staker account
+///
+/// Indices are searched from the newest down and the search stops once
+/// `open_withdraw_count` pending withdraws are found, so no guessing is needed.
+///
+/// # Arguments
+/// * `rpc` - The RPC client to use for fetching data
+/// * `staker` - The public key of the staker
+///
+/// # Returns
+/// A vector of `PendingWithdraw` accounts, newest first. Empty when the staker has no
+/// staker account.
+///
+/// # Errors
+/// Returns an error if:
+/// Any RPC call fails
+pub fn fetch_staker_indexed_pending_withdraws(
+    rpc: &RpcClient,
+    staker: &Pubkey,
+) -> Result<Vec<PendingWithdraw>, ProgramError> {
+    let (staker_account_address, _) = find_staker_account_pda(staker)?;
+    let staker_account = match fetch_maybe_staker_account(rpc, &staker_account_address)? {
+        shared::MaybeAccount::Exists(decoded) => decoded.data,
+        shared::MaybeAccount::NotFound(_) => return Ok(Vec::new()),
+    };
+
+    let open_withdraw_count = staker_account.open_withdraw_count as usize;
+    let mut pending_withdraws = Vec::new();
+    let mut next_index = staker_account.next_withdraw_index;
+    while next_index > 0 && pending_withdraws.len() < open_withdraw_count {
+        let first_index = next_index.saturating_sub(MAX_ACCOUNTS_PER_FETCH as u64);
+        let addresses = (first_index..next_index)
+            .rev()
+            .map(|index| {
+                find_staker_pending_withdraw_pda(staker, &index).map(|(address, _)| address)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let maybe_accounts = fetch_all_maybe_pending_withdraw(rpc, &addresses)?;
+        pending_withdraws.extend(maybe_accounts.into_iter().filter_map(|maybe_account| {
+            match maybe_account {
+                shared::MaybeAccount::Exists(decoded) => Some(decoded.data),
+                shared::MaybeAccount::NotFound(_) => None,
+            }
+        }));
+        next_index = first_index;
+    }
+
+    Ok(pending_withdraws)
+}
+
 /// Fetches the vault token account state
 ///
 /// # Arguments
@@ -255,6 +310,27 @@ mod tests {
         assert!(!pw.iter().any(|p| p.withdraw_index == 2));
     }
 
+    #[test]
+    fn test_fetch_staker_indexed_pending_withdraws_unit() {
+        // Fixture staker account has taken indices 0..=5, with 1, 3 and 5 still open
+        let rpc = make_mocked_client_from_fixtures(&fixtures_path()).expect("mock rpc");
+        let staker = Pubkey::from_str("9GJeoK3Qn2p8Rq6i7AbQm7x1SE7K75Eo3VdFUSf1xZ4i").unwrap();
+        let pw = fetch_staker_indexed_pending_withdraws(&rpc, &staker).expect("pending");
+        let indices: Vec<u64> = pw.iter().map(|p| p.staker_withdraw_index).collect();
+        assert_eq!(indices, vec![5, 3, 1]);
+        assert!(pw
+            .iter()
+            .all(|p| p.staker_indexed == 1 && p.unstaker == staker));
+    }
+
+    #[test]
+    fn test_fetch_staker_indexed_pending_withdraws_without_staker_account_unit() {
+        let rpc = make_mocked_client_from_fixtures(&fixtures_path()).expect("mock rpc");
+        let pw =
+            fetch_staker_indexed_pending_withdraws(&rpc, &Pubkey::new_unique()).expect("pending");
+        assert!(pw.is_empty());
+    }
+
     #[test]
     fn test_staking_exchange_rate_ok_unit() {
         let rpc = make_mocked_client_from_fixtures(&fixtures_path()).expect("mock rpc");
@@ -0,0 +1,61 @@
+//! A minimal program that forwards an instruction to another program while signing for its
+//! `unstaker` PDA. The test suite uses it to unstake on behalf of a program-owned account.
+//!
+//! Accounts:
+//!   0. `[]` The program to invoke
+//!   1.. The accounts of the forwarded instruction, in order
+//!
+//! The instruction data is forwarded verbatim.
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::slice_invoke_signed,
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    seeds, ProgramResult,
+};
+
+#[cfg(target_os = "solana")]
+use pinocchio::entrypoint;
+
+#[cfg(target_os = "solana")]
+entrypoint!(process_instruction);
+
+pub const UNSTAKER_SEED: &[u8] = b"unstaker";
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (target_program, forwarded_accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let (unstaker, bump) = find_program_address(&[UNSTAKER_SEED], program_id);
+
+    // The unstaker PDA signs through this program; every other account keeps its role
+    let account_metas: Vec<AccountMeta> = forwarded_accounts
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_writable: account.is_writable(),
+            is_signer: account.is_signer() || account.key() == &unstaker,
+        })
+        .collect();
+    let account_infos: Vec<&AccountInfo> = forwarded_accounts.iter().collect();
+
+    let instruction = Instruction {
+        program_id: target_program.key(),
+        data: instruction_data,
+        accounts: &account_metas,
+    };
+    let bump_bytes = [bump];
+    let unstaker_seeds = seeds!(UNSTAKER_SEED, &bump_bytes);
+    slice_invoke_signed(
+        &instruction,
+        &account_infos,
+        &[Signer::from(&unstaker_seeds)],
+    )
+}
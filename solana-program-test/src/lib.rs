@@ -28,15 +28,21 @@ pub const JITO_TIP_ADDRESS: Pubkey =
     solana_sdk::pubkey!("96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5");
 pub const TOKEN_PROGRAM_ID: Pubkey =
     solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
-pub const ORCA_ID: Pubkey = solana_sdk::pubkey!("orcaEKTdK7LKz57vaAYr9QeNsVEPfiu6QeMU1kektZE");
-pub const XORCA_ID: Pubkey = solana_sdk::pubkey!("xorcaYqbXUNz3474ubUMJAdu2xgPsew3rUCe5ughT3N");
-pub const XORCA_PROGRAM_ID: Pubkey =
-    solana_sdk::pubkey!("StaKE6XNKVVhG8Qu9hDJBqCW3eRe7MDGLz17nJZetLT");
+// Cluster addresses come from the client, which reads the same build-time configuration as
+// the program
+pub const ORCA_ID: Pubkey = xorca::ORCA_MINT_ID;
+pub const XORCA_ID: Pubkey = xorca::XORCA_MINT_ID;
+pub const XORCA_PROGRAM_ID: Pubkey = xorca::XORCA_STAKING_PROGRAM_ID;
 pub const ATA_PROGRAM_ID: Pubkey =
     solana_sdk::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
-// In test mode, we use a different deployer address that we can generate a keypair for
-pub const DEPLOYER_ADDRESS: Pubkey =
-    solana_sdk::pubkey!("9C6hybhQ6Aycep9jaUnP6uL9ZYvDjUp1aSkFWPUFJtpj");
+// Test-only program that unstakes on behalf of its PDA through CPI
+pub const UNSTAKE_CPI_CALLER_ID: Pubkey =
+    solana_sdk::pubkey!("UnstakeCpiCaLLer111111111111111111111111111");
+pub const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    solana_sdk::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+// Size of the UpgradeableLoaderState::ProgramData header preceding the ELF
+const PROGRAM_DATA_METADATA_LEN: usize = 45;
+const XORCA_PROGRAM_ELF: &[u8] = include_bytes!("../../target/deploy/xorca_staking_program.so");
 
 struct TestContext {
     svm: Rc<RefCell<LiteSVM>>,
@@ -62,17 +68,69 @@ impl TestContext {
         // Fund the test signer / deployer
         svm.airdrop(&signer.pubkey(), LAMPORTS_PER_SOL).unwrap();
 
-        // Add the program
-        svm.add_program(
-            XORCA_PROGRAM_ID,
-            include_bytes!("../../target/deploy/xorca_staking_program.so"),
-        )
-        .unwrap();
-        Self {
+        // Deploy the program as upgradeable with the test signer as its upgrade authority
+        let signer_pubkey = signer.pubkey();
+        let ctx = Self {
             svm: Rc::new(RefCell::new(svm)),
             signer,
             verify_tx_size: true,
+        };
+        ctx.deploy_program(Some(signer_pubkey));
+        ctx
+    }
+
+    /// (Re)deploys the xORCA program through the BPF Upgradeable Loader. `None` makes the
+    /// program immutable.
+    pub fn deploy_program(&self, upgrade_authority: Option<Pubkey>) {
+        // cargo only needs the file to exist, so a placeholder compiles but cannot be loaded
+        assert!(
+            !XORCA_PROGRAM_ELF.is_empty(),
+            "target/deploy/xorca_staking_program.so is empty; run `yarn build:test` first"
+        );
+        let (program_data_address, _) = xorca::find_program_data_address().unwrap();
+        let mut svm = self.svm.borrow_mut();
+
+        let mut program_data =
+            Vec::with_capacity(PROGRAM_DATA_METADATA_LEN + XORCA_PROGRAM_ELF.len());
+        program_data.extend_from_slice(&3u32.to_le_bytes());
+        program_data.extend_from_slice(&0u64.to_le_bytes());
+        match upgrade_authority {
+            Some(authority) => {
+                program_data.push(1);
+                program_data.extend_from_slice(authority.as_ref());
+            }
+            None => program_data.extend_from_slice(&[0; 33]),
         }
+        program_data.extend_from_slice(XORCA_PROGRAM_ELF);
+        // The ProgramData account must exist before the program account is loaded from it
+        let lamports = svm.minimum_balance_for_rent_exemption(program_data.len());
+        svm.set_account(
+            program_data_address,
+            Account {
+                lamports,
+                data: program_data,
+                owner: BPF_LOADER_UPGRADEABLE_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        let mut program = Vec::with_capacity(36);
+        program.extend_from_slice(&2u32.to_le_bytes());
+        program.extend_from_slice(program_data_address.as_ref());
+        let lamports = svm.minimum_balance_for_rent_exemption(program.len());
+        svm.set_account(
+            XORCA_PROGRAM_ID,
+            Account {
+                lamports,
+                data: program,
+                owner: BPF_LOADER_UPGRADEABLE_ID,
+                executable: true,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
     }
 
     pub fn new_signer(svm: Rc<RefCell<LiteSVM>>) -> Self {
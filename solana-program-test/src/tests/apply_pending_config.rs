@@ -0,0 +1,102 @@
+use crate::assert_program_error;
+use crate::utils::assert::decode_events_from_result;
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{advance_clock_env, do_apply_pending_config, unstake_and_advance};
+use solana_sdk::clock::Clock;
+use xorca::{
+    Event, PendingWithdraw, Set, SetInstructionArgs, State, StateUpdateInstruction,
+    XorcaStakingProgramError,
+};
+use xorca_staking_program::state::state::CONFIG_TIMELOCK_S;
+
+fn setup_env() -> Env {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    // Make the test signer the update authority so it can queue changes
+    let update_authority = env.ctx.signer();
+    env.update_state(|state| state.update_authority = update_authority);
+    env
+}
+
+fn queue_cool_down_period(env: &mut Env, new_cool_down_period_s: i64) {
+    let ix = Set {
+        update_authority_account: env.ctx.signer(),
+        state_account: env.state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::UpdateCoolDownPeriod {
+            new_cool_down_period_s,
+        },
+    });
+    let res = env.ctx.sends(&[ix]);
+    let activation_timestamp = env.ctx.get_sysvar::<Clock>().unix_timestamp + CONFIG_TIMELOCK_S;
+    let found = decode_events_from_result(&res)
+        .iter()
+        .any(|event| match event {
+            Event::CoolDownPeriodChangeQueued {
+                new_cool_down_period_s: queued_cool_down_period_s,
+                activation_timestamp: queued_activation_timestamp,
+                queued_by,
+            } => {
+                *queued_cool_down_period_s == new_cool_down_period_s
+                    && *queued_activation_timestamp == activation_timestamp
+                    && *queued_by == env.ctx.signer()
+            }
+            _ => false,
+        });
+    assert!(found, "CoolDownPeriodChangeQueued event should be emitted");
+}
+
+// Happy path: anyone applies the queued cooldown once the timelock has passed
+#[test]
+fn test_apply_pending_config_after_timelock() {
+    let mut env = setup_env();
+    queue_cool_down_period(&mut env, 300);
+    advance_clock_env(&mut env, CONFIG_TIMELOCK_S);
+
+    let res = do_apply_pending_config(&mut env);
+    assert!(res.is_ok());
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    assert_eq!(state.data.cool_down_period_s, 300);
+    assert_eq!(state.data.pending_cool_down_period_s, 0);
+    assert_eq!(state.data.pending_config_activation_timestamp, 0);
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::PendingConfigApplied {
+            old_cool_down_period_s,
+            new_cool_down_period_s,
+        } => *old_cool_down_period_s == 60 && *new_cool_down_period_s == 300,
+        _ => false,
+    });
+    assert!(found, "PendingConfigApplied event should be emitted");
+
+    // New unstakes use the applied cooldown
+    let now = env.ctx.get_sysvar::<Clock>().unix_timestamp;
+    let pending_withdraw_account = unstake_and_advance(&mut env, 0, 1_000_000, 0);
+    let pending = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap();
+    assert_eq!(pending.data.withdrawable_timestamp, now + 300);
+}
+
+// Failure: queued cooldown cannot be applied before the timelock passes
+#[test]
+fn test_apply_pending_config_fails_before_timelock() {
+    let mut env = setup_env();
+    queue_cool_down_period(&mut env, 300);
+    advance_clock_env(&mut env, CONFIG_TIMELOCK_S - 1);
+
+    let res = do_apply_pending_config(&mut env);
+    assert_program_error!(res, XorcaStakingProgramError::ConfigTimelockActive);
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    assert_eq!(state.data.cool_down_period_s, 60);
+}
+
+// Failure: nothing queued
+#[test]
+fn test_apply_pending_config_fails_without_pending_config() {
+    let mut env = setup_env();
+    let res = do_apply_pending_config(&mut env);
+    assert_program_error!(res, XorcaStakingProgramError::NoPendingConfig);
+}
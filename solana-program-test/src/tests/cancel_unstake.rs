@@ -0,0 +1,302 @@
+use crate::utils::assert::{
+    assert_account_closed, decode_events_from_result, take_withdraw_snapshot,
+};
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{
+    deposit_yield_into_vault, do_cancel_unstake, do_cancel_unstake_with_unique, do_unstake,
+    do_unstake_with_unique, do_withdraw,
+};
+use crate::{assert_program_error, ORCA_ID, TOKEN_PROGRAM_ID, XORCA_ID};
+use solana_sdk::pubkey::Pubkey;
+use xorca::{
+    find_pending_withdraw_pda, CancelUnstake, CancelUnstakeInstructionArgs, Event, PendingWithdraw,
+    State, TokenAccount, TokenMint, XorcaStakingProgramError,
+};
+use xorca_staking_program::util::math::convert_orca_to_xorca;
+
+// Cost for resizing an account to 0 in Solana runtime
+const RESIZE_TO_ZERO_COST: u64 = 5000;
+
+// Happy path: cancelling right after unstake restores xORCA, clears escrow and closes the pending withdraw
+#[test]
+fn test_cancel_unstake_restores_position_at_unchanged_rate() {
+    let pool = PoolSetup {
+        cool_down_period_s: 7 * 24 * 60 * 60,
+        ..PoolSetup::funded()
+    };
+    let mut env = Env::setup(&pool, 0, 1_000_000_000);
+    let idx = 3u8;
+    let xorca_unstake_amount = 1_000_000_000u64;
+    assert!(do_unstake(&mut env, idx, xorca_unstake_amount).is_ok());
+    let pending_withdraw_account = find_pending_withdraw_pda(&env.staker, &idx).unwrap().0;
+    let withdrawable_orca_amount = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap()
+        .data
+        .withdrawable_orca_amount;
+    let snap = take_withdraw_snapshot(
+        &env.ctx,
+        env.state,
+        env.vault,
+        env.staker_orca_ata,
+        env.staker_xorca_ata,
+        XORCA_ID,
+    );
+    let expected_xorca = convert_orca_to_xorca(
+        withdrawable_orca_amount,
+        snap.vault_before - snap.escrow_before,
+        snap.xorca_supply_before,
+    )
+    .unwrap();
+
+    let res = do_cancel_unstake(&mut env, idx);
+    assert!(res.is_ok());
+    assert_account_closed(
+        &env.ctx,
+        pending_withdraw_account,
+        "pending withdraw closed",
+    );
+
+    let user_xorca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_xorca_ata)
+        .unwrap();
+    assert_eq!(user_xorca.data.amount, expected_xorca);
+    assert_eq!(expected_xorca, xorca_unstake_amount);
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    assert_eq!(
+        state.data.escrowed_orca_amount,
+        snap.escrow_before - withdrawable_orca_amount
+    );
+    let vault = env.ctx.get_account::<TokenAccount>(env.vault).unwrap();
+    assert_eq!(
+        vault.data.amount, snap.vault_before,
+        "vault must not change on cancel"
+    );
+    let mint = env.ctx.get_account::<TokenMint>(XORCA_ID).unwrap();
+    assert_eq!(mint.data.supply, snap.xorca_supply_before + expected_xorca);
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::CancelUnstake {
+            withdrawable_orca_amount: amount,
+            xorca_to_mint,
+            vault_escrowed_orca_amount,
+            withdraw_index,
+            ..
+        } => {
+            *amount == withdrawable_orca_amount
+                && *xorca_to_mint == expected_xorca
+                && *vault_escrowed_orca_amount == state.data.escrowed_orca_amount
+                && *withdraw_index == idx
+        }
+        _ => false,
+    });
+    assert!(found, "CancelUnstake event should be emitted");
+}
+
+// Happy path: yield accrued during the cooldown mints fewer xORCA back than were burned
+#[test]
+fn test_cancel_unstake_uses_current_exchange_rate() {
+    let pool = PoolSetup {
+        cool_down_period_s: 7 * 24 * 60 * 60,
+        ..PoolSetup::funded()
+    };
+    let mut env = Env::setup(&pool, 0, 1_000_000_000);
+    let idx = 0u8;
+    let xorca_unstake_amount = 1_000_000_000u64;
+    assert!(do_unstake(&mut env, idx, xorca_unstake_amount).is_ok());
+    deposit_yield_into_vault(&mut env, 9_000_000_000, "yield");
+
+    let snap = take_withdraw_snapshot(
+        &env.ctx,
+        env.state,
+        env.vault,
+        env.staker_orca_ata,
+        env.staker_xorca_ata,
+        XORCA_ID,
+    );
+    let pending_withdraw_account = find_pending_withdraw_pda(&env.staker, &idx).unwrap().0;
+    let withdrawable_orca_amount = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap()
+        .data
+        .withdrawable_orca_amount;
+    let expected_xorca = convert_orca_to_xorca(
+        withdrawable_orca_amount,
+        snap.vault_before - snap.escrow_before,
+        snap.xorca_supply_before,
+    )
+    .unwrap();
+
+    assert!(do_cancel_unstake(&mut env, idx).is_ok());
+    let user_xorca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_xorca_ata)
+        .unwrap();
+    assert_eq!(user_xorca.data.amount, expected_xorca);
+    assert!(
+        expected_xorca < xorca_unstake_amount,
+        "cancelling after yield should not return the full burned amount"
+    );
+}
+
+// Happy path: rent of the pending withdraw is refunded to the unstaker
+#[test]
+fn test_cancel_unstake_refunds_rent_to_unstaker() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    let idx = 9u8;
+    assert!(do_unstake(&mut env, idx, 1_000_000).is_ok());
+    let pending_withdraw_account = find_pending_withdraw_pda(&env.staker, &idx).unwrap().0;
+    let pending_lamports = env
+        .ctx
+        .get_raw_account(pending_withdraw_account)
+        .unwrap()
+        .lamports;
+    let staker_lamports_before = env.ctx.get_raw_account(env.staker).unwrap().lamports;
+
+    assert!(do_cancel_unstake(&mut env, idx).is_ok());
+
+    let staker_lamports_after = env.ctx.get_raw_account(env.staker).unwrap().lamports;
+    assert_eq!(
+        staker_lamports_after,
+        staker_lamports_before + pending_lamports - RESIZE_TO_ZERO_COST
+    );
+    assert!(env.ctx.get_raw_account(pending_withdraw_account).is_err());
+}
+
+// Happy path: the index can be reused for a new unstake after cancelling
+#[test]
+fn test_cancel_unstake_allows_index_reuse() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    let idx = 1u8;
+    assert!(do_unstake(&mut env, idx, 1_000_000).is_ok());
+    assert!(do_cancel_unstake(&mut env, idx).is_ok());
+    assert!(do_unstake_with_unique(&mut env, idx, 1_000_000, 1).is_ok());
+    let pending_withdraw_account = find_pending_withdraw_pda(&env.staker, &idx).unwrap().0;
+    let pending = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap();
+    assert_eq!(pending.data.withdrawable_orca_amount, 1_000_000);
+}
+
+// Failure: a cancelled pending withdraw can no longer be cancelled or withdrawn
+#[test]
+fn test_cancel_unstake_twice_fails() {
+    let pool = PoolSetup {
+        cool_down_period_s: 0,
+        ..PoolSetup::funded()
+    };
+    let mut env = Env::setup(&pool, 0, 1_000_000);
+    let idx = 2u8;
+    assert!(do_unstake(&mut env, idx, 1_000_000).is_ok());
+    assert!(do_cancel_unstake(&mut env, idx).is_ok());
+    let res = do_cancel_unstake_with_unique(&mut env, idx, 1);
+    assert_program_error!(res, XorcaStakingProgramError::IncorrectOwner);
+    let pending_withdraw_account = find_pending_withdraw_pda(&env.staker, &idx).unwrap().0;
+    let res = do_withdraw(&mut env, pending_withdraw_account, idx);
+    assert!(res.is_err());
+}
+
+// Failure: pending withdraw account does not match the withdraw index
+#[test]
+fn test_cancel_unstake_fails_with_mismatched_index() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    assert!(do_unstake(&mut env, 4, 1_000_000).is_ok());
+    let pending_withdraw_account = find_pending_withdraw_pda(&env.staker, &4).unwrap().0;
+    let ix = CancelUnstake {
+        unstaker_account: env.staker,
+        state_account: env.state,
+        pending_withdraw_account,
+        unstaker_xorca_ata: env.staker_xorca_ata,
+        xorca_mint_account: XORCA_ID,
+        orca_mint_account: ORCA_ID,
+        vault_account: env.vault,
+        token_program_account: TOKEN_PROGRAM_ID,
+    }
+    .instruction(CancelUnstakeInstructionArgs { withdraw_index: 5 });
+    let res = env.ctx.sends(&[ix]);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidSeeds);
+}
+
+// Failure: vault holds less than the escrow
+#[test]
+fn test_cancel_unstake_fails_when_vault_below_escrow() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    let idx = 6u8;
+    assert!(do_unstake(&mut env, idx, 1_000_000).is_ok());
+    env.update_state(|state| state.escrowed_orca_amount = 20_000_000_000);
+    let res = do_cancel_unstake(&mut env, idx);
+    assert_program_error!(res, XorcaStakingProgramError::InsufficientVaultBacking);
+}
+
+// Failure: xORCA mint has a freeze authority
+#[test]
+fn test_cancel_unstake_fails_with_freeze_authority_on_xorca_mint() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    let idx = 7u8;
+    assert!(do_unstake(&mut env, idx, 1_000_000).is_ok());
+    let mut mint = env.ctx.get_account::<TokenMint>(XORCA_ID).unwrap().data;
+    mint.freeze_authority_flag = 1;
+    mint.freeze_authority = Pubkey::new_unique();
+    env.ctx
+        .write_account(XORCA_ID, TOKEN_PROGRAM_ID, mint)
+        .unwrap();
+    let res = do_cancel_unstake(&mut env, idx);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidAccountData);
+}
+
+// Failure: xORCA mint authority is not the state account
+#[test]
+fn test_cancel_unstake_fails_with_wrong_xorca_mint_authority() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    let idx = 8u8;
+    assert!(do_unstake(&mut env, idx, 1_000_000).is_ok());
+    let mut mint = env.ctx.get_account::<TokenMint>(XORCA_ID).unwrap().data;
+    mint.mint_authority = Pubkey::new_unique();
+    env.ctx
+        .write_account(XORCA_ID, TOKEN_PROGRAM_ID, mint)
+        .unwrap();
+    let res = do_cancel_unstake(&mut env, idx);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidAccountData);
+}
+
+// Failure: the restaked amount must meet the minimum stake
+#[test]
+fn test_cancel_unstake_fails_below_min_stake() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    let idx = 9u8;
+    assert!(do_unstake(&mut env, idx, 1_000_000).is_ok());
+    env.update_state(|state| state.min_stake_orca = 1_000_001);
+    let res = do_cancel_unstake(&mut env, idx);
+    assert_program_error!(res, XorcaStakingProgramError::StakeAmountBelowMinimum);
+}
+
+// Failure: the restaked amount must stay within the per-transaction cap
+#[test]
+fn test_cancel_unstake_fails_above_max_stake_per_tx() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    let idx = 10u8;
+    assert!(do_unstake(&mut env, idx, 1_000_000).is_ok());
+    env.update_state(|state| state.max_stake_per_tx = 999_999);
+    let res = do_cancel_unstake(&mut env, idx);
+    assert_program_error!(res, XorcaStakingProgramError::MaxStakePerTxExceeded);
+}
+
+// Failure: cancelling is refused while the vault is above its cap, and allowed at the cap
+#[test]
+fn test_cancel_unstake_respects_max_vault_orca() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    let idx = 11u8;
+    assert!(do_unstake(&mut env, idx, 1_000_000).is_ok());
+    env.update_state(|state| state.max_vault_orca = 9_999_999_999);
+    let res = do_cancel_unstake(&mut env, idx);
+    assert_program_error!(res, XorcaStakingProgramError::MaxVaultOrcaExceeded);
+
+    env.update_state(|state| state.max_vault_orca = 10_000_000_000);
+    let res = do_cancel_unstake_with_unique(&mut env, idx, 1);
+    assert!(res.is_ok());
+}
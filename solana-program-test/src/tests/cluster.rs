@@ -0,0 +1,21 @@
+use crate::{ORCA_ID, XORCA_ID, XORCA_PROGRAM_ID};
+use xorca_staking_program::cluster;
+
+// Happy path: the program and the client are built for the same cluster
+#[test]
+fn test_program_and_client_share_cluster_addresses() {
+    assert_eq!(xorca_staking_program::ID, XORCA_PROGRAM_ID.to_bytes());
+    assert_eq!(cluster::ORCA_MINT_ID, ORCA_ID.to_bytes());
+    assert_eq!(cluster::XORCA_MINT_ID, XORCA_ID.to_bytes());
+}
+
+// Happy path: without a build-time override both sides use the mainnet addresses
+#[test]
+fn test_cluster_defaults_to_mainnet() {
+    if option_env!("XORCA_PROGRAM_ID").is_some() {
+        return;
+    }
+    assert_eq!(XORCA_PROGRAM_ID.to_string(), cluster::MAINNET_PROGRAM_ID);
+    assert_eq!(ORCA_ID.to_string(), cluster::MAINNET_ORCA_MINT);
+    assert_eq!(XORCA_ID.to_string(), cluster::MAINNET_XORCA_MINT);
+}
@@ -0,0 +1,133 @@
+use crate::utils::assert::decode_events_from_result;
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::do_crank_withdraw;
+use crate::{assert_program_error, ATA_PROGRAM_ID, ORCA_ID, TOKEN_PROGRAM_ID, XORCA_PROGRAM_ID};
+use solana_sdk::{clock::Clock, pubkey::Pubkey};
+use xorca::{find_pending_withdraw_pda, Event, State, TokenAccount, XorcaStakingProgramError};
+
+const WITHDRAWABLE_ORCA_AMOUNT: u64 = 1_000_000_000;
+
+struct Unstaker {
+    key: Pubkey,
+    orca_ata: Pubkey,
+    pending_withdraw: Pubkey,
+}
+
+// Seeds a pending withdraw for an unstaker that never signs, maturing at `withdrawable_timestamp`
+fn seed_pending_withdraw(env: &mut Env, withdrawable_timestamp: i64) -> Unstaker {
+    let key = Pubkey::new_unique();
+    let orca_ata = Pubkey::find_program_address(
+        &[
+            &key.to_bytes(),
+            &TOKEN_PROGRAM_ID.to_bytes(),
+            &ORCA_ID.to_bytes(),
+        ],
+        &ATA_PROGRAM_ID,
+    )
+    .0;
+    env.ctx
+        .write_account(
+            orca_ata,
+            TOKEN_PROGRAM_ID,
+            crate::token_account_data!(mint => ORCA_ID, owner => key, amount => 0),
+        )
+        .unwrap();
+
+    let (pending_withdraw, bump) = find_pending_withdraw_pda(&key, &0).unwrap();
+    env.ctx
+        .write_account(
+            pending_withdraw,
+            XORCA_PROGRAM_ID,
+            crate::pending_withdraw_data!(
+                unstaker => key,
+                withdraw_index => 0,
+                withdrawable_orca_amount => WITHDRAWABLE_ORCA_AMOUNT,
+                withdrawable_timestamp => withdrawable_timestamp,
+                bump => bump,
+            ),
+        )
+        .unwrap();
+
+    env.update_state(|state| state.escrowed_orca_amount += WITHDRAWABLE_ORCA_AMOUNT);
+
+    Unstaker {
+        key,
+        orca_ata,
+        pending_withdraw,
+    }
+}
+
+fn now(env: &Env) -> i64 {
+    env.ctx.get_sysvar::<Clock>().unix_timestamp
+}
+
+// Happy path: a keeper settles a matured withdraw to the unstaker's ATA and refunds the rent to them
+#[test]
+fn test_crank_withdraw_pays_unstaker() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 0);
+    let timestamp = now(&env);
+    let unstaker = seed_pending_withdraw(&mut env, timestamp);
+    let rent = env
+        .ctx
+        .get_raw_account(unstaker.pending_withdraw)
+        .unwrap()
+        .lamports;
+
+    let res = do_crank_withdraw(&mut env, unstaker.key, unstaker.orca_ata, 0);
+    assert!(res.is_ok());
+
+    let unstaker_orca = env
+        .ctx
+        .get_account::<TokenAccount>(unstaker.orca_ata)
+        .unwrap();
+    assert_eq!(unstaker_orca.data.amount, WITHDRAWABLE_ORCA_AMOUNT);
+    assert!(env.ctx.get_raw_account(unstaker.pending_withdraw).is_err());
+    assert_eq!(
+        env.ctx.get_raw_account(unstaker.key).unwrap().lamports,
+        rent
+    );
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    assert_eq!(state.data.escrowed_orca_amount, 0);
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::Withdraw {
+            withdrawable_orca_amount,
+            destination,
+            ..
+        } => {
+            *withdrawable_orca_amount == WITHDRAWABLE_ORCA_AMOUNT
+                && *destination == unstaker.orca_ata
+        }
+        _ => false,
+    });
+    assert!(found, "Withdraw event should be emitted");
+}
+
+// Failure: the crank cannot settle before the cooldown has passed
+#[test]
+fn test_crank_withdraw_fails_before_maturity() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 0);
+    let timestamp = now(&env) + 60;
+    let unstaker = seed_pending_withdraw(&mut env, timestamp);
+    let res = do_crank_withdraw(&mut env, unstaker.key, unstaker.orca_ata, 0);
+    assert_program_error!(res, XorcaStakingProgramError::CoolDownPeriodStillActive);
+}
+
+// Failure: ORCA may only go to the unstaker's canonical ATA, even if another account is theirs
+#[test]
+fn test_crank_withdraw_fails_with_non_canonical_ata() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 0);
+    let timestamp = now(&env);
+    let unstaker = seed_pending_withdraw(&mut env, timestamp);
+    let other_orca_account = Pubkey::new_unique();
+    env.ctx
+        .write_account(
+            other_orca_account,
+            TOKEN_PROGRAM_ID,
+            crate::token_account_data!(mint => ORCA_ID, owner => unstaker.key, amount => 0),
+        )
+        .unwrap();
+    let res = do_crank_withdraw(&mut env, unstaker.key, other_orca_account, 0);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidSeeds);
+}
@@ -0,0 +1,64 @@
+use crate::assert_program_error;
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{stake_orca, stake_orca_for_exact_xorca, stake_orca_with_unique};
+use xorca::{remaining_stake_capacity, State, TokenAccount, XorcaStakingProgramError};
+
+fn setup_env(max_vault_orca: u64, max_stake_per_tx: u64) -> Env {
+    let mut env = Env::setup(&PoolSetup::funded(), 5_000_000_000, 0);
+    env.update_state(|state| {
+        state.max_vault_orca = max_vault_orca;
+        state.max_stake_per_tx = max_stake_per_tx;
+    });
+    env
+}
+
+fn remaining_capacity(env: &Env) -> Option<u64> {
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    let vault = env.ctx.get_account::<TokenAccount>(env.vault).unwrap();
+    remaining_stake_capacity(&state.data, vault.data.amount)
+}
+
+// Happy path: stakes up to the vault cap succeed and use up the remaining capacity
+#[test]
+fn test_stake_up_to_vault_cap() {
+    let mut env = setup_env(12_000_000_000, 0);
+    assert_eq!(remaining_capacity(&env), Some(2_000_000_000));
+    assert!(stake_orca(&mut env, 1_500_000_000).is_ok());
+    assert_eq!(remaining_capacity(&env), Some(500_000_000));
+    assert!(stake_orca_with_unique(&mut env, 500_000_000, 1).is_ok());
+    assert_eq!(remaining_capacity(&env), Some(0));
+}
+
+// Failure: stake that would push the vault above its cap
+#[test]
+fn test_stake_fails_above_vault_cap() {
+    let mut env = setup_env(12_000_000_000, 0);
+    let res = stake_orca(&mut env, 2_000_000_001);
+    assert_program_error!(res, XorcaStakingProgramError::MaxVaultOrcaExceeded);
+}
+
+// Failure: stake above the per-transaction cap, even with vault capacity left
+#[test]
+fn test_stake_fails_above_per_tx_cap() {
+    let mut env = setup_env(0, 1_000_000_000);
+    assert_eq!(remaining_capacity(&env), Some(1_000_000_000));
+    let res = stake_orca(&mut env, 1_000_000_001);
+    assert_program_error!(res, XorcaStakingProgramError::MaxStakePerTxExceeded);
+    assert!(stake_orca_with_unique(&mut env, 1_000_000_000, 1).is_ok());
+}
+
+// Failure: exact-output stakes are held to the same caps
+#[test]
+fn test_stake_for_exact_xorca_respects_vault_cap() {
+    let mut env = setup_env(11_000_000_000, 0);
+    let res = stake_orca_for_exact_xorca(&mut env, 2_000_000_000, 3_000_000_000);
+    assert_program_error!(res, XorcaStakingProgramError::MaxVaultOrcaExceeded);
+}
+
+// Happy path: without caps there is no capacity limit
+#[test]
+fn test_stake_uncapped() {
+    let mut env = setup_env(0, 0);
+    assert_eq!(remaining_capacity(&env), None);
+    assert!(stake_orca(&mut env, 5_000_000_000).is_ok());
+}
@@ -0,0 +1,177 @@
+use crate::assert_program_error;
+use crate::utils::assert::decode_events_from_result;
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{
+    advance_clock_env, do_deposit_rewards, do_deposit_rewards_with_unique, do_unstake, stake_orca,
+};
+use solana_sdk::{clock::Clock, pubkey::Pubkey};
+use xorca::{
+    find_pending_withdraw_pda, Event, PendingWithdraw, State, TokenAccount,
+    XorcaStakingProgramError, PAUSE_DEPOSIT_REWARDS,
+};
+use xorca_staking_program::{
+    state::state::MAX_VESTING_PERIOD_S,
+    util::math::{convert_orca_to_xorca, convert_xorca_to_orca},
+};
+
+const VESTING_PERIOD_S: i64 = 7 * 24 * 60 * 60;
+
+fn setup_env(staker_orca: u64, staker_xorca: u64) -> Env {
+    let mut env = Env::setup(&PoolSetup::funded(), staker_orca, staker_xorca);
+    // The staker acts as the rewards authority
+    let rewards_authority = env.staker;
+    env.update_state(|state| state.rewards_authority = rewards_authority);
+    env
+}
+
+// Happy path: rewards move into the vault and start vesting; the deposit is reported in an event
+#[test]
+fn test_deposit_rewards_starts_vesting() {
+    let mut env = setup_env(2_000_000_000, 0);
+    let now = env.ctx.get_sysvar::<Clock>().unix_timestamp;
+    let res = do_deposit_rewards(&mut env, 1_000_000_000, VESTING_PERIOD_S);
+    assert!(res.is_ok());
+
+    let vault = env.ctx.get_account::<TokenAccount>(env.vault).unwrap();
+    assert_eq!(vault.data.amount, 11_000_000_000);
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    assert_eq!(state.data.vesting_rewards_amount, 1_000_000_000);
+    assert_eq!(state.data.vesting_start_timestamp, now);
+    assert_eq!(state.data.vesting_end_timestamp, now + VESTING_PERIOD_S);
+    assert_eq!(state.data.total_rewards_deposited, 1_000_000_000);
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::RewardsDeposited {
+            rewards_amount,
+            vault_orca_amount,
+            vesting_rewards_amount,
+            vesting_end_timestamp,
+            total_rewards_deposited,
+            non_escrowed_orca_amount,
+            xorca_mint_supply,
+        } => {
+            *rewards_amount == 1_000_000_000
+                && *vault_orca_amount == 11_000_000_000
+                && *vesting_rewards_amount == 1_000_000_000
+                && *vesting_end_timestamp == now + VESTING_PERIOD_S
+                && *total_rewards_deposited == 1_000_000_000
+                && *non_escrowed_orca_amount == 10_000_000_000
+                && *xorca_mint_supply == 10_000_000_000
+        }
+        _ => false,
+    });
+    assert!(found, "RewardsDeposited event should be emitted");
+}
+
+// Happy path: staking right after a deposit gets the pre-deposit rate, so the deposit cannot be sandwiched
+#[test]
+fn test_stake_excludes_unvested_rewards() {
+    let mut env = setup_env(2_000_000_000, 0);
+    let (non_escrowed_before, supply_before) = env.pool_amounts();
+    assert!(do_deposit_rewards(&mut env, 1_000_000_000, VESTING_PERIOD_S).is_ok());
+
+    assert!(stake_orca(&mut env, 1_000_000_000).is_ok());
+    let user_xorca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_xorca_ata)
+        .unwrap();
+    let expected =
+        convert_orca_to_xorca(1_000_000_000, non_escrowed_before, supply_before).unwrap();
+    assert_eq!(user_xorca.data.amount, expected);
+}
+
+// Happy path: halfway through the vesting period unstakers receive half of the rewards
+#[test]
+fn test_unstake_includes_vested_rewards_linearly() {
+    let mut env = setup_env(1_000_000_000, 1_000_000_000);
+    assert!(do_deposit_rewards(&mut env, 1_000_000_000, VESTING_PERIOD_S).is_ok());
+    advance_clock_env(&mut env, VESTING_PERIOD_S / 2);
+
+    let (_, supply) = env.pool_amounts();
+    let res = do_unstake(&mut env, 0, 1_000_000_000);
+    assert!(res.is_ok());
+    let pending_withdraw_account = find_pending_withdraw_pda(&env.staker, &0).unwrap().0;
+    let pending = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap();
+    // Half of the rewards have vested: 10_000 ORCA + 500 ORCA backs the supply
+    let expected = convert_xorca_to_orca(1_000_000_000, 10_500_000_000, supply).unwrap();
+    assert_eq!(pending.data.withdrawable_orca_amount, expected);
+}
+
+// Happy path: once fully vested the rewards count towards the exchange rate
+#[test]
+fn test_rewards_fully_vested_after_period() {
+    let mut env = setup_env(2_000_000_000, 0);
+    assert!(do_deposit_rewards(&mut env, 1_000_000_000, VESTING_PERIOD_S).is_ok());
+    advance_clock_env(&mut env, VESTING_PERIOD_S);
+
+    let expected = env.expected_xorca_out(1_000_000_000);
+    assert!(stake_orca(&mut env, 1_000_000_000).is_ok());
+    let user_xorca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_xorca_ata)
+        .unwrap();
+    assert_eq!(user_xorca.data.amount, expected);
+}
+
+// Happy path: rewards deposited without vesting are counted in the exchange rate and the cumulative total
+#[test]
+fn test_deposit_rewards_accumulates_total() {
+    let mut env = setup_env(2_000_000_000, 0);
+    assert!(do_deposit_rewards(&mut env, 300_000_000, 0).is_ok());
+    let res = do_deposit_rewards_with_unique(&mut env, 700_000_000, 0, 1);
+    assert!(res.is_ok());
+
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    assert_eq!(state.data.total_rewards_deposited, 1_000_000_000);
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::RewardsDeposited {
+            total_rewards_deposited,
+            non_escrowed_orca_amount,
+            ..
+        } => {
+            *total_rewards_deposited == 1_000_000_000 && *non_escrowed_orca_amount == 11_000_000_000
+        }
+        _ => false,
+    });
+    assert!(
+        found,
+        "RewardsDeposited event should report the updated totals"
+    );
+}
+
+// Failure: only the rewards authority can deposit rewards
+#[test]
+fn test_deposit_rewards_fails_for_non_rewards_authority() {
+    let mut env = setup_env(2_000_000_000, 0);
+    env.update_state(|state| state.rewards_authority = Pubkey::new_unique());
+    let res = do_deposit_rewards(&mut env, 1_000_000_000, VESTING_PERIOD_S);
+    assert_program_error!(res, XorcaStakingProgramError::IncorrectAccountAddress);
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    assert_eq!(state.data.total_rewards_deposited, 0);
+}
+
+// Failure: zero amount or an out-of-range vesting period
+#[test]
+fn test_deposit_rewards_fails_with_invalid_arguments() {
+    let mut env = setup_env(2_000_000_000, 0);
+    let res = do_deposit_rewards(&mut env, 0, VESTING_PERIOD_S);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidRewardsAmount);
+    let res = do_deposit_rewards_with_unique(&mut env, 1_000, MAX_VESTING_PERIOD_S + 1, 1);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidVestingPeriod);
+    let res = do_deposit_rewards_with_unique(&mut env, 1_000, -1, 2);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidVestingPeriod);
+}
+
+// Failure: deposits are rejected while paused
+#[test]
+fn test_deposit_rewards_fails_while_paused() {
+    let mut env = setup_env(2_000_000_000, 0);
+    env.update_state(|state| state.pause_flags = PAUSE_DEPOSIT_REWARDS);
+    let res = do_deposit_rewards(&mut env, 1_000_000_000, VESTING_PERIOD_S);
+    assert_program_error!(res, XorcaStakingProgramError::ProgramPaused);
+}
@@ -43,6 +43,8 @@ fn test_unstake_dos_protection_various_pre_funding() {
                 orca_mint_account: ORCA_ID,
                 system_program_account: solana_sdk::system_program::ID,
                 token_program_account: crate::TOKEN_PROGRAM_ID,
+                rent_payer_account: None,
+                staker_account_pda: None,
             }
             .instruction(xorca::UnstakeInstructionArgs {
                 xorca_unstake_amount: 1_000_000,
@@ -0,0 +1,138 @@
+use crate::assert_program_error;
+use crate::utils::assert::decode_events_from_result;
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{do_unstake_for_exact_orca, stake_orca_for_exact_xorca};
+use xorca::{
+    find_pending_withdraw_pda, Event, PendingWithdraw, State, TokenAccount,
+    XorcaStakingProgramError,
+};
+use xorca_staking_program::util::math::{
+    convert_orca_to_xorca_round_up, convert_xorca_to_orca_round_up,
+};
+
+const POOL: PoolSetup = PoolSetup {
+    vault_orca: 13_000_000_000,
+    ..PoolSetup::funded()
+};
+
+// Happy path: staker receives exactly the requested xORCA and pays the rounded-up ORCA amount
+#[test]
+fn test_stake_for_exact_xorca_mints_exact_amount() {
+    let mut env = Env::setup(&POOL, 2_000_000_000, 0);
+    let (non_escrowed, supply) = env.pool_amounts();
+    let orca_in = convert_xorca_to_orca_round_up(1_000_000_000, non_escrowed, supply).unwrap();
+    let res = stake_orca_for_exact_xorca(&mut env, 1_000_000_000, orca_in);
+    assert!(res.is_ok());
+    let user_xorca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_xorca_ata)
+        .unwrap();
+    assert_eq!(user_xorca.data.amount, 1_000_000_000);
+    let user_orca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_orca_ata)
+        .unwrap();
+    assert_eq!(user_orca.data.amount, 2_000_000_000 - orca_in);
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::Stake {
+            orca_stake_amount,
+            xorca_to_mint,
+            payer,
+            beneficiary,
+            ..
+        } => {
+            *orca_stake_amount == orca_in
+                && *xorca_to_mint == 1_000_000_000
+                && *payer == env.staker
+                && *beneficiary == env.staker
+        }
+        _ => false,
+    });
+    assert!(found, "Stake event should record the exact amounts");
+}
+
+// Happy path: an empty vault stakes 1:1, matching Stake
+#[test]
+fn test_stake_for_exact_xorca_on_empty_vault_mints_one_to_one() {
+    let pool = PoolSetup {
+        xorca_supply: 0,
+        vault_orca: 0,
+        ..PoolSetup::funded()
+    };
+    let mut env = Env::setup(&pool, 2_000_000, 0);
+    let res = stake_orca_for_exact_xorca(&mut env, 1_000_000, 1_000_000);
+    assert!(res.is_ok());
+    let user_xorca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_xorca_ata)
+        .unwrap();
+    assert_eq!(user_xorca.data.amount, 1_000_000);
+    let user_orca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_orca_ata)
+        .unwrap();
+    assert_eq!(user_orca.data.amount, 1_000_000);
+    let vault = env.ctx.get_account::<TokenAccount>(env.vault).unwrap();
+    assert_eq!(vault.data.amount, 1_000_000);
+}
+
+// Failure: required ORCA exceeds the caller's maximum
+#[test]
+fn test_stake_for_exact_xorca_fails_above_max_orca_in() {
+    let mut env = Env::setup(&POOL, 2_000_000_000, 0);
+    let (non_escrowed, supply) = env.pool_amounts();
+    let orca_in = convert_xorca_to_orca_round_up(1_000_000_000, non_escrowed, supply).unwrap();
+    let res = stake_orca_for_exact_xorca(&mut env, 1_000_000_000, orca_in - 1);
+    assert_program_error!(res, XorcaStakingProgramError::SlippageExceeded);
+}
+
+// Failure: zero xORCA requested
+#[test]
+fn test_stake_for_exact_xorca_fails_with_zero_output() {
+    let mut env = Env::setup(&POOL, 2_000_000_000, 0);
+    let res = stake_orca_for_exact_xorca(&mut env, 0, u64::MAX);
+    assert_program_error!(res, XorcaStakingProgramError::InsufficientStakeAmount);
+}
+
+// Happy path: pending withdraw holds exactly the requested ORCA and the rounded-up xORCA is burned
+#[test]
+fn test_unstake_for_exact_orca_escrows_exact_amount() {
+    let mut env = Env::setup(&POOL, 0, 2_000_000_000);
+    let (non_escrowed, supply) = env.pool_amounts();
+    let xorca_in = convert_orca_to_xorca_round_up(1_000_000_000, non_escrowed, supply).unwrap();
+    let res = do_unstake_for_exact_orca(&mut env, 0, 1_000_000_000, xorca_in);
+    assert!(res.is_ok());
+    let pending_withdraw_account = find_pending_withdraw_pda(&env.staker, &0).unwrap().0;
+    let pending = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap();
+    assert_eq!(pending.data.withdrawable_orca_amount, 1_000_000_000);
+    let user_xorca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_xorca_ata)
+        .unwrap();
+    assert_eq!(user_xorca.data.amount, 2_000_000_000 - xorca_in);
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    assert_eq!(state.data.escrowed_orca_amount, 1_000_000_000);
+}
+
+// Failure: required xORCA exceeds the caller's maximum
+#[test]
+fn test_unstake_for_exact_orca_fails_above_max_xorca_in() {
+    let mut env = Env::setup(&POOL, 0, 2_000_000_000);
+    let (non_escrowed, supply) = env.pool_amounts();
+    let xorca_in = convert_orca_to_xorca_round_up(1_000_000_000, non_escrowed, supply).unwrap();
+    let res = do_unstake_for_exact_orca(&mut env, 0, 1_000_000_000, xorca_in - 1);
+    assert_program_error!(res, XorcaStakingProgramError::SlippageExceeded);
+}
+
+// Failure: required xORCA exceeds the unstaker's balance
+#[test]
+fn test_unstake_for_exact_orca_fails_with_insufficient_xorca() {
+    let mut env = Env::setup(&POOL, 0, 500_000_000);
+    let res = do_unstake_for_exact_orca(&mut env, 0, 1_000_000_000, u64::MAX);
+    assert_program_error!(res, XorcaStakingProgramError::InsufficientFunds);
+}
@@ -0,0 +1,213 @@
+use crate::utils::assert::decode_events_from_result;
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{
+    advance_clock_env, deposit_yield_into_vault, do_deposit_rewards, do_harvest,
+    do_harvest_with_unique, do_instant_unstake, stake_orca,
+};
+use crate::{assert_program_error, ORCA_ID, TOKEN_PROGRAM_ID, XORCA_ID};
+use solana_sdk::pubkey::Pubkey;
+use xorca::{Event, State, TokenAccount, TokenMint, XorcaStakingProgramError, PAUSE_HARVEST};
+use xorca_staking_program::util::math::convert_orca_to_xorca;
+
+const PERFORMANCE_FEE_BPS: u16 = 1_000;
+
+struct Treasury {
+    owner: Pubkey,
+    xorca_ata: Pubkey,
+}
+
+fn setup_env() -> (Env, Treasury) {
+    let mut env = Env::setup(&PoolSetup::funded(), 2_000_000_000, 0);
+    let treasury = Treasury {
+        owner: Pubkey::new_unique(),
+        xorca_ata: Pubkey::new_unique(),
+    };
+    env.ctx
+        .write_account(
+            treasury.xorca_ata,
+            TOKEN_PROGRAM_ID,
+            crate::token_account_data!(mint => XORCA_ID, owner => treasury.owner, amount => 0),
+        )
+        .unwrap();
+    let rewards_authority = env.staker;
+    env.update_state(|state| {
+        state.treasury = treasury.owner;
+        state.performance_fee_bps = PERFORMANCE_FEE_BPS;
+        state.rewards_authority = rewards_authority;
+    });
+    (env, treasury)
+}
+
+fn set_vault_amount(env: &mut Env, amount: u64) {
+    env.ctx
+        .write_account(
+            env.vault,
+            TOKEN_PROGRAM_ID,
+            crate::token_account_data!(mint => ORCA_ID, owner => env.state, amount => amount),
+        )
+        .unwrap();
+}
+
+fn treasury_xorca_balance(env: &Env, treasury: &Treasury) -> u64 {
+    env.ctx
+        .get_account::<TokenAccount>(treasury.xorca_ata)
+        .unwrap()
+        .data
+        .amount
+}
+
+// Happy path: the first harvest records the checkpoint without charging a fee
+#[test]
+fn test_first_harvest_records_checkpoint() {
+    let (mut env, treasury) = setup_env();
+    deposit_yield_into_vault(&mut env, 1_000_000_000, "pre-checkpoint yield");
+    let res = do_harvest(&mut env, treasury.owner, treasury.xorca_ata);
+    assert!(res.is_ok());
+
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    assert_eq!(state.data.fee_checkpoint_orca_amount, 11_000_000_000);
+    assert_eq!(state.data.fee_checkpoint_xorca_supply, 10_000_000_000);
+    assert_eq!(treasury_xorca_balance(&env, &treasury), 0);
+}
+
+// Happy path: yield since the checkpoint mints fee_bps of it to the treasury as xORCA
+#[test]
+fn test_harvest_mints_fee_on_yield() {
+    let (mut env, treasury) = setup_env();
+    assert!(do_harvest(&mut env, treasury.owner, treasury.xorca_ata).is_ok());
+    deposit_yield_into_vault(&mut env, 1_000_000_000, "yield");
+
+    let res = do_harvest_with_unique(&mut env, treasury.owner, treasury.xorca_ata, 1);
+    assert!(res.is_ok());
+
+    // 10% of 1_000 ORCA yield, priced against the pool without the fee
+    let expected_fee_xorca =
+        convert_orca_to_xorca(100_000_000, 10_900_000_000, 10_000_000_000).unwrap();
+    assert_eq!(treasury_xorca_balance(&env, &treasury), expected_fee_xorca);
+    let mint = env.ctx.get_account::<TokenMint>(XORCA_ID).unwrap();
+    assert_eq!(mint.data.supply, 10_000_000_000 + expected_fee_xorca);
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    assert_eq!(state.data.fee_checkpoint_orca_amount, 11_000_000_000);
+    assert_eq!(state.data.fee_checkpoint_xorca_supply, mint.data.supply);
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::FeeMinted {
+            yield_orca_amount,
+            fee_orca_amount,
+            fee_xorca_amount,
+            xorca_mint_supply,
+            treasury: treasury_owner,
+        } => {
+            *yield_orca_amount == 1_000_000_000
+                && *fee_orca_amount == 100_000_000
+                && *fee_xorca_amount == expected_fee_xorca
+                && *xorca_mint_supply == mint.data.supply
+                && *treasury_owner == treasury.owner
+        }
+        _ => false,
+    });
+    assert!(found, "FeeMinted event should be emitted");
+
+    // Harvesting again without new yield mints nothing
+    assert!(do_harvest_with_unique(&mut env, treasury.owner, treasury.xorca_ata, 2).is_ok());
+    assert_eq!(treasury_xorca_balance(&env, &treasury), expected_fee_xorca);
+}
+
+// Happy path: after a loss the checkpoint stays at its high-water mark, so only yield above it is charged
+#[test]
+fn test_harvest_does_not_charge_recovery_after_loss() {
+    let (mut env, treasury) = setup_env();
+    assert!(do_harvest(&mut env, treasury.owner, treasury.xorca_ata).is_ok());
+
+    // Loss: the vault drops below the checkpoint rate
+    set_vault_amount(&mut env, 9_000_000_000);
+    assert!(do_harvest_with_unique(&mut env, treasury.owner, treasury.xorca_ata, 1).is_ok());
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    assert_eq!(state.data.fee_checkpoint_orca_amount, 10_000_000_000);
+    assert_eq!(state.data.fee_checkpoint_xorca_supply, 10_000_000_000);
+
+    // Recovery back to the high-water mark is not yield
+    set_vault_amount(&mut env, 10_000_000_000);
+    assert!(do_harvest_with_unique(&mut env, treasury.owner, treasury.xorca_ata, 2).is_ok());
+    assert_eq!(treasury_xorca_balance(&env, &treasury), 0);
+
+    // Only the gain above the high-water mark is charged
+    set_vault_amount(&mut env, 11_000_000_000);
+    assert!(do_harvest_with_unique(&mut env, treasury.owner, treasury.xorca_ata, 3).is_ok());
+    let expected_fee_xorca =
+        convert_orca_to_xorca(100_000_000, 10_900_000_000, 10_000_000_000).unwrap();
+    assert_eq!(treasury_xorca_balance(&env, &treasury), expected_fee_xorca);
+}
+
+// Happy path: instant-unstake fees left in the vault raise the rate and are charged as yield
+#[test]
+fn test_harvest_charges_instant_unstake_fees() {
+    let (mut env, treasury) = setup_env();
+    env.update_state(|state| state.instant_unstake_fee_bps = 1_000);
+    assert!(do_harvest(&mut env, treasury.owner, treasury.xorca_ata).is_ok());
+
+    // Staking at the checkpoint rate is not yield; the 10% fee on the instant unstake is
+    assert!(stake_orca(&mut env, 1_000_000_000).is_ok());
+    assert!(do_instant_unstake(&mut env, 1_000_000_000).is_ok());
+    let vault = env.ctx.get_account::<TokenAccount>(env.vault).unwrap();
+    assert_eq!(vault.data.amount, 10_100_000_000);
+
+    let res = do_harvest_with_unique(&mut env, treasury.owner, treasury.xorca_ata, 1);
+    assert!(res.is_ok());
+    let expected_fee_xorca =
+        convert_orca_to_xorca(10_000_000, 10_090_000_000, 10_000_000_000).unwrap();
+    assert_eq!(treasury_xorca_balance(&env, &treasury), expected_fee_xorca);
+    let events = decode_events_from_result(&res);
+    assert!(events.iter().any(|event| match event {
+        Event::FeeMinted {
+            yield_orca_amount,
+            fee_orca_amount,
+            ..
+        } => *yield_orca_amount == 100_000_000 && *fee_orca_amount == 10_000_000,
+        _ => false,
+    }));
+}
+
+// Failure: harvest is rejected while paused
+#[test]
+fn test_harvest_fails_while_paused() {
+    let (mut env, treasury) = setup_env();
+    env.update_state(|state| state.pause_flags = PAUSE_HARVEST);
+    let res = do_harvest(&mut env, treasury.owner, treasury.xorca_ata);
+    assert_program_error!(res, XorcaStakingProgramError::ProgramPaused);
+}
+
+// Happy path: rewards are only charged once they have vested
+#[test]
+fn test_harvest_skips_unvested_rewards() {
+    let (mut env, treasury) = setup_env();
+    assert!(do_harvest(&mut env, treasury.owner, treasury.xorca_ata).is_ok());
+    assert!(do_deposit_rewards(&mut env, 1_000_000_000, 1_000).is_ok());
+
+    assert!(do_harvest_with_unique(&mut env, treasury.owner, treasury.xorca_ata, 1).is_ok());
+    assert_eq!(treasury_xorca_balance(&env, &treasury), 0);
+
+    advance_clock_env(&mut env, 1_000);
+    assert!(do_harvest_with_unique(&mut env, treasury.owner, treasury.xorca_ata, 2).is_ok());
+    let expected_fee_xorca =
+        convert_orca_to_xorca(100_000_000, 10_900_000_000, 10_000_000_000).unwrap();
+    assert_eq!(treasury_xorca_balance(&env, &treasury), expected_fee_xorca);
+}
+
+// Failure: the treasury account must match the one stored in state
+#[test]
+fn test_harvest_fails_with_wrong_treasury() {
+    let (mut env, treasury) = setup_env();
+    let res = do_harvest(&mut env, Pubkey::new_unique(), treasury.xorca_ata);
+    assert_program_error!(res, XorcaStakingProgramError::IncorrectAccountAddress);
+}
+
+// Failure: the fee token account must be owned by the treasury
+#[test]
+fn test_harvest_fails_with_foreign_treasury_ata() {
+    let (mut env, treasury) = setup_env();
+    let staker_xorca_ata = env.staker_xorca_ata;
+    let res = do_harvest(&mut env, treasury.owner, staker_xorca_ata);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidAccountData);
+}
@@ -1,15 +1,16 @@
 use crate::{
-    assert_program_error, TestContext, ATA_PROGRAM_ID, ORCA_ID, SYSTEM_PROGRAM_ID,
-    TOKEN_PROGRAM_ID, XORCA_ID,
+    assert_program_error, TestContext, ATA_PROGRAM_ID, BPF_LOADER_UPGRADEABLE_ID, ORCA_ID,
+    SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID, XORCA_ID,
 };
 use solana_sdk::{
+    instruction::Instruction,
     native_token::LAMPORTS_PER_SOL,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
 };
 use xorca::{
-    find_orca_vault_address, find_state_address, Initialize, InitializeInstructionArgs, State,
-    TokenMint, XorcaStakingProgramError,
+    find_orca_vault_address, find_program_data_address, find_state_address, Initialize,
+    InitializeInstructionArgs, State, TokenMint, XorcaStakingProgramError,
 };
 
 #[test]
@@ -60,6 +61,7 @@ fn initialize_sets_values_with_standard_values_success() {
         system_program_account: SYSTEM_PROGRAM_ID,
         token_program_account: TOKEN_PROGRAM_ID,
         associated_token_program_account: ATA_PROGRAM_ID,
+        program_data_account: find_program_data_address().unwrap().0,
     }
     .instruction(InitializeInstructionArgs {
         cool_down_period_s: 100,
@@ -69,6 +71,7 @@ fn initialize_sets_values_with_standard_values_success() {
     let state_account = ctx.get_account::<State>(state).unwrap();
     assert_eq!(state_account.data.cool_down_period_s, 100);
     assert_eq!(state_account.data.update_authority, ctx.signer());
+    assert_eq!(state_account.data.rewards_authority, ctx.signer());
     assert_eq!(state_account.account.owner, crate::XORCA_PROGRAM_ID);
     let mint_after = ctx.get_account::<TokenMint>(XORCA_ID).unwrap();
     assert_eq!(mint_after.data.mint_authority, state);
@@ -124,6 +127,7 @@ fn initialize_fails_with_wrong_system_program_account() {
         system_program_account: wrong_system,
         token_program_account: TOKEN_PROGRAM_ID,
         associated_token_program_account: ATA_PROGRAM_ID,
+        program_data_account: find_program_data_address().unwrap().0,
     }
     .instruction(InitializeInstructionArgs {
         cool_down_period_s: 1,
@@ -195,6 +199,7 @@ fn initialize_fails_with_insufficient_lamports() {
         system_program_account: SYSTEM_PROGRAM_ID,
         token_program_account: TOKEN_PROGRAM_ID,
         associated_token_program_account: ATA_PROGRAM_ID,
+        program_data_account: find_program_data_address().unwrap().0,
     }
     .instruction(InitializeInstructionArgs {
         cool_down_period_s: 1,
@@ -247,6 +252,7 @@ fn initialize_fails_when_xorca_mint_frozen() {
         system_program_account: SYSTEM_PROGRAM_ID,
         token_program_account: TOKEN_PROGRAM_ID,
         associated_token_program_account: ATA_PROGRAM_ID,
+        program_data_account: find_program_data_address().unwrap().0,
     }
     .instruction(InitializeInstructionArgs {
         cool_down_period_s: 1,
@@ -298,6 +304,7 @@ fn initialize_fails_when_xorca_mint_no_authority_flag() {
         system_program_account: SYSTEM_PROGRAM_ID,
         token_program_account: TOKEN_PROGRAM_ID,
         associated_token_program_account: ATA_PROGRAM_ID,
+        program_data_account: find_program_data_address().unwrap().0,
     }
     .instruction(InitializeInstructionArgs {
         cool_down_period_s: 1,
@@ -352,6 +359,7 @@ fn initialize_fails_when_xorca_mint_supply_nonzero() {
         system_program_account: SYSTEM_PROGRAM_ID,
         token_program_account: TOKEN_PROGRAM_ID,
         associated_token_program_account: ATA_PROGRAM_ID,
+        program_data_account: find_program_data_address().unwrap().0,
     }
     .instruction(InitializeInstructionArgs {
         cool_down_period_s: 1,
@@ -405,6 +413,7 @@ fn initialize_fails_when_xorca_mint_wrong_owner() {
         system_program_account: SYSTEM_PROGRAM_ID,
         token_program_account: TOKEN_PROGRAM_ID,
         associated_token_program_account: ATA_PROGRAM_ID,
+        program_data_account: find_program_data_address().unwrap().0,
     }
     .instruction(InitializeInstructionArgs {
         cool_down_period_s: 1,
@@ -459,6 +468,7 @@ fn initialize_fails_when_xorca_mint_wrong_address() {
         system_program_account: SYSTEM_PROGRAM_ID,
         token_program_account: TOKEN_PROGRAM_ID,
         associated_token_program_account: ATA_PROGRAM_ID,
+        program_data_account: find_program_data_address().unwrap().0,
     }
     .instruction(InitializeInstructionArgs {
         cool_down_period_s: 1,
@@ -519,6 +529,7 @@ fn initialize_fails_when_state_already_initialized() {
         system_program_account: SYSTEM_PROGRAM_ID,
         token_program_account: TOKEN_PROGRAM_ID,
         associated_token_program_account: ATA_PROGRAM_ID,
+        program_data_account: find_program_data_address().unwrap().0,
     }
     .instruction(InitializeInstructionArgs {
         cool_down_period_s: 1,
@@ -578,6 +589,7 @@ fn initialize_fails_with_wrong_state_owner() {
         system_program_account: SYSTEM_PROGRAM_ID,
         token_program_account: TOKEN_PROGRAM_ID,
         associated_token_program_account: ATA_PROGRAM_ID,
+        program_data_account: find_program_data_address().unwrap().0,
     }
     .instruction(InitializeInstructionArgs {
         cool_down_period_s: 1,
@@ -643,6 +655,7 @@ fn initialize_fails_when_payer_is_not_deployer() {
         system_program_account: SYSTEM_PROGRAM_ID,
         token_program_account: TOKEN_PROGRAM_ID,
         associated_token_program_account: ATA_PROGRAM_ID,
+        program_data_account: find_program_data_address().unwrap().0,
     }
     .instruction(InitializeInstructionArgs {
         cool_down_period_s: 100,
@@ -704,6 +717,7 @@ fn initialize_sets_different_update_authority_success() {
         system_program_account: SYSTEM_PROGRAM_ID,
         token_program_account: TOKEN_PROGRAM_ID,
         associated_token_program_account: ATA_PROGRAM_ID,
+        program_data_account: find_program_data_address().unwrap().0,
     }
     .instruction(InitializeInstructionArgs {
         cool_down_period_s: 100,
@@ -778,6 +792,7 @@ fn initialize_sets_different_update_authority_no_signer_fail() {
         system_program_account: SYSTEM_PROGRAM_ID,
         token_program_account: TOKEN_PROGRAM_ID,
         associated_token_program_account: ATA_PROGRAM_ID,
+        program_data_account: find_program_data_address().unwrap().0,
     }
     .instruction(InitializeInstructionArgs {
         cool_down_period_s: 1,
@@ -792,3 +807,124 @@ fn initialize_sets_different_update_authority_no_signer_fail() {
         "Should fail with SanitizeFailure when update authority is not a signer"
     );
 }
+
+fn seed_mints(ctx: &mut TestContext, state: Pubkey) {
+    ctx.write_account(
+        XORCA_ID,
+        TOKEN_PROGRAM_ID,
+        crate::token_mint_data!(
+            supply => 0,
+            decimals => 6,
+            mint_authority_flag => 1,
+            mint_authority => state,
+            is_initialized => true,
+            freeze_authority_flag => 0,
+            freeze_authority => Pubkey::default(),
+        ),
+    )
+    .unwrap();
+    ctx.write_account(
+        ORCA_ID,
+        TOKEN_PROGRAM_ID,
+        crate::token_mint_data!(
+            supply => 0,
+            decimals => 6,
+            mint_authority_flag => 1,
+            mint_authority => Pubkey::default(),
+            is_initialized => true,
+            freeze_authority_flag => 0,
+            freeze_authority => Pubkey::default(),
+        ),
+    )
+    .unwrap();
+}
+
+fn initialize_ix(payer: Pubkey, program_data_account: Pubkey) -> Instruction {
+    let (state, _) = find_state_address().unwrap();
+    let (vault_account, _) = find_orca_vault_address(&state, &TOKEN_PROGRAM_ID, &ORCA_ID).unwrap();
+    Initialize {
+        payer_account: payer,
+        update_authority_account: payer,
+        state_account: state,
+        vault_account,
+        xorca_mint_account: XORCA_ID,
+        orca_mint_account: ORCA_ID,
+        system_program_account: SYSTEM_PROGRAM_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+        associated_token_program_account: ATA_PROGRAM_ID,
+        program_data_account,
+    }
+    .instruction(InitializeInstructionArgs {
+        cool_down_period_s: 100,
+    })
+}
+
+// Happy path: redeploying with a new upgrade authority lets it initialize without a rebuild
+#[test]
+fn initialize_succeeds_for_new_upgrade_authority() {
+    let mut ctx = TestContext::new();
+    let (state, _) = find_state_address().unwrap();
+    seed_mints(&mut ctx, state);
+    let upgrade_authority = Keypair::new();
+    ctx.svm
+        .borrow_mut()
+        .airdrop(&upgrade_authority.pubkey(), LAMPORTS_PER_SOL)
+        .unwrap();
+    ctx.deploy_program(Some(upgrade_authority.pubkey()));
+
+    let ix = initialize_ix(
+        upgrade_authority.pubkey(),
+        find_program_data_address().unwrap().0,
+    );
+    assert!(ctx.sends_with_signers(&[ix], &[&upgrade_authority]).is_ok());
+    let state_account = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(
+        state_account.data.update_authority,
+        upgrade_authority.pubkey()
+    );
+}
+
+// Failure: the previous upgrade authority is rejected once the authority changes
+#[test]
+fn initialize_fails_for_previous_upgrade_authority() {
+    let mut ctx = TestContext::new();
+    let (state, _) = find_state_address().unwrap();
+    seed_mints(&mut ctx, state);
+    ctx.deploy_program(Some(Pubkey::new_unique()));
+
+    let ix = initialize_ix(ctx.signer(), find_program_data_address().unwrap().0);
+    let res = ctx.sends(&[ix]);
+    assert_program_error!(res, XorcaStakingProgramError::UnauthorizedDeployerAccess);
+}
+
+// Failure: an immutable program has no upgrade authority to initialize it
+#[test]
+fn initialize_fails_when_program_is_immutable() {
+    let mut ctx = TestContext::new();
+    let (state, _) = find_state_address().unwrap();
+    seed_mints(&mut ctx, state);
+    ctx.deploy_program(None);
+
+    let ix = initialize_ix(ctx.signer(), find_program_data_address().unwrap().0);
+    let res = ctx.sends(&[ix]);
+    assert_program_error!(res, XorcaStakingProgramError::UnauthorizedDeployerAccess);
+}
+
+// Failure: a ProgramData account of another program is rejected even if the payer controls it
+#[test]
+fn initialize_fails_with_wrong_program_data_account() {
+    let mut ctx = TestContext::new();
+    let (state, _) = find_state_address().unwrap();
+    seed_mints(&mut ctx, state);
+    let fake_program_data = Pubkey::new_unique();
+    let mut data = vec![0u8; 45];
+    data[..4].copy_from_slice(&3u32.to_le_bytes());
+    data[12] = 1;
+    data[13..45].copy_from_slice(ctx.signer().as_ref());
+    ctx.write_raw_account(fake_program_data, BPF_LOADER_UPGRADEABLE_ID, data)
+        .unwrap();
+
+    let ix = initialize_ix(ctx.signer(), fake_program_data);
+    let res = ctx.sends(&[ix]);
+    assert_program_error!(res, XorcaStakingProgramError::IncorrectAccountAddress);
+}
@@ -0,0 +1,182 @@
+use crate::utils::assert::{decode_events_from_result, take_withdraw_snapshot};
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{do_instant_unstake, do_instant_unstake_with_unique};
+use crate::{assert_program_error, XORCA_ID};
+use xorca::{Event, State, TokenAccount, TokenMint, XorcaStakingProgramError};
+use xorca_staking_program::util::math::{calculate_instant_unstake_fee, convert_xorca_to_orca};
+
+// Happy path: with no fee the unstaker receives the full conversion immediately
+#[test]
+fn test_instant_unstake_without_fee_pays_full_amount() {
+    let pool = PoolSetup {
+        cool_down_period_s: 7 * 24 * 60 * 60,
+        ..PoolSetup::funded()
+    };
+    let mut env = Env::setup(&pool, 0, 1_000_000_000);
+    let snap = take_withdraw_snapshot(
+        &env.ctx,
+        env.state,
+        env.vault,
+        env.staker_orca_ata,
+        env.staker_xorca_ata,
+        XORCA_ID,
+    );
+    let xorca_burn = 1_000_000_000u64;
+    let expected = convert_xorca_to_orca(
+        xorca_burn,
+        snap.vault_before - snap.escrow_before,
+        snap.xorca_supply_before,
+    )
+    .unwrap();
+
+    assert!(do_instant_unstake(&mut env, xorca_burn).is_ok());
+
+    let user_orca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_orca_ata)
+        .unwrap();
+    assert_eq!(user_orca.data.amount, snap.user_orca_before + expected);
+    let user_xorca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_xorca_ata)
+        .unwrap();
+    assert_eq!(user_xorca.data.amount, snap.user_xorca_before - xorca_burn);
+    let vault = env.ctx.get_account::<TokenAccount>(env.vault).unwrap();
+    assert_eq!(vault.data.amount, snap.vault_before - expected);
+    let mint = env.ctx.get_account::<TokenMint>(XORCA_ID).unwrap();
+    assert_eq!(mint.data.supply, snap.xorca_supply_before - xorca_burn);
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    assert_eq!(
+        state.data.escrowed_orca_amount, snap.escrow_before,
+        "escrow must not change on instant unstake"
+    );
+}
+
+// Fee is withheld from the payout and remains in the vault, raising the exchange rate
+#[test]
+fn test_instant_unstake_fee_stays_in_vault() {
+    let pool = PoolSetup {
+        vault_orca: 20_000_000_000,
+        escrowed_orca: 1_000_000_000,
+        cool_down_period_s: 7 * 24 * 60 * 60,
+        ..PoolSetup::funded()
+    };
+    let mut env = Env::setup(&pool, 0, 1_000_000_000);
+    env.update_state(|state| state.instant_unstake_fee_bps = 250);
+    let snap = take_withdraw_snapshot(
+        &env.ctx,
+        env.state,
+        env.vault,
+        env.staker_orca_ata,
+        env.staker_xorca_ata,
+        XORCA_ID,
+    );
+    let xorca_burn = 1_000_000_000u64;
+    let gross = convert_xorca_to_orca(
+        xorca_burn,
+        snap.vault_before - snap.escrow_before,
+        snap.xorca_supply_before,
+    )
+    .unwrap();
+    let fee = calculate_instant_unstake_fee(gross, 250).unwrap();
+    assert!(fee > 0);
+
+    let res = do_instant_unstake(&mut env, xorca_burn);
+    assert!(res.is_ok());
+
+    let user_orca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_orca_ata)
+        .unwrap();
+    assert_eq!(user_orca.data.amount, snap.user_orca_before + gross - fee);
+    let vault = env.ctx.get_account::<TokenAccount>(env.vault).unwrap();
+    assert_eq!(vault.data.amount, snap.vault_before - (gross - fee));
+
+    // Remaining stakers are now backed by more ORCA per xORCA than before
+    let rate_before = (snap.vault_before - snap.escrow_before) as u128 * 1_000_000_000
+        / snap.xorca_supply_before as u128;
+    let rate_after = (vault.data.amount - snap.escrow_before) as u128 * 1_000_000_000
+        / (snap.xorca_supply_before - xorca_burn) as u128;
+    assert!(rate_after > rate_before, "exchange rate should increase");
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::InstantUnstake {
+            xorca_unstake_amount,
+            orca_unstake_amount,
+            fee_orca_amount,
+            instant_unstake_fee_bps,
+            vault_escrowed_orca_amount,
+            ..
+        } => {
+            *xorca_unstake_amount == xorca_burn
+                && *orca_unstake_amount == gross - fee
+                && *fee_orca_amount == fee
+                && *instant_unstake_fee_bps == 250
+                && *vault_escrowed_orca_amount == snap.escrow_before
+        }
+        _ => false,
+    });
+    assert!(found, "InstantUnstake event should be emitted");
+}
+
+// Repeated instant unstakes keep applying the fee
+#[test]
+fn test_instant_unstake_multiple_times() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 3_000_000_000);
+    env.update_state(|state| state.instant_unstake_fee_bps = 100);
+    for i in 0..3u64 {
+        assert!(do_instant_unstake_with_unique(&mut env, 1_000_000_000, i).is_ok());
+    }
+    let user_xorca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_xorca_ata)
+        .unwrap();
+    assert_eq!(user_xorca.data.amount, 0);
+    let vault = env.ctx.get_account::<TokenAccount>(env.vault).unwrap();
+    let mint = env.ctx.get_account::<TokenMint>(XORCA_ID).unwrap();
+    assert_eq!(mint.data.supply, 7_000_000_000);
+    assert!(
+        vault.data.amount > 7_000_000_000,
+        "fees should remain in the vault"
+    );
+}
+
+// Failure: a 100% fee leaves nothing to pay out
+#[test]
+fn test_instant_unstake_fails_when_fee_consumes_everything() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000_000);
+    env.update_state(|state| state.instant_unstake_fee_bps = 10_000);
+    let res = do_instant_unstake(&mut env, 1_000_000_000);
+    assert_program_error!(res, XorcaStakingProgramError::InsufficientUnstakeAmount);
+}
+
+// Failure: a corrupted fee above 10000 bps is rejected
+#[test]
+fn test_instant_unstake_fails_with_invalid_fee() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000_000);
+    env.update_state(|state| state.instant_unstake_fee_bps = 10_001);
+    let res = do_instant_unstake(&mut env, 1_000_000_000);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidInstantUnstakeFee);
+}
+
+// Failure: unstaking more xORCA than the user holds
+#[test]
+fn test_instant_unstake_fails_with_insufficient_xorca() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000);
+    let res = do_instant_unstake(&mut env, 1_001);
+    assert_program_error!(res, XorcaStakingProgramError::InsufficientFunds);
+}
+
+// Failure: vault holds less than the escrow
+#[test]
+fn test_instant_unstake_fails_when_vault_below_escrow() {
+    let pool = PoolSetup {
+        vault_orca: 1_000,
+        escrowed_orca: 2_000,
+        ..PoolSetup::funded()
+    };
+    let mut env = Env::setup(&pool, 0, 1_000_000);
+    let res = do_instant_unstake(&mut env, 1_000_000);
+    assert_program_error!(res, XorcaStakingProgramError::InsufficientVaultBacking);
+}
@@ -0,0 +1,185 @@
+use crate::assert_program_error;
+use crate::utils::assert::{assert_account_closed, decode_events_from_result};
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{
+    advance_clock_env, do_merge_pending_withdraws, do_withdraw, unstake_and_advance,
+};
+use xorca::{
+    find_pending_withdraw_pda, Event, PendingWithdraw, State, TokenAccount,
+    XorcaStakingProgramError, PAUSE_WITHDRAW,
+};
+
+const POOL: PoolSetup = PoolSetup {
+    cool_down_period_s: 1_000,
+    ..PoolSetup::funded()
+};
+
+// Base fee paid by the unstaker for a single-signature transaction
+const TRANSACTION_FEE: u64 = 5000;
+
+fn pending_withdraw(env: &Env, withdraw_index: u8) -> PendingWithdraw {
+    let address = find_pending_withdraw_pda(&env.staker, &withdraw_index)
+        .unwrap()
+        .0;
+    env.ctx
+        .get_account::<PendingWithdraw>(address)
+        .unwrap()
+        .data
+}
+
+// Happy path: amounts are summed, the later unlock time is kept and the source is closed
+#[test]
+fn test_merge_pending_withdraws_sums_amounts_and_keeps_later_timestamp() {
+    let mut env = Env::setup(&POOL, 0, 3_000_000);
+    unstake_and_advance(&mut env, 0, 1_000_000, 100);
+    let source = unstake_and_advance(&mut env, 1, 2_000_000, 0);
+    let target_before = pending_withdraw(&env, 0);
+    let source_before = pending_withdraw(&env, 1);
+    assert!(source_before.withdrawable_timestamp > target_before.withdrawable_timestamp);
+    let escrow_before = env
+        .ctx
+        .get_account::<State>(env.state)
+        .unwrap()
+        .data
+        .escrowed_orca_amount;
+    let source_lamports = env.ctx.get_raw_account(source).unwrap().lamports;
+    let staker_lamports_before = env.ctx.get_raw_account(env.staker).unwrap().lamports;
+
+    let res = do_merge_pending_withdraws(&mut env, 0, &[1]);
+    assert!(res.is_ok());
+
+    let target_after = pending_withdraw(&env, 0);
+    let expected_amount =
+        target_before.withdrawable_orca_amount + source_before.withdrawable_orca_amount;
+    assert_eq!(target_after.withdrawable_orca_amount, expected_amount);
+    assert_eq!(
+        target_after.withdrawable_timestamp,
+        source_before.withdrawable_timestamp
+    );
+    assert_account_closed(&env.ctx, source, "source pending withdraw closed");
+    let staker_lamports_after = env.ctx.get_raw_account(env.staker).unwrap().lamports;
+    assert_eq!(
+        staker_lamports_after,
+        staker_lamports_before + source_lamports - TRANSACTION_FEE,
+        "source rent should be refunded to the unstaker"
+    );
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    assert_eq!(
+        state.data.escrowed_orca_amount, escrow_before,
+        "escrow must not change on merge"
+    );
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::MergePendingWithdraws {
+            withdrawable_orca_amount,
+            withdrawable_timestamp,
+            merged_count,
+            withdraw_index,
+        } => {
+            *withdrawable_orca_amount == expected_amount
+                && *withdrawable_timestamp == source_before.withdrawable_timestamp
+                && *merged_count == 1
+                && *withdraw_index == 0
+        }
+        _ => false,
+    });
+    assert!(found, "MergePendingWithdraws event should be emitted");
+}
+
+// Happy path: merging into a later target keeps its own unlock time and the merged withdraw pays out in full
+#[test]
+fn test_merge_many_pending_withdraws_then_withdraw() {
+    let mut env = Env::setup(&POOL, 0, 4_000_000);
+    unstake_and_advance(&mut env, 10, 1_000_000, 10);
+    unstake_and_advance(&mut env, 11, 1_000_000, 10);
+    unstake_and_advance(&mut env, 12, 1_000_000, 10);
+    unstake_and_advance(&mut env, 13, 1_000_000, 0);
+    let target_before = pending_withdraw(&env, 13);
+
+    assert!(do_merge_pending_withdraws(&mut env, 13, &[10, 11, 12]).is_ok());
+    let target_after = pending_withdraw(&env, 13);
+    assert_eq!(target_after.withdrawable_orca_amount, 4_000_000);
+    assert_eq!(
+        target_after.withdrawable_timestamp,
+        target_before.withdrawable_timestamp
+    );
+
+    advance_clock_env(&mut env, 1_000);
+    let target = find_pending_withdraw_pda(&env.staker, &13).unwrap().0;
+    assert!(do_withdraw(&mut env, target, 13).is_ok());
+    let user_orca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_orca_ata)
+        .unwrap();
+    assert_eq!(user_orca.data.amount, 4_000_000);
+}
+
+// Failure: the merged withdraw is locked until the latest source unlocks
+#[test]
+fn test_merge_pending_withdraws_extends_cooldown_of_target() {
+    let mut env = Env::setup(&POOL, 0, 2_000_000);
+    unstake_and_advance(&mut env, 0, 1_000_000, 500);
+    unstake_and_advance(&mut env, 1, 1_000_000, 500);
+    // Target matured, source still has 500 seconds left
+    assert!(do_merge_pending_withdraws(&mut env, 0, &[1]).is_ok());
+    let target = find_pending_withdraw_pda(&env.staker, &0).unwrap().0;
+    let res = do_withdraw(&mut env, target, 0);
+    assert_program_error!(res, XorcaStakingProgramError::CoolDownPeriodStillActive);
+}
+
+// Failure: at least one source account is required
+#[test]
+fn test_merge_pending_withdraws_fails_without_sources() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000);
+    unstake_and_advance(&mut env, 0, 1_000_000, 0);
+    let res = do_merge_pending_withdraws(&mut env, 0, &[]);
+    assert_program_error!(res, XorcaStakingProgramError::NotEnoughAccountKeys);
+}
+
+// Failure: the target cannot be merged into itself
+#[test]
+fn test_merge_pending_withdraws_fails_when_target_is_source() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000);
+    unstake_and_advance(&mut env, 0, 1_000_000, 0);
+    let res = do_merge_pending_withdraws(&mut env, 0, &[0]);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidAccountData);
+}
+
+// Failure: a source listed twice is rejected instead of being counted twice
+#[test]
+fn test_merge_pending_withdraws_fails_with_duplicate_source() {
+    let mut env = Env::setup(&POOL, 0, 2_000_000);
+    unstake_and_advance(&mut env, 0, 1_000_000, 0);
+    unstake_and_advance(&mut env, 1, 1_000_000, 0);
+    let res = do_merge_pending_withdraws(&mut env, 0, &[1, 1]);
+    assert_program_error!(res, XorcaStakingProgramError::IncorrectOwner);
+    assert_eq!(
+        pending_withdraw(&env, 0).withdrawable_orca_amount,
+        1_000_000
+    );
+}
+
+// Failure: a source index without a pending withdraw
+#[test]
+fn test_merge_pending_withdraws_fails_with_missing_source() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000);
+    unstake_and_advance(&mut env, 0, 1_000_000, 0);
+    let res = do_merge_pending_withdraws(&mut env, 0, &[2]);
+    assert_program_error!(res, XorcaStakingProgramError::IncorrectOwner);
+}
+
+// Failure: merging is halted while withdrawals are paused
+#[test]
+fn test_merge_pending_withdraws_fails_when_withdraw_paused() {
+    let mut env = Env::setup(&POOL, 0, 2_000_000);
+    unstake_and_advance(&mut env, 0, 1_000_000, 0);
+    unstake_and_advance(&mut env, 1, 1_000_000, 0);
+    env.update_state(|state| state.pause_flags = PAUSE_WITHDRAW);
+    let res = do_merge_pending_withdraws(&mut env, 0, &[1]);
+    assert_program_error!(res, XorcaStakingProgramError::ProgramPaused);
+    assert_eq!(
+        pending_withdraw(&env, 0).withdrawable_orca_amount,
+        1_000_000
+    );
+}
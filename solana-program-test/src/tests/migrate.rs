@@ -0,0 +1,277 @@
+use crate::utils::assert::decode_events_from_result;
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{advance_clock_env, do_migrate, do_withdraw, stake_orca};
+use crate::{assert_program_error, XORCA_PROGRAM_ID};
+use litesvm::types::TransactionResult;
+use solana_sdk::{clock::Clock, pubkey::Pubkey};
+use xorca::{
+    find_pending_withdraw_pda, find_staker_account_pda, Event, PendingWithdraw, StakerAccount,
+    State, TokenAccount, XorcaStakingProgramError,
+};
+
+const WITHDRAWABLE_ORCA_AMOUNT: u64 = 1_000_000;
+const STAKER_ORCA_AMOUNT: u64 = 1_000_000;
+const LEGACY_PENDING_WITHDRAW_LEN: usize = 1024;
+
+fn setup_env() -> Env {
+    let pool = PoolSetup {
+        escrowed_orca: WITHDRAWABLE_ORCA_AMOUNT,
+        ..PoolSetup::funded()
+    };
+    let mut env = Env::setup(&pool, STAKER_ORCA_AMOUNT, 0);
+    // Old-layout state: the version byte is still padding
+    let update_authority = env.staker;
+    env.update_state(|state| {
+        state.version = 0;
+        state.update_authority = update_authority;
+    });
+    env
+}
+
+// Pre-compact pending withdraw: full-size account. v0 has no stored owner; v1 stores it
+fn write_legacy_pending_withdraw(env: &mut Env, withdraw_index: u8, version: u8) -> Pubkey {
+    let (pending_withdraw_account, bump) =
+        find_pending_withdraw_pda(&env.staker, &withdraw_index).unwrap();
+    let withdrawable_timestamp = env.ctx.get_sysvar::<Clock>().unix_timestamp;
+    let mut data = borsh::to_vec(&crate::pending_withdraw_data!(
+        version => version,
+        unstaker => env.staker,
+        owner => if version == 0 { Pubkey::default() } else { env.staker },
+        withdraw_index => withdraw_index,
+        withdrawable_orca_amount => WITHDRAWABLE_ORCA_AMOUNT,
+        withdrawable_timestamp => withdrawable_timestamp,
+        bump => bump,
+    ))
+    .unwrap();
+    data.resize(LEGACY_PENDING_WITHDRAW_LEN, 0);
+    env.ctx
+        .write_raw_account(pending_withdraw_account, XORCA_PROGRAM_ID, data)
+        .unwrap();
+    pending_withdraw_account
+}
+
+fn migrated_count(res: &TransactionResult) -> Option<u16> {
+    decode_events_from_result(res)
+        .iter()
+        .find_map(|event| match event {
+            Event::AccountsMigrated { migrated_count, .. } => Some(*migrated_count),
+            _ => None,
+        })
+}
+
+// Happy path: old-layout state and pending withdraws are upgraded in place
+#[test]
+fn test_migrate_upgrades_old_layouts() {
+    let mut env = setup_env();
+    let pending_withdraw_account = write_legacy_pending_withdraw(&mut env, 0, 0);
+
+    let lamports_before = env
+        .ctx
+        .get_raw_account(pending_withdraw_account)
+        .unwrap()
+        .lamports;
+
+    let res = do_migrate(&mut env, &[pending_withdraw_account]);
+    assert!(res.is_ok());
+    assert_eq!(migrated_count(&res), Some(2));
+
+    let state = env.ctx.get_account::<State>(env.state).unwrap().data;
+    assert_eq!(state.version, 1);
+    assert_eq!(state.update_authority, env.staker);
+    let pending_withdraw = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap();
+    assert_eq!(pending_withdraw.data.version, 2);
+    assert_eq!(pending_withdraw.data.owner, env.staker);
+    assert_eq!(
+        pending_withdraw.data.withdrawable_orca_amount,
+        WITHDRAWABLE_ORCA_AMOUNT
+    );
+    // Compacted in place; the freed rent stays with the account until it is closed
+    assert_eq!(pending_withdraw.account.data.len(), PendingWithdraw::LEN);
+    assert_eq!(pending_withdraw.account.lamports, lamports_before);
+}
+
+// Happy path: migrating again leaves current layouts untouched
+#[test]
+fn test_migrate_is_idempotent() {
+    let mut env = setup_env();
+    let pending_withdraw_account = write_legacy_pending_withdraw(&mut env, 1, 0);
+    assert!(do_migrate(&mut env, &[pending_withdraw_account]).is_ok());
+
+    advance_clock_env(&mut env, 1);
+    let res = do_migrate(&mut env, &[pending_withdraw_account]);
+    assert!(res.is_ok());
+    assert_eq!(migrated_count(&res), Some(0));
+}
+
+// Happy path: staker accounts are accepted alongside pending withdraws
+#[test]
+fn test_migrate_staker_account() {
+    let mut env = setup_env();
+    let (staker_account_pda, bump) = find_staker_account_pda(&env.staker).unwrap();
+    let mut staker_account = crate::zeroed_type!(StakerAccount);
+    staker_account.discriminator = xorca::AccountDiscriminator::StakerAccount;
+    staker_account.bump = bump;
+    staker_account.staker = env.staker;
+    env.ctx
+        .write_account(staker_account_pda, XORCA_PROGRAM_ID, staker_account)
+        .unwrap();
+
+    let res = do_migrate(&mut env, &[staker_account_pda]);
+    assert_eq!(migrated_count(&res), Some(2));
+    let staker_account = env
+        .ctx
+        .get_account::<StakerAccount>(staker_account_pda)
+        .unwrap()
+        .data;
+    assert_eq!(staker_account.version, 1);
+}
+
+// Happy path: old layouts stay usable before they are migrated
+#[test]
+fn test_old_layout_pending_withdraw_withdraws_without_migration() {
+    let mut env = setup_env();
+    let pending_withdraw_account = write_legacy_pending_withdraw(&mut env, 2, 0);
+    let rent = env
+        .ctx
+        .get_raw_account(pending_withdraw_account)
+        .unwrap()
+        .lamports;
+    let staker_lamports_before = env.ctx.get_raw_account(env.staker).unwrap().lamports;
+
+    let res = do_withdraw(&mut env, pending_withdraw_account, 2);
+    assert!(res.is_ok());
+    assert!(env.ctx.get_raw_account(pending_withdraw_account).is_err());
+    // The full-size account's rent goes back to the unstaker
+    let staker_lamports_after = env.ctx.get_raw_account(env.staker).unwrap().lamports;
+    assert!(staker_lamports_after > staker_lamports_before);
+    assert!(staker_lamports_after <= staker_lamports_before + rent);
+}
+
+// Happy path: an unmigrated full-size v1 pending withdraw withdraws to its stored owner
+#[test]
+fn test_v1_pending_withdraw_withdraws_without_migration() {
+    let mut env = setup_env();
+    let pending_withdraw_account = write_legacy_pending_withdraw(&mut env, 4, 1);
+    let pending_withdraw = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap();
+    assert_eq!(
+        pending_withdraw.account.data.len(),
+        LEGACY_PENDING_WITHDRAW_LEN
+    );
+    let rent = pending_withdraw.account.lamports;
+    let staker_lamports_before = env.ctx.get_raw_account(env.staker).unwrap().lamports;
+    let escrowed_orca_before = env
+        .ctx
+        .get_account::<State>(env.state)
+        .unwrap()
+        .data
+        .escrowed_orca_amount;
+
+    let res = do_withdraw(&mut env, pending_withdraw_account, 4);
+    assert!(res.is_ok());
+    assert!(env.ctx.get_raw_account(pending_withdraw_account).is_err());
+    let staker_orca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_orca_ata)
+        .unwrap();
+    assert_eq!(
+        staker_orca.data.amount,
+        STAKER_ORCA_AMOUNT + WITHDRAWABLE_ORCA_AMOUNT
+    );
+    let state = env.ctx.get_account::<State>(env.state).unwrap().data;
+    assert_eq!(
+        state.escrowed_orca_amount,
+        escrowed_orca_before - WITHDRAWABLE_ORCA_AMOUNT
+    );
+    // The full-size account's rent goes back to the unstaker
+    let staker_lamports_after = env.ctx.get_raw_account(env.staker).unwrap().lamports;
+    assert!(staker_lamports_after > staker_lamports_before);
+    assert!(staker_lamports_after <= staker_lamports_before + rent);
+}
+
+// Failure: only the update authority can migrate
+#[test]
+fn test_migrate_fails_for_non_update_authority() {
+    let mut env = setup_env();
+    env.update_state(|state| state.update_authority = Pubkey::new_unique());
+    let res = do_migrate(&mut env, &[]);
+    assert_program_error!(res, XorcaStakingProgramError::IncorrectAccountAddress);
+}
+
+// Failure: only pending withdraws and staker accounts can be passed for migration
+#[test]
+fn test_migrate_fails_with_unsupported_account() {
+    let mut env = setup_env();
+    let state = env.state;
+    let res = do_migrate(&mut env, &[state]);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidAccountData);
+}
+
+// Failure: a state written by a newer program version is rejected
+#[test]
+fn test_unknown_state_version_is_rejected() {
+    let mut env = setup_env();
+    env.update_state(|state| state.version = 2);
+
+    let res = stake_orca(&mut env, 1_000_000);
+    assert_program_error!(res, XorcaStakingProgramError::UnsupportedAccountVersion);
+    let res = do_migrate(&mut env, &[]);
+    assert_program_error!(res, XorcaStakingProgramError::UnsupportedAccountVersion);
+}
+
+// Failure: a pending withdraw written by a newer program version is rejected
+#[test]
+fn test_unknown_pending_withdraw_version_is_rejected() {
+    let mut env = setup_env();
+    let pending_withdraw_account = write_legacy_pending_withdraw(&mut env, 3, 0);
+    let mut pending_withdraw = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap()
+        .data;
+    pending_withdraw.version = 3;
+    env.ctx
+        .write_account(pending_withdraw_account, XORCA_PROGRAM_ID, pending_withdraw)
+        .unwrap();
+
+    let res = do_withdraw(&mut env, pending_withdraw_account, 3);
+    assert_program_error!(res, XorcaStakingProgramError::UnsupportedAccountVersion);
+}
+
+// Failure: pending withdraws are either the compact or the legacy size, nothing in between
+#[test]
+fn test_pending_withdraw_with_unexpected_size_is_rejected() {
+    let mut env = setup_env();
+    let pending_withdraw_account = write_legacy_pending_withdraw(&mut env, 5, 1);
+    let mut data = env
+        .ctx
+        .get_raw_account(pending_withdraw_account)
+        .unwrap()
+        .data;
+    data.truncate(LEGACY_PENDING_WITHDRAW_LEN / 2);
+    env.ctx
+        .write_raw_account(pending_withdraw_account, XORCA_PROGRAM_ID, data)
+        .unwrap();
+
+    let res = do_withdraw(&mut env, pending_withdraw_account, 5);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidAccountData);
+}
+
+// Failure: an oversized state account is rejected
+#[test]
+fn test_oversized_state_is_rejected() {
+    let mut env = setup_env();
+    let mut data = env.ctx.get_raw_account(env.state).unwrap().data;
+    data.push(0);
+    env.ctx
+        .write_raw_account(env.state, XORCA_PROGRAM_ID, data)
+        .unwrap();
+
+    let res = stake_orca(&mut env, 1_000_000);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidAccountData);
+}
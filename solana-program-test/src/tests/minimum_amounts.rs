@@ -0,0 +1,82 @@
+use crate::assert_program_error;
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{
+    do_instant_unstake, do_unstake, do_unstake_for_exact_orca, stake_orca,
+    stake_orca_for_exact_xorca,
+};
+use xorca::{find_pending_withdraw_pda, XorcaStakingProgramError};
+
+const MIN_STAKE_ORCA: u64 = 1_000_000;
+const MIN_UNSTAKE_XORCA: u64 = 1_000_000;
+
+fn setup_env() -> Env {
+    let mut env = Env::setup(&PoolSetup::funded(), 1_000_000_000, 1_000_000_000);
+    env.update_state(|state| {
+        state.min_stake_orca = MIN_STAKE_ORCA;
+        state.min_unstake_xorca = MIN_UNSTAKE_XORCA;
+    });
+    env
+}
+
+// Happy path: a stake of exactly the minimum succeeds
+#[test]
+fn test_stake_at_minimum_succeeds() {
+    let mut env = setup_env();
+    assert!(stake_orca(&mut env, MIN_STAKE_ORCA).is_ok());
+}
+
+// Failure: a stake below the minimum is rejected
+#[test]
+fn test_stake_below_minimum_fails() {
+    let mut env = setup_env();
+    let res = stake_orca(&mut env, MIN_STAKE_ORCA - 1);
+    assert_program_error!(res, XorcaStakingProgramError::StakeAmountBelowMinimum);
+}
+
+// Failure: an exact-output stake needing less ORCA than the minimum is rejected
+#[test]
+fn test_stake_for_exact_xorca_below_minimum_fails() {
+    let mut env = setup_env();
+    let res = stake_orca_for_exact_xorca(&mut env, 1_000, 1_000_000_000);
+    assert_program_error!(res, XorcaStakingProgramError::StakeAmountBelowMinimum);
+}
+
+// Happy path: an unstake of exactly the minimum succeeds
+#[test]
+fn test_unstake_at_minimum_succeeds() {
+    let mut env = setup_env();
+    assert!(do_unstake(&mut env, 0, MIN_UNSTAKE_XORCA).is_ok());
+}
+
+// Failure: a dust unstake is rejected before a pending withdraw is created
+#[test]
+fn test_unstake_below_minimum_fails() {
+    let mut env = setup_env();
+    let res = do_unstake(&mut env, 0, MIN_UNSTAKE_XORCA - 1);
+    assert_program_error!(res, XorcaStakingProgramError::UnstakeAmountBelowMinimum);
+    let pending_withdraw_account = find_pending_withdraw_pda(&env.staker, &0).unwrap().0;
+    assert!(env.ctx.get_raw_account(pending_withdraw_account).is_err());
+}
+
+// Failure: an exact-output unstake burning less xORCA than the minimum is rejected
+#[test]
+fn test_unstake_for_exact_orca_below_minimum_fails() {
+    let mut env = setup_env();
+    let res = do_unstake_for_exact_orca(&mut env, 0, 1_000, 1_000_000_000);
+    assert_program_error!(res, XorcaStakingProgramError::UnstakeAmountBelowMinimum);
+}
+
+// Happy path: an instant unstake of exactly the minimum succeeds
+#[test]
+fn test_instant_unstake_at_minimum_succeeds() {
+    let mut env = setup_env();
+    assert!(do_instant_unstake(&mut env, MIN_UNSTAKE_XORCA).is_ok());
+}
+
+// Failure: the minimum also applies to instant unstakes
+#[test]
+fn test_instant_unstake_below_minimum_fails() {
+    let mut env = setup_env();
+    let res = do_instant_unstake(&mut env, MIN_UNSTAKE_XORCA - 1);
+    assert_program_error!(res, XorcaStakingProgramError::UnstakeAmountBelowMinimum);
+}
@@ -1,9 +1,31 @@
+mod apply_pending_config;
 mod bump_edge_cases;
+mod cancel_unstake;
+mod cluster;
+mod crank_withdraw;
+mod deposit_caps;
+mod deposit_rewards;
 mod dos_protection;
+mod exact_output;
+mod harvest;
 mod initialize;
+mod instant_unstake;
+mod merge_pending_withdraws;
+mod migrate;
+mod minimum_amounts;
+mod pause;
 mod set;
+mod slippage;
 mod stake;
+mod stake_for;
+mod staker_account;
+mod transfer_pending_withdraw;
 mod unstake;
+mod unstake_cpi;
+mod update_authority;
 mod vault_inflation;
 mod withdraw;
+mod withdraw_many;
+mod withdraw_partial;
+mod withdraw_to;
 mod yield_operations;
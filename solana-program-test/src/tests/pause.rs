@@ -0,0 +1,144 @@
+use crate::utils::assert::decode_events_from_result;
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{
+    advance_clock_env, do_cancel_unstake, do_instant_unstake, do_unstake, do_withdraw,
+    do_withdraw_with_unique, stake_orca, unstake_and_advance,
+};
+use crate::{assert_program_error, TestContext, XORCA_PROGRAM_ID};
+use solana_sdk::pubkey::Pubkey;
+use xorca::{
+    find_state_address, Event, Set, SetInstructionArgs, State, StateUpdateInstruction,
+    TokenAccount, XorcaStakingProgramError, PAUSE_INSTANT_UNSTAKE, PAUSE_STAKE, PAUSE_UNSTAKE,
+    PAUSE_WITHDRAW,
+};
+
+fn seed_state_with_authority(ctx: &mut TestContext, update_authority: Pubkey) -> Pubkey {
+    let (state, state_bump) = find_state_address().unwrap();
+    ctx.write_account(
+        state,
+        XORCA_PROGRAM_ID,
+        crate::state_data!(
+            escrowed_orca_amount => 0,
+            update_authority => update_authority,
+            cool_down_period_s => 10,
+            bump => state_bump,
+        ),
+    )
+    .unwrap();
+    state
+}
+
+// Happy path: update authority sets the pause flags and an event records the change
+#[test]
+fn test_set_pause_flags_updates_state_and_emits_event() {
+    let mut ctx = TestContext::new();
+    let signer = ctx.signer();
+    let state = seed_state_with_authority(&mut ctx, signer);
+    let ix = Set {
+        update_authority_account: ctx.signer(),
+        state_account: state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::SetPauseFlags {
+            new_pause_flags: PAUSE_STAKE | PAUSE_WITHDRAW,
+        },
+    });
+    let res = ctx.sends(&[ix]);
+    assert!(res.is_ok());
+    let state_account = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(state_account.data.pause_flags, PAUSE_STAKE | PAUSE_WITHDRAW);
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::PauseFlagsSet {
+            new_pause_flags,
+            old_pause_flags,
+            set_by,
+        } => {
+            *new_pause_flags == PAUSE_STAKE | PAUSE_WITHDRAW
+                && *old_pause_flags == 0
+                && *set_by == ctx.signer()
+        }
+        _ => false,
+    });
+    assert!(found, "PauseFlagsSet event should be emitted");
+}
+
+// Failure: only the update authority can change the pause flags
+#[test]
+fn test_set_pause_flags_fails_with_wrong_update_authority() {
+    let mut ctx = TestContext::new();
+    let state = seed_state_with_authority(&mut ctx, Pubkey::new_unique());
+    let ix = Set {
+        update_authority_account: ctx.signer(),
+        state_account: state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::SetPauseFlags {
+            new_pause_flags: u16::MAX,
+        },
+    });
+    let res = ctx.sends(&[ix]);
+    assert_program_error!(res, XorcaStakingProgramError::IncorrectAccountAddress);
+    let state_account = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(state_account.data.pause_flags, 0);
+}
+
+// Failure: stake is rejected while paused, unstake keeps working
+#[test]
+fn test_stake_fails_while_paused() {
+    let mut env = Env::setup(&PoolSetup::funded(), 1_000_000, 1_000_000);
+    env.update_state(|state| state.pause_flags = PAUSE_STAKE);
+    let res = stake_orca(&mut env, 1_000_000);
+    assert_program_error!(res, XorcaStakingProgramError::ProgramPaused);
+    assert!(do_unstake(&mut env, 0, 1_000_000).is_ok());
+}
+
+// Failure: unstake is rejected while paused, stake keeps working
+#[test]
+fn test_unstake_fails_while_paused() {
+    let mut env = Env::setup(&PoolSetup::funded(), 1_000_000, 1_000_000);
+    env.update_state(|state| state.pause_flags = PAUSE_UNSTAKE);
+    let res = do_unstake(&mut env, 0, 1_000_000);
+    assert_program_error!(res, XorcaStakingProgramError::ProgramPaused);
+    assert!(stake_orca(&mut env, 1_000_000).is_ok());
+}
+
+// Failure: withdraw is rejected while paused and succeeds once unpaused
+#[test]
+fn test_withdraw_fails_while_paused_and_resumes_after_unpause() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    let pending_withdraw_account = unstake_and_advance(&mut env, 0, 1_000_000, 0);
+    advance_clock_env(&mut env, 60);
+    env.update_state(|state| state.pause_flags = PAUSE_WITHDRAW);
+    let res = do_withdraw(&mut env, pending_withdraw_account, 0);
+    assert_program_error!(res, XorcaStakingProgramError::ProgramPaused);
+
+    env.update_state(|state| state.pause_flags = 0);
+    let res = do_withdraw_with_unique(&mut env, pending_withdraw_account, 0, 1);
+    assert!(res.is_ok());
+    let user_orca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_orca_ata)
+        .unwrap();
+    assert!(user_orca.data.amount > 0);
+}
+
+// Failure: instant unstake is rejected while paused
+#[test]
+fn test_instant_unstake_fails_while_paused() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    env.update_state(|state| state.pause_flags = PAUSE_INSTANT_UNSTAKE);
+    let res = do_instant_unstake(&mut env, 1_000_000);
+    assert_program_error!(res, XorcaStakingProgramError::ProgramPaused);
+}
+
+// Failure: cancelling an unstake stakes it again, so it is rejected while staking is paused
+#[test]
+fn test_cancel_unstake_fails_while_stake_paused() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    assert!(do_unstake(&mut env, 0, 1_000_000).is_ok());
+    env.update_state(|state| state.pause_flags = PAUSE_STAKE);
+    let res = do_cancel_unstake(&mut env, 0);
+    assert_program_error!(res, XorcaStakingProgramError::ProgramPaused);
+}
@@ -1,12 +1,15 @@
+use crate::utils::assert::decode_events_from_result;
 use crate::{assert_program_error, TestContext};
-use solana_sdk::signature::Signer;
+use solana_sdk::{clock::Clock, signature::Signer};
 use xorca::{
-    find_state_address, Set, SetInstructionArgs, State, StateUpdateInstruction,
+    find_state_address, Event, Set, SetInstructionArgs, State, StateUpdateInstruction,
     XorcaStakingProgramError,
 };
+use xorca_staking_program::state::state::{CONFIG_TIMELOCK_S, MAX_COOL_DOWN_PERIOD_S};
 
+// Success: a cooldown update is queued behind the timelock instead of applied
 #[test]
-fn set_updates_cooldown() {
+fn set_queues_cooldown() {
     let mut ctx = TestContext::new();
     let (state, state_bump) = find_state_address().unwrap();
     // Seed state with update authority as signer
@@ -32,40 +35,14 @@ fn set_updates_cooldown() {
         },
     });
     assert!(ctx.sends(&[ix]).is_ok());
+    let now = ctx.get_sysvar::<Clock>().unix_timestamp;
     let state_account = ctx.get_account::<State>(state).unwrap();
-    assert_eq!(state_account.data.cool_down_period_s, 500);
-}
-
-// Success: update the update authority to a new pubkey
-#[test]
-fn set_updates_update_authority() {
-    let mut ctx = TestContext::new();
-    let (state, state_bump) = find_state_address().unwrap();
-    // Seed state with current update authority as signer
-    ctx.write_account(
-        state,
-        xorca::XORCA_STAKING_PROGRAM_ID,
-        crate::state_data!(
-            escrowed_orca_amount => 0,
-            update_authority => ctx.signer(),
-            cool_down_period_s => 10,
-            bump => state_bump,
-        ),
-    )
-    .unwrap();
-    let new_auth = solana_sdk::pubkey::Pubkey::new_unique();
-    let ix = Set {
-        update_authority_account: ctx.signer(),
-        state_account: state,
-    }
-    .instruction(SetInstructionArgs {
-        instruction_data: StateUpdateInstruction::UpdateUpdateAuthority {
-            new_authority: new_auth,
-        },
-    });
-    assert!(ctx.sends(&[ix]).is_ok());
-    let state_account = ctx.get_account::<State>(state).unwrap();
-    assert_eq!(state_account.data.update_authority, new_auth);
+    assert_eq!(state_account.data.cool_down_period_s, 10);
+    assert_eq!(state_account.data.pending_cool_down_period_s, 500);
+    assert_eq!(
+        state_account.data.pending_config_activation_timestamp,
+        now + CONFIG_TIMELOCK_S
+    );
 }
 
 // Failure: wrong signer (not current update authority)
@@ -271,9 +248,9 @@ fn set_cooldown_idempotent_noop_succeeds() {
     assert_eq!(state_account.data.cool_down_period_s, 777);
 }
 
-// Idempotent: updating authority to the same pubkey succeeds and leaves authority unchanged
+// Test queueing the maximum allowed cooldown succeeds
 #[test]
-fn set_update_authority_idempotent_noop_succeeds() {
+fn set_queues_cooldown_at_max_success() {
     let mut ctx = TestContext::new();
     let (state, state_bump) = find_state_address().unwrap();
     ctx.write_account(
@@ -282,28 +259,31 @@ fn set_update_authority_idempotent_noop_succeeds() {
         crate::state_data!(
             escrowed_orca_amount => 0,
             update_authority => ctx.signer(),
-            cool_down_period_s => 10,
+            cool_down_period_s => 0,
             bump => state_bump,
         ),
     )
     .unwrap();
-    let ix = Set {
+    let ix_max = Set {
         update_authority_account: ctx.signer(),
         state_account: state,
     }
     .instruction(SetInstructionArgs {
-        instruction_data: StateUpdateInstruction::UpdateUpdateAuthority {
-            new_authority: ctx.signer(),
+        instruction_data: StateUpdateInstruction::UpdateCoolDownPeriod {
+            new_cool_down_period_s: MAX_COOL_DOWN_PERIOD_S,
         },
     });
-    assert!(ctx.sends(&[ix]).is_ok());
-    let state_account = ctx.get_account::<State>(state).unwrap();
-    assert_eq!(state_account.data.update_authority, ctx.signer());
+    assert!(ctx.sends(&[ix_max]).is_ok());
+    let st_max = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(
+        st_max.data.pending_cool_down_period_s,
+        MAX_COOL_DOWN_PERIOD_S
+    );
 }
 
-// Test setting cooldown to large positive value (i64::MAX)
+// Test setting cooldown above the maximum fails (i64::MAX would overflow unstake timestamps)
 #[test]
-fn set_updates_cooldown_to_max_success() {
+fn set_fails_on_cooldown_above_max() {
     let mut ctx = TestContext::new();
     let (state, state_bump) = find_state_address().unwrap();
     ctx.write_account(
@@ -317,21 +297,29 @@ fn set_updates_cooldown_to_max_success() {
         ),
     )
     .unwrap();
-    let ix_max = Set {
-        update_authority_account: ctx.signer(),
-        state_account: state,
+    for (unique, new_cool_down_period_s) in [MAX_COOL_DOWN_PERIOD_S + 1, i64::MAX]
+        .into_iter()
+        .enumerate()
+    {
+        let ix = Set {
+            update_authority_account: ctx.signer(),
+            state_account: state,
+        }
+        .instruction(SetInstructionArgs {
+            instruction_data: StateUpdateInstruction::UpdateCoolDownPeriod {
+                new_cool_down_period_s,
+            },
+        });
+        let noop_ix =
+            solana_sdk::system_instruction::transfer(&ctx.signer(), &ctx.signer(), unique as u64);
+        let res = ctx.sends(&[ix, noop_ix]);
+        assert_program_error!(res, XorcaStakingProgramError::InvalidCoolDownPeriod);
     }
-    .instruction(SetInstructionArgs {
-        instruction_data: StateUpdateInstruction::UpdateCoolDownPeriod {
-            new_cool_down_period_s: i64::MAX,
-        },
-    });
-    assert!(ctx.sends(&[ix_max]).is_ok());
-    let st_max = ctx.get_account::<State>(state).unwrap();
-    assert_eq!(st_max.data.cool_down_period_s, i64::MAX);
+    let st = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(st.data.pending_config_activation_timestamp, 0);
 }
 
-// Test setting cooldown to zero succeeds
+// Test queueing a zero cooldown succeeds
 #[test]
 fn set_updates_cooldown_to_zero_success() {
     let mut ctx = TestContext::new();
@@ -358,7 +346,9 @@ fn set_updates_cooldown_to_zero_success() {
     });
     assert!(ctx.sends(&[ix_zero]).is_ok());
     let st_zero = ctx.get_account::<State>(state).unwrap();
-    assert_eq!(st_zero.data.cool_down_period_s, 0);
+    assert_eq!(st_zero.data.cool_down_period_s, 1);
+    assert_eq!(st_zero.data.pending_cool_down_period_s, 0);
+    assert_ne!(st_zero.data.pending_config_activation_timestamp, 0);
 }
 
 // Test setting negative cooldown fails
@@ -458,7 +448,392 @@ fn set_succeeds_with_correct_bump_in_state_data() {
     let res = ctx.sends(&[ix]);
     assert!(res.is_ok());
 
-    // Verify the update was applied
+    // Verify the update was queued
+    let state_account = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(state_account.data.pending_cool_down_period_s, 500);
+}
+
+// Success: update the instant unstake fee
+#[test]
+fn set_updates_instant_unstake_fee() {
+    let mut ctx = TestContext::new();
+    let (state, state_bump) = find_state_address().unwrap();
+    ctx.write_account(
+        state,
+        xorca::XORCA_STAKING_PROGRAM_ID,
+        crate::state_data!(
+            escrowed_orca_amount => 0,
+            update_authority => ctx.signer(),
+            cool_down_period_s => 10,
+            bump => state_bump,
+        ),
+    )
+    .unwrap();
+    let ix = Set {
+        update_authority_account: ctx.signer(),
+        state_account: state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::UpdateInstantUnstakeFee {
+            new_instant_unstake_fee_bps: 10_000,
+        },
+    });
+    let res = ctx.sends(&[ix]);
+    assert!(res.is_ok());
+    let state_account = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(state_account.data.instant_unstake_fee_bps, 10_000);
+    assert_eq!(state_account.data.cool_down_period_s, 10);
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::InstantUnstakeFeeSet {
+            new_fee_bps,
+            old_fee_bps,
+            set_by,
+        } => *new_fee_bps == 10_000 && *old_fee_bps == 0 && *set_by == ctx.signer(),
+        _ => false,
+    });
+    assert!(found, "InstantUnstakeFeeSet event should be emitted");
+}
+
+// Failure: instant unstake fee above 10000 bps
+#[test]
+fn set_fails_on_instant_unstake_fee_above_max() {
+    let mut ctx = TestContext::new();
+    let (state, state_bump) = find_state_address().unwrap();
+    ctx.write_account(
+        state,
+        xorca::XORCA_STAKING_PROGRAM_ID,
+        crate::state_data!(
+            escrowed_orca_amount => 0,
+            update_authority => ctx.signer(),
+            cool_down_period_s => 10,
+            bump => state_bump,
+        ),
+    )
+    .unwrap();
+    let ix = Set {
+        update_authority_account: ctx.signer(),
+        state_account: state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::UpdateInstantUnstakeFee {
+            new_instant_unstake_fee_bps: 10_001,
+        },
+    });
+    let res = ctx.sends(&[ix]);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidInstantUnstakeFee);
     let state_account = ctx.get_account::<State>(state).unwrap();
-    assert_eq!(state_account.data.cool_down_period_s, 500);
+    assert_eq!(state_account.data.instant_unstake_fee_bps, 0);
+}
+
+// Success: update the rewards authority to a new pubkey
+#[test]
+fn set_updates_rewards_authority() {
+    let mut ctx = TestContext::new();
+    let (state, state_bump) = find_state_address().unwrap();
+    ctx.write_account(
+        state,
+        xorca::XORCA_STAKING_PROGRAM_ID,
+        crate::state_data!(
+            escrowed_orca_amount => 0,
+            update_authority => ctx.signer(),
+            cool_down_period_s => 10,
+            bump => state_bump,
+        ),
+    )
+    .unwrap();
+    let new_rewards_authority = solana_sdk::pubkey::Pubkey::new_unique();
+    let ix = Set {
+        update_authority_account: ctx.signer(),
+        state_account: state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::UpdateRewardsAuthority {
+            new_rewards_authority,
+        },
+    });
+    assert!(ctx.sends(&[ix]).is_ok());
+    let state_account = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(state_account.data.rewards_authority, new_rewards_authority);
+    assert_eq!(state_account.data.update_authority, ctx.signer());
+}
+
+// Success: update the performance fee and treasury
+#[test]
+fn set_updates_performance_fee_and_treasury() {
+    let mut ctx = TestContext::new();
+    let (state, state_bump) = find_state_address().unwrap();
+    ctx.write_account(
+        state,
+        xorca::XORCA_STAKING_PROGRAM_ID,
+        crate::state_data!(
+            escrowed_orca_amount => 0,
+            update_authority => ctx.signer(),
+            cool_down_period_s => 10,
+            bump => state_bump,
+        ),
+    )
+    .unwrap();
+    let new_treasury = solana_sdk::pubkey::Pubkey::new_unique();
+    let fee_ix = Set {
+        update_authority_account: ctx.signer(),
+        state_account: state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::UpdatePerformanceFee {
+            new_performance_fee_bps: 1_500,
+        },
+    });
+    let treasury_ix = Set {
+        update_authority_account: ctx.signer(),
+        state_account: state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::UpdateTreasury { new_treasury },
+    });
+    let res = ctx.sends(&[fee_ix, treasury_ix]);
+    assert!(res.is_ok());
+    let state_account = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(state_account.data.performance_fee_bps, 1_500);
+    assert_eq!(state_account.data.treasury, new_treasury);
+
+    let events = decode_events_from_result(&res);
+    let fee_set = events.iter().any(|event| match event {
+        Event::PerformanceFeeSet {
+            new_fee_bps,
+            old_fee_bps,
+            set_by,
+        } => *new_fee_bps == 1_500 && *old_fee_bps == 0 && *set_by == ctx.signer(),
+        _ => false,
+    });
+    assert!(fee_set, "PerformanceFeeSet event should be emitted");
+    let treasury_set = events.iter().any(|event| match event {
+        Event::TreasurySet {
+            new_treasury: event_new_treasury,
+            old_treasury,
+            set_by,
+        } => {
+            *event_new_treasury == new_treasury
+                && *old_treasury == solana_sdk::pubkey::Pubkey::default()
+                && *set_by == ctx.signer()
+        }
+        _ => false,
+    });
+    assert!(treasury_set, "TreasurySet event should be emitted");
+}
+
+// Failure: performance fee above 10000 bps
+#[test]
+fn set_fails_on_performance_fee_above_max() {
+    let mut ctx = TestContext::new();
+    let (state, state_bump) = find_state_address().unwrap();
+    ctx.write_account(
+        state,
+        xorca::XORCA_STAKING_PROGRAM_ID,
+        crate::state_data!(
+            escrowed_orca_amount => 0,
+            update_authority => ctx.signer(),
+            cool_down_period_s => 10,
+            bump => state_bump,
+        ),
+    )
+    .unwrap();
+    let ix = Set {
+        update_authority_account: ctx.signer(),
+        state_account: state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::UpdatePerformanceFee {
+            new_performance_fee_bps: 10_001,
+        },
+    });
+    let res = ctx.sends(&[ix]);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidPerformanceFee);
+    let state_account = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(state_account.data.performance_fee_bps, 0);
+}
+
+// Success: update the deposit caps
+#[test]
+fn set_updates_deposit_caps() {
+    let mut ctx = TestContext::new();
+    let (state, state_bump) = find_state_address().unwrap();
+    ctx.write_account(
+        state,
+        xorca::XORCA_STAKING_PROGRAM_ID,
+        crate::state_data!(
+            escrowed_orca_amount => 0,
+            update_authority => ctx.signer(),
+            cool_down_period_s => 10,
+            bump => state_bump,
+        ),
+    )
+    .unwrap();
+    let vault_cap_ix = Set {
+        update_authority_account: ctx.signer(),
+        state_account: state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::UpdateMaxVaultOrca {
+            new_max_vault_orca: 1_000_000_000_000,
+        },
+    });
+    let per_tx_cap_ix = Set {
+        update_authority_account: ctx.signer(),
+        state_account: state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::UpdateMaxStakePerTx {
+            new_max_stake_per_tx: 10_000_000_000,
+        },
+    });
+    let res = ctx.sends(&[vault_cap_ix, per_tx_cap_ix]);
+    assert!(res.is_ok());
+    let state_account = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(state_account.data.max_vault_orca, 1_000_000_000_000);
+    assert_eq!(state_account.data.max_stake_per_tx, 10_000_000_000);
+
+    let events = decode_events_from_result(&res);
+    let vault_cap_set = events.iter().any(|event| match event {
+        Event::MaxVaultOrcaSet {
+            new_max_vault_orca,
+            old_max_vault_orca,
+            set_by,
+        } => {
+            *new_max_vault_orca == 1_000_000_000_000
+                && *old_max_vault_orca == 0
+                && *set_by == ctx.signer()
+        }
+        _ => false,
+    });
+    assert!(vault_cap_set, "MaxVaultOrcaSet event should be emitted");
+    let per_tx_cap_set = events.iter().any(|event| match event {
+        Event::MaxStakePerTxSet {
+            new_max_stake_per_tx,
+            old_max_stake_per_tx,
+            set_by,
+        } => {
+            *new_max_stake_per_tx == 10_000_000_000
+                && *old_max_stake_per_tx == 0
+                && *set_by == ctx.signer()
+        }
+        _ => false,
+    });
+    assert!(per_tx_cap_set, "MaxStakePerTxSet event should be emitted");
+}
+
+// Failure: the per-transaction cap cannot exceed a set vault cap, whichever is updated
+#[test]
+fn set_fails_on_per_tx_cap_above_vault_cap() {
+    let mut ctx = TestContext::new();
+    let (state, state_bump) = find_state_address().unwrap();
+    ctx.write_account(
+        state,
+        xorca::XORCA_STAKING_PROGRAM_ID,
+        crate::state_data!(
+            escrowed_orca_amount => 0,
+            update_authority => ctx.signer(),
+            cool_down_period_s => 10,
+            bump => state_bump,
+            max_vault_orca => 1_000_000_000,
+            max_stake_per_tx => 500_000_000,
+        ),
+    )
+    .unwrap();
+    let per_tx_cap_ix = Set {
+        update_authority_account: ctx.signer(),
+        state_account: state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::UpdateMaxStakePerTx {
+            new_max_stake_per_tx: 1_000_000_001,
+        },
+    });
+    let res = ctx.sends(&[per_tx_cap_ix]);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidStakeCaps);
+    let vault_cap_ix = Set {
+        update_authority_account: ctx.signer(),
+        state_account: state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::UpdateMaxVaultOrca {
+            new_max_vault_orca: 499_999_999,
+        },
+    });
+    let res = ctx.sends(&[vault_cap_ix]);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidStakeCaps);
+    let state_account = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(state_account.data.max_vault_orca, 1_000_000_000);
+    assert_eq!(state_account.data.max_stake_per_tx, 500_000_000);
+}
+
+// Success: update the minimum stake and unstake amounts
+#[test]
+fn set_updates_minimum_amounts() {
+    let mut ctx = TestContext::new();
+    let (state, state_bump) = find_state_address().unwrap();
+    ctx.write_account(
+        state,
+        xorca::XORCA_STAKING_PROGRAM_ID,
+        crate::state_data!(
+            escrowed_orca_amount => 0,
+            update_authority => ctx.signer(),
+            cool_down_period_s => 10,
+            bump => state_bump,
+        ),
+    )
+    .unwrap();
+    let min_stake_ix = Set {
+        update_authority_account: ctx.signer(),
+        state_account: state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::UpdateMinStakeOrca {
+            new_min_stake_orca: 1_000_000,
+        },
+    });
+    let min_unstake_ix = Set {
+        update_authority_account: ctx.signer(),
+        state_account: state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::UpdateMinUnstakeXorca {
+            new_min_unstake_xorca: 2_000_000,
+        },
+    });
+    let res = ctx.sends(&[min_stake_ix, min_unstake_ix]);
+    assert!(res.is_ok());
+    let state_account = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(state_account.data.min_stake_orca, 1_000_000);
+    assert_eq!(state_account.data.min_unstake_xorca, 2_000_000);
+
+    let events = decode_events_from_result(&res);
+    let min_stake_set = events.iter().any(|event| match event {
+        Event::MinStakeOrcaSet {
+            new_min_stake_orca,
+            old_min_stake_orca,
+            set_by,
+        } => {
+            *new_min_stake_orca == 1_000_000 && *old_min_stake_orca == 0 && *set_by == ctx.signer()
+        }
+        _ => false,
+    });
+    assert!(min_stake_set, "MinStakeOrcaSet event should be emitted");
+    let min_unstake_set = events.iter().any(|event| match event {
+        Event::MinUnstakeXorcaSet {
+            new_min_unstake_xorca,
+            old_min_unstake_xorca,
+            set_by,
+        } => {
+            *new_min_unstake_xorca == 2_000_000
+                && *old_min_unstake_xorca == 0
+                && *set_by == ctx.signer()
+        }
+        _ => false,
+    });
+    assert!(
+        min_unstake_set,
+        "MinUnstakeXorcaSet event should be emitted"
+    );
 }
@@ -0,0 +1,81 @@
+use crate::assert_program_error;
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{do_unstake_with_slippage, stake_orca_with_slippage};
+use solana_sdk::clock::Clock;
+use xorca::{find_pending_withdraw_pda, PendingWithdraw, TokenAccount, XorcaStakingProgramError};
+
+const POOL: PoolSetup = PoolSetup {
+    vault_orca: 13_000_000_000,
+    ..PoolSetup::funded()
+};
+
+fn current_unix_timestamp(env: &Env) -> i64 {
+    env.ctx.get_sysvar::<Clock>().unix_timestamp
+}
+
+// Happy path: stake succeeds when the minimum output is exactly met
+#[test]
+fn test_stake_with_slippage_succeeds_at_minimum() {
+    let mut env = Env::setup(&POOL, 1_000_000_000, 0);
+    let min_xorca_out = env.expected_xorca_out(1_000_000_000);
+    let deadline = current_unix_timestamp(&env);
+    let res = stake_orca_with_slippage(&mut env, 1_000_000_000, min_xorca_out, Some(deadline));
+    assert!(res.is_ok());
+    let user_xorca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_xorca_ata)
+        .unwrap();
+    assert_eq!(user_xorca.data.amount, min_xorca_out);
+}
+
+// Failure: stake output below the requested minimum
+#[test]
+fn test_stake_with_slippage_fails_below_minimum() {
+    let mut env = Env::setup(&POOL, 1_000_000_000, 0);
+    let min_xorca_out = env.expected_xorca_out(1_000_000_000) + 1;
+    let res = stake_orca_with_slippage(&mut env, 1_000_000_000, min_xorca_out, None);
+    assert_program_error!(res, XorcaStakingProgramError::SlippageExceeded);
+}
+
+// Failure: stake submitted after its deadline
+#[test]
+fn test_stake_with_slippage_fails_after_deadline() {
+    let mut env = Env::setup(&POOL, 1_000_000_000, 0);
+    let deadline = current_unix_timestamp(&env) - 1;
+    let res = stake_orca_with_slippage(&mut env, 1_000_000_000, 0, Some(deadline));
+    assert_program_error!(res, XorcaStakingProgramError::DeadlineExceeded);
+}
+
+// Happy path: unstake succeeds when the minimum output is exactly met
+#[test]
+fn test_unstake_with_slippage_succeeds_at_minimum() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000_000);
+    let min_orca_out = env.expected_orca_out(1_000_000_000);
+    let deadline = current_unix_timestamp(&env) + 60;
+    let res = do_unstake_with_slippage(&mut env, 0, 1_000_000_000, min_orca_out, Some(deadline));
+    assert!(res.is_ok());
+    let pending_withdraw_account = find_pending_withdraw_pda(&env.staker, &0).unwrap().0;
+    let pending = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap();
+    assert_eq!(pending.data.withdrawable_orca_amount, min_orca_out);
+}
+
+// Failure: unstake output below the requested minimum
+#[test]
+fn test_unstake_with_slippage_fails_below_minimum() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000_000);
+    let min_orca_out = env.expected_orca_out(1_000_000_000) + 1;
+    let res = do_unstake_with_slippage(&mut env, 0, 1_000_000_000, min_orca_out, None);
+    assert_program_error!(res, XorcaStakingProgramError::SlippageExceeded);
+}
+
+// Failure: unstake submitted after its deadline
+#[test]
+fn test_unstake_with_slippage_fails_after_deadline() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000_000);
+    let deadline = current_unix_timestamp(&env) - 1;
+    let res = do_unstake_with_slippage(&mut env, 0, 1_000_000_000, 0, Some(deadline));
+    assert_program_error!(res, XorcaStakingProgramError::DeadlineExceeded);
+}
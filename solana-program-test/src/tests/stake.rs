@@ -1513,9 +1513,13 @@ fn stake_event_emission_verification() {
             vault_escrowed_orca_amount,
             xorca_mint_supply,
             xorca_to_mint,
+            payer,
+            beneficiary,
         } = e
         {
             assert_eq!(orca_stake_amount, 1_000_000);
+            assert_eq!(payer, env.staker);
+            assert_eq!(beneficiary, env.staker);
             assert_eq!(xorca_to_mint, 1_000_000);
             assert_eq!(vault_escrowed_orca_amount, 0);
             assert_eq!(vault_orca_amount, 1_000_000);
@@ -0,0 +1,102 @@
+use crate::utils::assert::decode_events_from_result;
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::stake_orca_for;
+use crate::{assert_program_error, ORCA_ID, TOKEN_PROGRAM_ID, XORCA_ID};
+use solana_sdk::pubkey::Pubkey;
+use xorca::{Event, TokenAccount, XorcaStakingProgramError};
+
+const POOL: PoolSetup = PoolSetup {
+    vault_orca: 13_000_000_000,
+    ..PoolSetup::funded()
+};
+
+fn create_token_account(env: &mut Env, mint: Pubkey, owner: Pubkey) -> Pubkey {
+    let token_account = Pubkey::new_unique();
+    env.ctx
+        .write_account(
+            token_account,
+            TOKEN_PROGRAM_ID,
+            crate::token_account_data!(mint => mint, owner => owner, amount => 0),
+        )
+        .unwrap();
+    token_account
+}
+
+// Happy path: ORCA comes from the signer while the xORCA goes to the beneficiary
+#[test]
+fn test_stake_for_mints_to_beneficiary() {
+    let mut env = Env::setup(&POOL, 1_000_000_000, 0);
+    let beneficiary = Pubkey::new_unique();
+    let beneficiary_xorca_ata = create_token_account(&mut env, XORCA_ID, beneficiary);
+
+    let expected_xorca = env.expected_xorca_out(1_000_000_000);
+
+    let res = stake_orca_for(&mut env, beneficiary_xorca_ata, 1_000_000_000);
+    assert!(res.is_ok());
+
+    let staker_orca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_orca_ata)
+        .unwrap();
+    assert_eq!(staker_orca.data.amount, 0);
+    let staker_xorca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_xorca_ata)
+        .unwrap();
+    assert_eq!(staker_xorca.data.amount, 0);
+    let beneficiary_xorca = env
+        .ctx
+        .get_account::<TokenAccount>(beneficiary_xorca_ata)
+        .unwrap();
+    assert_eq!(beneficiary_xorca.data.amount, expected_xorca);
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::Stake {
+            xorca_to_mint,
+            payer,
+            beneficiary: event_beneficiary,
+            ..
+        } => {
+            *xorca_to_mint == expected_xorca
+                && *payer == env.staker
+                && *event_beneficiary == beneficiary
+        }
+        _ => false,
+    });
+    assert!(found, "Stake event should record payer and beneficiary");
+}
+
+// Happy path: staking for yourself through StakeFor records the staker twice
+#[test]
+fn test_stake_for_self() {
+    let mut env = Env::setup(&POOL, 1_000_000_000, 0);
+    let staker_xorca_ata = env.staker_xorca_ata;
+    let res = stake_orca_for(&mut env, staker_xorca_ata, 1_000_000_000);
+    assert!(res.is_ok());
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::Stake {
+            payer, beneficiary, ..
+        } => *payer == env.staker && *beneficiary == env.staker,
+        _ => false,
+    });
+    assert!(found, "Stake event should be emitted");
+}
+
+// Failure: the beneficiary account must hold xORCA
+#[test]
+fn test_stake_for_fails_with_non_xorca_account() {
+    let mut env = Env::setup(&POOL, 1_000_000_000, 0);
+    let beneficiary_orca_ata = create_token_account(&mut env, ORCA_ID, Pubkey::new_unique());
+    let res = stake_orca_for(&mut env, beneficiary_orca_ata, 1_000_000_000);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidAccountData);
+}
+
+// Failure: the beneficiary account must be an SPL token account
+#[test]
+fn test_stake_for_fails_with_non_token_account() {
+    let mut env = Env::setup(&POOL, 1_000_000_000, 0);
+    let res = stake_orca_for(&mut env, Pubkey::new_unique(), 1_000_000_000);
+    assert_program_error!(res, XorcaStakingProgramError::IncorrectOwner);
+}
@@ -0,0 +1,484 @@
+use crate::utils::assert::decode_events_from_result;
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{
+    advance_clock_env, do_initialize_staker_account, unstake_and_advance,
+    unstake_with_staker_account,
+};
+use crate::{
+    assert_program_error, TestContext, ORCA_ID, SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID, XORCA_ID,
+};
+use solana_sdk::{instruction::AccountMeta, instruction::Instruction, pubkey::Pubkey};
+use xorca::{
+    find_staker_account_pda, find_staker_pending_withdraw_pda, CancelUnstake,
+    CancelUnstakeInstructionArgs, Event, InitializeStakerAccount, MergePendingWithdraws,
+    MergePendingWithdrawsInstructionArgs, PendingWithdraw, StakerAccount, TokenAccount, Unstake,
+    UnstakeForExactOrca, UnstakeForExactOrcaInstructionArgs, UnstakeInstructionArgs, Withdraw,
+    WithdrawInstructionArgs, WithdrawMany, WithdrawPartial, WithdrawPartialInstructionArgs,
+    XorcaStakingProgramError,
+};
+
+const COOL_DOWN_PERIOD_S: i64 = 60;
+const XORCA_UNSTAKE_AMOUNT: u64 = 1_000_000;
+
+fn setup_env() -> Env {
+    let pool = PoolSetup {
+        cool_down_period_s: COOL_DOWN_PERIOD_S,
+        ..PoolSetup::funded()
+    };
+    let mut env = Env::setup(&pool, 0, 10_000_000);
+    assert!(do_initialize_staker_account(&mut env).is_ok());
+    env
+}
+
+fn staker_account(env: &Env) -> StakerAccount {
+    let staker_account_pda = find_staker_account_pda(&env.staker).unwrap().0;
+    env.ctx
+        .get_account::<StakerAccount>(staker_account_pda)
+        .unwrap()
+        .data
+}
+
+fn staker_account_meta(env: &Env) -> AccountMeta {
+    AccountMeta::new(find_staker_account_pda(&env.staker).unwrap().0, false)
+}
+
+fn withdraw_ix(env: &Env, pending_withdraw_account: Pubkey, withdraw_index: u8) -> Instruction {
+    Withdraw {
+        unstaker_account: env.staker,
+        state_account: env.state,
+        vault_account: env.vault,
+        pending_withdraw_account,
+        unstaker_orca_ata: env.staker_orca_ata,
+        orca_mint_account: ORCA_ID,
+        system_program_account: SYSTEM_PROGRAM_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+    }
+    .instruction(WithdrawInstructionArgs { withdraw_index })
+}
+
+fn with_staker_account(env: &Env, mut ix: Instruction) -> Instruction {
+    ix.accounts.push(staker_account_meta(env));
+    ix
+}
+
+// Happy path: the staker account starts empty and belongs to the staker
+#[test]
+fn test_initialize_staker_account() {
+    let env = setup_env();
+    let staker_account_pda = find_staker_account_pda(&env.staker).unwrap();
+    let data = staker_account(&env);
+    assert_eq!(data.staker, env.staker);
+    assert_eq!(data.bump, staker_account_pda.1);
+    assert_eq!(data.next_withdraw_index, 0);
+    assert_eq!(data.open_withdraw_count, 0);
+    assert_eq!(data.total_xorca_unstaked, 0);
+    assert_eq!(data.total_orca_unstaked, 0);
+    assert_eq!(data.total_orca_withdrawn, 0);
+}
+
+// Failure: a staker account can only be created once
+#[test]
+fn test_initialize_staker_account_twice_fails() {
+    let mut env = setup_env();
+    advance_clock_env(&mut env, 1);
+    assert!(do_initialize_staker_account(&mut env).is_err());
+}
+
+// Failure: the staker account must be derived from the signing staker
+#[test]
+fn test_initialize_staker_account_for_other_staker_fails() {
+    let mut env = setup_env();
+    let ix = InitializeStakerAccount {
+        staker_account: env.staker,
+        staker_account_pda: find_staker_account_pda(&Pubkey::new_unique()).unwrap().0,
+        system_program_account: SYSTEM_PROGRAM_ID,
+    }
+    .instruction();
+    let res = env.ctx.sends(&[ix]);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidSeeds);
+}
+
+// Happy path: consecutive unstakes take consecutive indices and update the totals
+#[test]
+fn test_unstake_with_staker_account_takes_next_index() {
+    let mut env = setup_env();
+    let (first_index, first, res) = unstake_with_staker_account(&mut env, XORCA_UNSTAKE_AMOUNT);
+    assert!(res.is_ok());
+    let (second_index, second, res) = unstake_with_staker_account(&mut env, XORCA_UNSTAKE_AMOUNT);
+    assert!(res.is_ok());
+    assert_eq!((first_index, second_index), (0, 1));
+
+    let mut withdrawable_orca_amount = 0;
+    for (index, address) in [(first_index, first), (second_index, second)] {
+        let pending_withdraw = env.ctx.get_account::<PendingWithdraw>(address).unwrap();
+        assert_eq!(pending_withdraw.data.staker_indexed, 1);
+        assert_eq!(pending_withdraw.data.staker_withdraw_index, index);
+        assert_eq!(pending_withdraw.data.withdraw_index, index as u8);
+        assert_eq!(pending_withdraw.data.unstaker, env.staker);
+        withdrawable_orca_amount += pending_withdraw.data.withdrawable_orca_amount;
+    }
+
+    let data = staker_account(&env);
+    assert_eq!(data.next_withdraw_index, 2);
+    assert_eq!(data.open_withdraw_count, 2);
+    assert_eq!(data.total_xorca_unstaked, 2 * XORCA_UNSTAKE_AMOUNT);
+    assert_eq!(data.total_orca_unstaked, withdrawable_orca_amount);
+}
+
+// Happy path: indices past 255 stay unique, with withdraw_index holding the lowest byte
+#[test]
+fn test_unstake_with_staker_account_past_one_byte_index() {
+    let mut env = setup_env();
+    let staker_account_pda = find_staker_account_pda(&env.staker).unwrap().0;
+    let mut data = staker_account(&env);
+    data.next_withdraw_index = 256;
+    env.ctx
+        .write_account(staker_account_pda, crate::XORCA_PROGRAM_ID, data)
+        .unwrap();
+
+    let (index, pending_withdraw_account, res) =
+        unstake_with_staker_account(&mut env, XORCA_UNSTAKE_AMOUNT);
+    assert!(res.is_ok());
+    assert_eq!(index, 256);
+    let pending_withdraw = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap();
+    assert_eq!(pending_withdraw.data.withdraw_index, 0);
+    assert_eq!(pending_withdraw.data.staker_withdraw_index, 256);
+    let events = decode_events_from_result(&res);
+    assert!(events.iter().any(|event| match event {
+        Event::Unstake {
+            withdraw_index,
+            staker_withdraw_index,
+            ..
+        } => *withdraw_index == 0 && *staker_withdraw_index == Some(256),
+        _ => false,
+    }));
+}
+
+// Happy path: UnstakeForExactOrca also takes the staker account's next index
+#[test]
+fn test_unstake_for_exact_orca_with_staker_account_takes_next_index() {
+    let mut env = setup_env();
+    let staker_account_pda = find_staker_account_pda(&env.staker).unwrap().0;
+    let mut data = staker_account(&env);
+    data.next_withdraw_index = 257;
+    env.ctx
+        .write_account(staker_account_pda, crate::XORCA_PROGRAM_ID, data)
+        .unwrap();
+
+    let pending_withdraw_account = find_staker_pending_withdraw_pda(&env.staker, &257)
+        .unwrap()
+        .0;
+    let orca_out = 1_000_000;
+    let ix = UnstakeForExactOrca {
+        unstaker_account: env.staker,
+        state_account: env.state,
+        vault_account: env.vault,
+        pending_withdraw_account,
+        unstaker_xorca_ata: env.staker_xorca_ata,
+        xorca_mint_account: XORCA_ID,
+        orca_mint_account: ORCA_ID,
+        system_program_account: SYSTEM_PROGRAM_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+        rent_payer_account: None,
+        staker_account_pda: Some(staker_account_pda),
+    }
+    .instruction(UnstakeForExactOrcaInstructionArgs {
+        orca_out,
+        max_xorca_in: u64::MAX,
+        withdraw_index: 1,
+    });
+    let res = env.ctx.sends(&[ix]);
+    assert!(res.is_ok());
+
+    let pending_withdraw = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap();
+    assert_eq!(pending_withdraw.data.staker_indexed, 1);
+    assert_eq!(pending_withdraw.data.staker_withdraw_index, 257);
+    assert_eq!(pending_withdraw.data.withdraw_index, 1);
+    assert_eq!(pending_withdraw.data.withdrawable_orca_amount, orca_out);
+    let data = staker_account(&env);
+    assert_eq!(data.next_withdraw_index, 258);
+    assert_eq!(data.open_withdraw_count, 1);
+    assert_eq!(data.total_orca_unstaked, orca_out);
+    let events = decode_events_from_result(&res);
+    assert!(events.iter().any(|event| match event {
+        Event::Unstake {
+            staker_withdraw_index,
+            ..
+        } => *staker_withdraw_index == Some(257),
+        _ => false,
+    }));
+}
+
+// Failure: withdraw_index must match the lowest byte of the staker account's next index
+#[test]
+fn test_unstake_with_staker_account_fails_with_mismatched_index() {
+    let mut env = setup_env();
+    let staker_account_pda = find_staker_account_pda(&env.staker).unwrap().0;
+    let ix = Unstake {
+        unstaker_account: env.staker,
+        state_account: env.state,
+        vault_account: env.vault,
+        pending_withdraw_account: find_staker_pending_withdraw_pda(&env.staker, &0).unwrap().0,
+        unstaker_xorca_ata: env.staker_xorca_ata,
+        xorca_mint_account: XORCA_ID,
+        orca_mint_account: ORCA_ID,
+        system_program_account: SYSTEM_PROGRAM_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+        rent_payer_account: None,
+        staker_account_pda: Some(staker_account_pda),
+    }
+    .instruction(UnstakeInstructionArgs {
+        xorca_unstake_amount: XORCA_UNSTAKE_AMOUNT,
+        withdraw_index: 1,
+    });
+    let res = env.ctx.sends(&[ix]);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidSeeds);
+}
+
+// Failure: another staker's staker account cannot index this staker's unstakes
+#[test]
+fn test_unstake_with_other_staker_account_fails() {
+    let mut env = setup_env();
+    let mut other = TestContext::new_signer(env.ctx.svm.clone());
+    let other_staker_account_pda = find_staker_account_pda(&other.signer()).unwrap().0;
+    let ix = InitializeStakerAccount {
+        staker_account: other.signer(),
+        staker_account_pda: other_staker_account_pda,
+        system_program_account: SYSTEM_PROGRAM_ID,
+    }
+    .instruction();
+    assert!(other.sends(&[ix]).is_ok());
+
+    let ix = Unstake {
+        unstaker_account: env.staker,
+        state_account: env.state,
+        vault_account: env.vault,
+        pending_withdraw_account: find_staker_pending_withdraw_pda(&env.staker, &0).unwrap().0,
+        unstaker_xorca_ata: env.staker_xorca_ata,
+        xorca_mint_account: XORCA_ID,
+        orca_mint_account: ORCA_ID,
+        system_program_account: SYSTEM_PROGRAM_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+        rent_payer_account: None,
+        staker_account_pda: Some(other_staker_account_pda),
+    }
+    .instruction(UnstakeInstructionArgs {
+        xorca_unstake_amount: XORCA_UNSTAKE_AMOUNT,
+        withdraw_index: 0,
+    });
+    advance_clock_env(&mut env, 1);
+    let res = env.ctx.sends(&[ix]);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidAccountData);
+}
+
+// Happy path: withdrawing a staker-indexed position closes it on the staker account
+#[test]
+fn test_withdraw_with_staker_account_records_settlement() {
+    let mut env = setup_env();
+    let (index, pending_withdraw_account, res) =
+        unstake_with_staker_account(&mut env, XORCA_UNSTAKE_AMOUNT);
+    assert!(res.is_ok());
+    let withdrawable_orca_amount = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap()
+        .data
+        .withdrawable_orca_amount;
+
+    advance_clock_env(&mut env, COOL_DOWN_PERIOD_S + 1);
+    let ix = with_staker_account(
+        &env,
+        withdraw_ix(&env, pending_withdraw_account, index as u8),
+    );
+    let res = env.ctx.sends(&[ix]);
+    assert!(res.is_ok());
+    let events = decode_events_from_result(&res);
+    assert!(events.iter().any(|event| match event {
+        Event::Withdraw {
+            staker_withdraw_index,
+            ..
+        } => *staker_withdraw_index == Some(index),
+        _ => false,
+    }));
+
+    assert!(env.ctx.get_raw_account(pending_withdraw_account).is_err());
+    let staker_orca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_orca_ata)
+        .unwrap();
+    assert_eq!(staker_orca.data.amount, withdrawable_orca_amount);
+    let data = staker_account(&env);
+    assert_eq!(data.open_withdraw_count, 0);
+    assert_eq!(data.total_orca_withdrawn, withdrawable_orca_amount);
+}
+
+// Failure: a staker-indexed position cannot close without its staker account
+#[test]
+fn test_withdraw_without_staker_account_fails() {
+    let mut env = setup_env();
+    let (index, pending_withdraw_account, res) =
+        unstake_with_staker_account(&mut env, XORCA_UNSTAKE_AMOUNT);
+    assert!(res.is_ok());
+    advance_clock_env(&mut env, COOL_DOWN_PERIOD_S + 1);
+    let ix = withdraw_ix(&env, pending_withdraw_account, index as u8);
+    let res = env.ctx.sends(&[ix]);
+    assert_program_error!(res, XorcaStakingProgramError::StakerAccountRequired);
+}
+
+// Happy path: a partial withdraw keeps the position open until it is drained
+#[test]
+fn test_withdraw_partial_with_staker_account() {
+    let mut env = setup_env();
+    let (index, pending_withdraw_account, res) =
+        unstake_with_staker_account(&mut env, XORCA_UNSTAKE_AMOUNT);
+    assert!(res.is_ok());
+    let withdrawable_orca_amount = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap()
+        .data
+        .withdrawable_orca_amount;
+    advance_clock_env(&mut env, COOL_DOWN_PERIOD_S + 1);
+
+    let partial_ix = |env: &Env, orca_withdraw_amount: u64| {
+        with_staker_account(
+            env,
+            WithdrawPartial {
+                unstaker_account: env.staker,
+                state_account: env.state,
+                vault_account: env.vault,
+                pending_withdraw_account,
+                unstaker_orca_ata: env.staker_orca_ata,
+                orca_mint_account: ORCA_ID,
+                system_program_account: SYSTEM_PROGRAM_ID,
+                token_program_account: TOKEN_PROGRAM_ID,
+            }
+            .instruction(WithdrawPartialInstructionArgs {
+                withdraw_index: index as u8,
+                orca_withdraw_amount,
+            }),
+        )
+    };
+    let first_amount = withdrawable_orca_amount / 2;
+    assert!(env.ctx.sends(&[partial_ix(&env, first_amount)]).is_ok());
+    let data = staker_account(&env);
+    assert_eq!(data.open_withdraw_count, 1);
+    assert_eq!(data.total_orca_withdrawn, first_amount);
+
+    let ix = partial_ix(&env, withdrawable_orca_amount - first_amount);
+    assert!(env.ctx.sends(&[ix]).is_ok());
+    let data = staker_account(&env);
+    assert_eq!(data.open_withdraw_count, 0);
+    assert_eq!(data.total_orca_withdrawn, withdrawable_orca_amount);
+}
+
+// Happy path: cancelling closes the position without counting it as withdrawn
+#[test]
+fn test_cancel_unstake_with_staker_account() {
+    let mut env = setup_env();
+    let (index, pending_withdraw_account, res) =
+        unstake_with_staker_account(&mut env, XORCA_UNSTAKE_AMOUNT);
+    assert!(res.is_ok());
+    let ix = with_staker_account(
+        &env,
+        CancelUnstake {
+            unstaker_account: env.staker,
+            state_account: env.state,
+            pending_withdraw_account,
+            unstaker_xorca_ata: env.staker_xorca_ata,
+            xorca_mint_account: XORCA_ID,
+            orca_mint_account: ORCA_ID,
+            vault_account: env.vault,
+            token_program_account: TOKEN_PROGRAM_ID,
+        }
+        .instruction(CancelUnstakeInstructionArgs {
+            withdraw_index: index as u8,
+        }),
+    );
+    assert!(env.ctx.sends(&[ix]).is_ok());
+
+    assert!(env.ctx.get_raw_account(pending_withdraw_account).is_err());
+    let data = staker_account(&env);
+    assert_eq!(data.open_withdraw_count, 0);
+    assert_eq!(data.total_orca_withdrawn, 0);
+}
+
+// Happy path: withdraw_many settles staker-indexed and one-byte indexed positions together
+#[test]
+fn test_withdraw_many_with_both_indexing_modes() {
+    let mut env = setup_env();
+    let legacy = unstake_and_advance(&mut env, 7, XORCA_UNSTAKE_AMOUNT, 0);
+    let (_, first, res) = unstake_with_staker_account(&mut env, XORCA_UNSTAKE_AMOUNT);
+    assert!(res.is_ok());
+    let (_, second, res) = unstake_with_staker_account(&mut env, XORCA_UNSTAKE_AMOUNT);
+    assert!(res.is_ok());
+    let staker_indexed_orca_amount: u64 = [first, second]
+        .iter()
+        .map(|address| {
+            env.ctx
+                .get_account::<PendingWithdraw>(*address)
+                .unwrap()
+                .data
+                .withdrawable_orca_amount
+        })
+        .sum();
+    advance_clock_env(&mut env, COOL_DOWN_PERIOD_S + 1);
+
+    let ix = WithdrawMany {
+        unstaker_account: env.staker,
+        state_account: env.state,
+        unstaker_orca_ata: env.staker_orca_ata,
+        vault_account: env.vault,
+        orca_mint_account: ORCA_ID,
+        system_program_account: SYSTEM_PROGRAM_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+    }
+    .instruction_with_remaining_accounts(&[
+        AccountMeta::new(legacy, false),
+        AccountMeta::new(first, false),
+        staker_account_meta(&env),
+        AccountMeta::new(second, false),
+    ]);
+    assert!(env.ctx.sends(&[ix]).is_ok());
+
+    for address in [legacy, first, second] {
+        assert!(env.ctx.get_raw_account(address).is_err());
+    }
+    let data = staker_account(&env);
+    assert_eq!(data.open_withdraw_count, 0);
+    assert_eq!(data.total_orca_withdrawn, staker_indexed_orca_amount);
+}
+
+// Happy path: merging closes staker-indexed sources on the staker account
+#[test]
+fn test_merge_pending_withdraws_with_staker_account() {
+    let mut env = setup_env();
+    let (target_index, target, res) = unstake_with_staker_account(&mut env, XORCA_UNSTAKE_AMOUNT);
+    assert!(res.is_ok());
+    let (_, source, res) = unstake_with_staker_account(&mut env, XORCA_UNSTAKE_AMOUNT);
+    assert!(res.is_ok());
+
+    let ix = MergePendingWithdraws {
+        unstaker_account: env.staker,
+        state_account: env.state,
+        pending_withdraw_account: target,
+    }
+    .instruction_with_remaining_accounts(
+        MergePendingWithdrawsInstructionArgs {
+            withdraw_index: target_index as u8,
+        },
+        &[AccountMeta::new(source, false), staker_account_meta(&env)],
+    );
+    assert!(env.ctx.sends(&[ix]).is_ok());
+
+    assert!(env.ctx.get_raw_account(source).is_err());
+    let data = staker_account(&env);
+    assert_eq!(data.open_withdraw_count, 1);
+    assert_eq!(data.next_withdraw_index, 2);
+}
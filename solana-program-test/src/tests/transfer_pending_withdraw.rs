@@ -0,0 +1,245 @@
+use crate::utils::assert::decode_events_from_result;
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{
+    advance_clock_env, do_cancel_unstake, do_transfer_pending_withdraw, do_withdraw,
+    unstake_and_advance,
+};
+use crate::{
+    assert_program_error, TestContext, ORCA_ID, SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID,
+    XORCA_PROGRAM_ID,
+};
+use solana_sdk::{
+    clock::Clock, instruction::AccountMeta, instruction::Instruction, pubkey::Pubkey,
+};
+use xorca::{
+    find_pending_withdraw_pda, pending_withdraw_owner, Event, PendingWithdraw, TokenAccount,
+    TransferPendingWithdraw, TransferPendingWithdrawInstructionArgs, Withdraw,
+    WithdrawInstructionArgs, WithdrawMany, XorcaStakingProgramError,
+};
+
+const COOL_DOWN_PERIOD_S: i64 = 60;
+const WITHDRAWABLE_ORCA_AMOUNT: u64 = 1_000_000;
+
+const POOL: PoolSetup = PoolSetup {
+    cool_down_period_s: COOL_DOWN_PERIOD_S,
+    ..PoolSetup::funded()
+};
+
+// A second wallet buying pending withdraws, with its own ORCA token account
+struct Buyer {
+    ctx: TestContext,
+    orca_account: Pubkey,
+}
+
+fn setup_buyer(env: &mut Env) -> Buyer {
+    let ctx = TestContext::new_signer(env.ctx.svm.clone());
+    let orca_account = Pubkey::new_unique();
+    env.ctx
+        .write_account(
+            orca_account,
+            TOKEN_PROGRAM_ID,
+            crate::token_account_data!(mint => ORCA_ID, owner => ctx.signer(), amount => 0),
+        )
+        .unwrap();
+    Buyer { ctx, orca_account }
+}
+
+fn buyer_withdraw_ix(
+    env: &Env,
+    buyer: &Buyer,
+    pending_withdraw_account: Pubkey,
+    withdraw_index: u8,
+) -> Instruction {
+    Withdraw {
+        unstaker_account: buyer.ctx.signer(),
+        state_account: env.state,
+        vault_account: env.vault,
+        pending_withdraw_account,
+        unstaker_orca_ata: buyer.orca_account,
+        orca_mint_account: ORCA_ID,
+        system_program_account: SYSTEM_PROGRAM_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+    }
+    .instruction(WithdrawInstructionArgs { withdraw_index })
+}
+
+// Seeds a matured pending withdraw for `unstaker`, held by `owner`
+fn seed_pending_withdraw(env: &mut Env, unstaker: Pubkey, owner: Pubkey) -> Pubkey {
+    let (pending_withdraw_account, bump) = find_pending_withdraw_pda(&unstaker, &0).unwrap();
+    let withdrawable_timestamp = env.ctx.get_sysvar::<Clock>().unix_timestamp;
+    env.ctx
+        .write_account(
+            pending_withdraw_account,
+            XORCA_PROGRAM_ID,
+            crate::pending_withdraw_data!(
+                unstaker => unstaker,
+                withdraw_index => 0,
+                withdrawable_orca_amount => WITHDRAWABLE_ORCA_AMOUNT,
+                withdrawable_timestamp => withdrawable_timestamp,
+                bump => bump,
+                owner => owner,
+            ),
+        )
+        .unwrap();
+
+    env.update_state(|state| state.escrowed_orca_amount += WITHDRAWABLE_ORCA_AMOUNT);
+    pending_withdraw_account
+}
+
+// Happy path: the new owner withdraws the matured position and receives the ORCA
+#[test]
+fn test_transfer_pending_withdraw_lets_new_owner_withdraw() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000);
+    let mut buyer = setup_buyer(&mut env);
+    let idx = 0u8;
+    let pending_withdraw_account = unstake_and_advance(&mut env, idx, 1_000_000, 0);
+
+    let new_owner = buyer.ctx.signer();
+    let res = do_transfer_pending_withdraw(&mut env, pending_withdraw_account, idx, new_owner);
+    assert!(res.is_ok());
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::PendingWithdrawTransferred {
+            unstaker,
+            withdraw_index,
+            previous_owner,
+            new_owner: transferred_to,
+        } => {
+            *unstaker == env.staker
+                && *withdraw_index == idx
+                && *previous_owner == env.staker
+                && *transferred_to == new_owner
+        }
+        _ => false,
+    });
+    assert!(found, "PendingWithdrawTransferred event should be emitted");
+
+    let pending_withdraw = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap();
+    assert_eq!(pending_withdraw.data.unstaker, env.staker);
+    assert_eq!(pending_withdraw_owner(&pending_withdraw.data), new_owner);
+    let withdrawable_orca_amount = pending_withdraw.data.withdrawable_orca_amount;
+
+    advance_clock_env(&mut env, COOL_DOWN_PERIOD_S + 1);
+    let ix = buyer_withdraw_ix(&env, &buyer, pending_withdraw_account, idx);
+    assert!(buyer.ctx.sends(&[ix]).is_ok());
+
+    let buyer_orca = env
+        .ctx
+        .get_account::<TokenAccount>(buyer.orca_account)
+        .unwrap();
+    assert_eq!(buyer_orca.data.amount, withdrawable_orca_amount);
+    assert!(env.ctx.get_raw_account(pending_withdraw_account).is_err());
+}
+
+// Failure: once transferred, the original unstaker can neither withdraw nor cancel
+#[test]
+fn test_transfer_pending_withdraw_locks_out_previous_owner() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000);
+    let buyer = setup_buyer(&mut env);
+    let idx = 1u8;
+    let pending_withdraw_account = unstake_and_advance(&mut env, idx, 1_000_000, 0);
+    let new_owner = buyer.ctx.signer();
+    assert!(
+        do_transfer_pending_withdraw(&mut env, pending_withdraw_account, idx, new_owner).is_ok()
+    );
+
+    let res = do_cancel_unstake(&mut env, idx);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidPendingWithdrawOwner);
+
+    advance_clock_env(&mut env, COOL_DOWN_PERIOD_S + 1);
+    let res = do_withdraw(&mut env, pending_withdraw_account, idx);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidPendingWithdrawOwner);
+}
+
+// Failure: only the current owner can transfer the position
+#[test]
+fn test_transfer_pending_withdraw_fails_for_non_owner() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000);
+    let mut buyer = setup_buyer(&mut env);
+    let idx = 2u8;
+    let pending_withdraw_account = unstake_and_advance(&mut env, idx, 1_000_000, 0);
+    let ix = TransferPendingWithdraw {
+        owner_account: buyer.ctx.signer(),
+        pending_withdraw_account,
+    }
+    .instruction(TransferPendingWithdrawInstructionArgs {
+        withdraw_index: idx,
+        new_owner: buyer.ctx.signer(),
+    });
+    let res = buyer.ctx.sends(&[ix]);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidPendingWithdrawOwner);
+}
+
+// Failure: the default key is not a valid new owner
+#[test]
+fn test_transfer_pending_withdraw_fails_with_default_owner() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000);
+    let idx = 3u8;
+    let pending_withdraw_account = unstake_and_advance(&mut env, idx, 1_000_000, 0);
+    let res =
+        do_transfer_pending_withdraw(&mut env, pending_withdraw_account, idx, Pubkey::default());
+    assert_program_error!(res, XorcaStakingProgramError::InvalidPendingWithdrawOwner);
+}
+
+// Failure: pending withdraw account does not match the withdraw index
+#[test]
+fn test_transfer_pending_withdraw_fails_with_mismatched_index() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000);
+    let idx = 4u8;
+    let pending_withdraw_account = unstake_and_advance(&mut env, idx, 1_000_000, 0);
+    let new_owner = Pubkey::new_unique();
+    let res = do_transfer_pending_withdraw(&mut env, pending_withdraw_account, idx + 1, new_owner);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidSeeds);
+}
+
+// Happy path: accounts created before ownership was stored still belong to the unstaker
+#[test]
+fn test_transfer_pending_withdraw_from_account_without_stored_owner() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000);
+    let staker = env.staker;
+    let pending_withdraw_account = seed_pending_withdraw(&mut env, staker, Pubkey::default());
+    let new_owner = Pubkey::new_unique();
+    let res = do_transfer_pending_withdraw(&mut env, pending_withdraw_account, 0, new_owner);
+    assert!(res.is_ok());
+    let pending_withdraw = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap();
+    assert_eq!(pending_withdraw.data.owner, new_owner);
+}
+
+// Happy path: positions bought from different unstakers with the same index withdraw together
+#[test]
+fn test_withdraw_many_with_positions_from_different_unstakers() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000);
+    let mut buyer = setup_buyer(&mut env);
+    let owner = buyer.ctx.signer();
+    let first = seed_pending_withdraw(&mut env, Pubkey::new_unique(), owner);
+    let second = seed_pending_withdraw(&mut env, Pubkey::new_unique(), owner);
+
+    let ix = WithdrawMany {
+        unstaker_account: owner,
+        state_account: env.state,
+        unstaker_orca_ata: buyer.orca_account,
+        vault_account: env.vault,
+        orca_mint_account: ORCA_ID,
+        system_program_account: SYSTEM_PROGRAM_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+    }
+    .instruction_with_remaining_accounts(&[
+        AccountMeta::new(first, false),
+        AccountMeta::new(second, false),
+    ]);
+    assert!(buyer.ctx.sends(&[ix]).is_ok());
+
+    let buyer_orca = env
+        .ctx
+        .get_account::<TokenAccount>(buyer.orca_account)
+        .unwrap();
+    assert_eq!(buyer_orca.data.amount, 2 * WITHDRAWABLE_ORCA_AMOUNT);
+    assert!(env.ctx.get_raw_account(first).is_err());
+    assert!(env.ctx.get_raw_account(second).is_err());
+}
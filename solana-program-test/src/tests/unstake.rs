@@ -7,7 +7,7 @@ use crate::utils::flows::{do_unstake, do_unstake_with_unique};
 use crate::{
     assert_program_error, TestContext, ORCA_ID, SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID, XORCA_ID,
 };
-use solana_sdk::{clock::Clock, pubkey::Pubkey};
+use solana_sdk::{clock::Clock, pubkey::Pubkey, rent::Rent};
 use xorca::{
     find_pending_withdraw_pda, find_state_address, Event, PendingWithdraw, State,
     XorcaStakingProgramError,
@@ -66,6 +66,39 @@ fn test_unstake_success_at_initial_rate() {
     );
 }
 
+// Happy path: new pending withdraws use the compact layout and only lock its rent
+#[test]
+fn test_unstake_creates_compact_pending_withdraw() {
+    let ctx = TestContext::new();
+    let pool = PoolSetup {
+        xorca_supply: 10_000_000_000,
+        vault_orca: 10_000_000_000,
+        escrowed_orca: 0,
+        cool_down_period_s: 60,
+    };
+    let user = UserSetup {
+        staker_orca: 0,
+        staker_xorca: 1_000_000,
+    };
+    let mut env = Env::new(ctx, &pool, &user);
+    let pending_withdraw_account = find_pending_withdraw_pda(&env.staker, &0).unwrap().0;
+    assert!(do_unstake(&mut env, 0, 1_000_000).is_ok());
+
+    let pending_withdraw = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap();
+    assert_eq!(pending_withdraw.account.data.len(), PendingWithdraw::LEN);
+    assert_eq!(PendingWithdraw::LEN, 128);
+    assert_eq!(pending_withdraw.data.version, 2);
+    assert_eq!(
+        pending_withdraw.account.lamports,
+        env.ctx
+            .get_sysvar::<Rent>()
+            .minimum_balance(PendingWithdraw::LEN)
+    );
+}
+
 // Success: high exchange rate (5e9) so non_escrowed=5*supply -> withdrawable ~= 5*xORCA
 #[test]
 fn test_unstake_succeeds_at_high_exchange_rate() {
@@ -898,6 +931,8 @@ fn test_unstake_invalid_token_program_id() {
             orca_mint_account: ORCA_ID,
             system_program_account: SYSTEM_PROGRAM_ID,
             token_program_account: invalid_token_program_id,
+            rent_payer_account: None,
+            staker_account_pda: None,
         }
         .instruction(xorca::UnstakeInstructionArgs {
             xorca_unstake_amount: 10_000_000_000,
@@ -939,6 +974,8 @@ fn test_unstake_invalid_system_program_id() {
             orca_mint_account: ORCA_ID,
             system_program_account: invalid_system_program_id,
             token_program_account: TOKEN_PROGRAM_ID,
+            rent_payer_account: None,
+            staker_account_pda: None,
         }
         .instruction(xorca::UnstakeInstructionArgs {
             xorca_unstake_amount: 10_000_000_000,
@@ -1016,6 +1053,8 @@ fn test_unstake_wrong_vault_account_seeds() {
             orca_mint_account: ORCA_ID,
             system_program_account: SYSTEM_PROGRAM_ID,
             token_program_account: TOKEN_PROGRAM_ID,
+            rent_payer_account: None,
+            staker_account_pda: None,
         }
         .instruction(xorca::UnstakeInstructionArgs {
             xorca_unstake_amount: 1_000_000,
@@ -1065,6 +1104,8 @@ fn test_unstake_invalid_xorca_mint_address() {
             orca_mint_account: ORCA_ID,
             system_program_account: SYSTEM_PROGRAM_ID,
             token_program_account: TOKEN_PROGRAM_ID,
+            rent_payer_account: None,
+            staker_account_pda: None,
         }
         .instruction(xorca::UnstakeInstructionArgs {
             xorca_unstake_amount: 1_000_000,
@@ -1113,6 +1154,8 @@ fn test_unstake_invalid_orca_mint_address() {
             orca_mint_account: wrong_orca,
             system_program_account: SYSTEM_PROGRAM_ID,
             token_program_account: TOKEN_PROGRAM_ID,
+            rent_payer_account: None,
+            staker_account_pda: None,
         }
         .instruction(xorca::UnstakeInstructionArgs {
             xorca_unstake_amount: 1_000_000,
@@ -1247,6 +1290,8 @@ fn test_unstake_concurrent_unstakes_same_user_in_one_tx() {
         orca_mint_account: ORCA_ID,
         system_program_account: SYSTEM_PROGRAM_ID,
         token_program_account: TOKEN_PROGRAM_ID,
+        rent_payer_account: None,
+        staker_account_pda: None,
     }
     .instruction(xorca::UnstakeInstructionArgs {
         xorca_unstake_amount: 1_000_000,
@@ -1262,6 +1307,8 @@ fn test_unstake_concurrent_unstakes_same_user_in_one_tx() {
         orca_mint_account: ORCA_ID,
         system_program_account: SYSTEM_PROGRAM_ID,
         token_program_account: TOKEN_PROGRAM_ID,
+        rent_payer_account: None,
+        staker_account_pda: None,
     }
     .instruction(xorca::UnstakeInstructionArgs {
         xorca_unstake_amount: 2_000_000,
@@ -1310,6 +1357,8 @@ fn test_unstake_withdraw_index_mismatch() {
             orca_mint_account: ORCA_ID,
             system_program_account: SYSTEM_PROGRAM_ID,
             token_program_account: TOKEN_PROGRAM_ID,
+            rent_payer_account: None,
+            staker_account_pda: None,
         }
         .instruction(xorca::UnstakeInstructionArgs {
             xorca_unstake_amount: 1_000_000,
@@ -1400,6 +1449,8 @@ fn test_unstake_event_emission_verification() {
             orca_mint_account: ORCA_ID,
             system_program_account: SYSTEM_PROGRAM_ID,
             token_program_account: TOKEN_PROGRAM_ID,
+            rent_payer_account: None,
+            staker_account_pda: None,
         }
         .instruction(xorca::UnstakeInstructionArgs {
             xorca_unstake_amount: 1_000_000,
@@ -0,0 +1,228 @@
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::advance_clock_env;
+use crate::{
+    assert_program_error, ORCA_ID, SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID, UNSTAKE_CPI_CALLER_ID,
+    XORCA_ID,
+};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+};
+use xorca::{
+    find_pending_withdraw_pda, PendingWithdraw, TokenAccount, Unstake, UnstakeInstructionArgs,
+    Withdraw, WithdrawInstructionArgs, XorcaStakingProgramError,
+};
+
+const XORCA_UNSTAKE_AMOUNT: u64 = 1_000_000;
+const COOL_DOWN_PERIOD_S: i64 = 60;
+
+const POOL: PoolSetup = PoolSetup {
+    cool_down_period_s: COOL_DOWN_PERIOD_S,
+    ..PoolSetup::funded()
+};
+
+// A program-owned unstaker: the caller program's `unstaker` PDA holding xORCA but no lamports
+struct PdaUnstaker {
+    key: Pubkey,
+    xorca_account: Pubkey,
+    orca_account: Pubkey,
+}
+
+fn setup_pda_unstaker(env: &mut Env) -> PdaUnstaker {
+    let unstake_cpi_caller_elf = include_bytes!("../../../target/deploy/unstake_cpi_caller.so");
+    assert!(
+        !unstake_cpi_caller_elf.is_empty(),
+        "target/deploy/unstake_cpi_caller.so is empty; run `yarn build:test` first"
+    );
+    env.ctx
+        .svm
+        .borrow_mut()
+        .add_program(UNSTAKE_CPI_CALLER_ID, unstake_cpi_caller_elf)
+        .unwrap();
+    let key = Pubkey::find_program_address(&[b"unstaker"], &UNSTAKE_CPI_CALLER_ID).0;
+    let xorca_account = Pubkey::new_unique();
+    env.ctx
+        .write_account(
+            xorca_account,
+            TOKEN_PROGRAM_ID,
+            crate::token_account_data!(mint => XORCA_ID, owner => key, amount => XORCA_UNSTAKE_AMOUNT),
+        )
+        .unwrap();
+    let orca_account = Pubkey::new_unique();
+    env.ctx
+        .write_account(
+            orca_account,
+            TOKEN_PROGRAM_ID,
+            crate::token_account_data!(mint => ORCA_ID, owner => key, amount => 0),
+        )
+        .unwrap();
+    PdaUnstaker {
+        key,
+        xorca_account,
+        orca_account,
+    }
+}
+
+fn unstake_ix(
+    env: &Env,
+    unstaker: Pubkey,
+    unstaker_xorca_ata: Pubkey,
+    rent_payer: Option<Pubkey>,
+) -> Instruction {
+    Unstake {
+        unstaker_account: unstaker,
+        state_account: env.state,
+        vault_account: env.vault,
+        pending_withdraw_account: find_pending_withdraw_pda(&unstaker, &0).unwrap().0,
+        unstaker_xorca_ata,
+        xorca_mint_account: XORCA_ID,
+        orca_mint_account: ORCA_ID,
+        system_program_account: SYSTEM_PROGRAM_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+        rent_payer_account: rent_payer,
+        staker_account_pda: None,
+    }
+    .instruction(UnstakeInstructionArgs {
+        xorca_unstake_amount: XORCA_UNSTAKE_AMOUNT,
+        withdraw_index: 0,
+    })
+}
+
+// Routes an instruction through the caller program, which signs for its `unstaker` PDA
+fn via_cpi_caller(mut ix: Instruction, unstaker: Pubkey) -> Instruction {
+    for account in ix.accounts.iter_mut() {
+        if account.pubkey == unstaker {
+            account.is_signer = false;
+        }
+    }
+    ix.accounts
+        .insert(0, AccountMeta::new_readonly(ix.program_id, false));
+    ix.program_id = UNSTAKE_CPI_CALLER_ID;
+    ix
+}
+
+// Happy path: a separate rent payer funds the pending withdraw while the unstaker owns it
+#[test]
+fn test_unstake_with_rent_payer_funds_pending_withdraw() {
+    let env = Env::setup(&POOL, 0, 1_000_000_000);
+    let rent_payer = Keypair::new();
+    env.ctx
+        .svm
+        .borrow_mut()
+        .airdrop(&rent_payer.pubkey(), 1_000_000_000)
+        .unwrap();
+
+    let ix = unstake_ix(
+        &env,
+        env.staker,
+        env.staker_xorca_ata,
+        Some(rent_payer.pubkey()),
+    );
+    let res = env
+        .ctx
+        .sends_with_signers(&[ix], &[env.ctx.signer_ref(), &rent_payer]);
+    assert!(res.is_ok());
+
+    let pending_withdraw_account = find_pending_withdraw_pda(&env.staker, &0).unwrap().0;
+    let rent = env
+        .ctx
+        .get_raw_account(pending_withdraw_account)
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        env.ctx
+            .get_raw_account(rent_payer.pubkey())
+            .unwrap()
+            .lamports,
+        1_000_000_000 - rent
+    );
+    let pending_withdraw = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap();
+    assert_eq!(pending_withdraw.data.unstaker, env.staker);
+}
+
+// Failure: the rent payer must sign for the lamports it provides
+#[test]
+fn test_unstake_fails_when_rent_payer_does_not_sign() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000_000);
+    let rent_payer = Pubkey::new_unique();
+    let mut ix = unstake_ix(&env, env.staker, env.staker_xorca_ata, Some(rent_payer));
+    ix.accounts[9].is_signer = false;
+    let res = env.ctx.sends(&[ix]);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidAccountRole);
+}
+
+// Happy path: a program unstakes for its PDA through CPI and later withdraws, receiving the rent
+#[test]
+fn test_unstake_via_cpi_for_program_owned_unstaker() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000_000);
+    let unstaker = setup_pda_unstaker(&mut env);
+
+    let ix = via_cpi_caller(
+        unstake_ix(&env, unstaker.key, unstaker.xorca_account, Some(env.staker)),
+        unstaker.key,
+    );
+    let res = env.ctx.sends(&[ix]);
+    assert!(res.is_ok());
+
+    let pending_withdraw_account = find_pending_withdraw_pda(&unstaker.key, &0).unwrap().0;
+    let pending_withdraw = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap();
+    assert_eq!(pending_withdraw.data.unstaker, unstaker.key);
+    let withdrawable_orca_amount = pending_withdraw.data.withdrawable_orca_amount;
+    let rent = pending_withdraw.account.lamports;
+    let xorca_account = env
+        .ctx
+        .get_account::<TokenAccount>(unstaker.xorca_account)
+        .unwrap();
+    assert_eq!(xorca_account.data.amount, 0);
+
+    advance_clock_env(&mut env, COOL_DOWN_PERIOD_S + 1);
+    let ix = via_cpi_caller(
+        Withdraw {
+            unstaker_account: unstaker.key,
+            state_account: env.state,
+            pending_withdraw_account,
+            unstaker_orca_ata: unstaker.orca_account,
+            vault_account: env.vault,
+            orca_mint_account: ORCA_ID,
+            system_program_account: SYSTEM_PROGRAM_ID,
+            token_program_account: TOKEN_PROGRAM_ID,
+        }
+        .instruction(WithdrawInstructionArgs { withdraw_index: 0 }),
+        unstaker.key,
+    );
+    let res = env.ctx.sends(&[ix]);
+    assert!(res.is_ok());
+
+    let orca_account = env
+        .ctx
+        .get_account::<TokenAccount>(unstaker.orca_account)
+        .unwrap();
+    assert_eq!(orca_account.data.amount, withdrawable_orca_amount);
+    assert_eq!(
+        env.ctx.get_raw_account(unstaker.key).unwrap().lamports,
+        rent
+    );
+}
+
+// Failure: without a rent payer the unfunded PDA cannot pay for its pending withdraw
+#[test]
+fn test_unstake_via_cpi_fails_without_rent_payer() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000_000);
+    let unstaker = setup_pda_unstaker(&mut env);
+    let ix = via_cpi_caller(
+        unstake_ix(&env, unstaker.key, unstaker.xorca_account, None),
+        unstaker.key,
+    );
+    let res = env.ctx.sends(&[ix]);
+    assert!(res.is_err());
+    let pending_withdraw_account = find_pending_withdraw_pda(&unstaker.key, &0).unwrap().0;
+    assert!(env.ctx.get_raw_account(pending_withdraw_account).is_err());
+}
@@ -0,0 +1,157 @@
+use crate::utils::assert::decode_events_from_result;
+use crate::{assert_program_error, TestContext, XORCA_PROGRAM_ID};
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use xorca::{
+    find_state_address, AcceptUpdateAuthority, Event, Set, SetInstructionArgs, State,
+    StateUpdateInstruction, XorcaStakingProgramError,
+};
+
+fn seed_state(ctx: &mut TestContext, pending_update_authority: Pubkey) -> Pubkey {
+    let (state, state_bump) = find_state_address().unwrap();
+    let update_authority = ctx.signer();
+    ctx.write_account(
+        state,
+        XORCA_PROGRAM_ID,
+        crate::state_data!(
+            escrowed_orca_amount => 0,
+            update_authority => update_authority,
+            pending_update_authority => pending_update_authority,
+            cool_down_period_s => 10,
+            bump => state_bump,
+        ),
+    )
+    .unwrap();
+    state
+}
+
+fn propose_ix(update_authority: Pubkey, state: Pubkey, new_authority: Pubkey) -> Instruction {
+    Set {
+        update_authority_account: update_authority,
+        state_account: state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::ProposeUpdateAuthority { new_authority },
+    })
+}
+
+fn accept_ix(pending_update_authority: Pubkey, state: Pubkey) -> Instruction {
+    AcceptUpdateAuthority {
+        pending_update_authority_account: pending_update_authority,
+        state_account: state,
+    }
+    .instruction()
+}
+
+// Happy path: proposing records the pending authority without changing the current one
+#[test]
+fn test_propose_update_authority_sets_pending_authority() {
+    let mut ctx = TestContext::new();
+    let state = seed_state(&mut ctx, Pubkey::default());
+    let new_authority = Pubkey::new_unique();
+    let res = ctx.sends(&[propose_ix(ctx.signer(), state, new_authority)]);
+    assert!(res.is_ok());
+
+    let state_account = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(state_account.data.update_authority, ctx.signer());
+    assert_eq!(state_account.data.pending_update_authority, new_authority);
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::UpdateAuthorityProposed {
+            proposed_authority,
+            proposed_by,
+        } => *proposed_authority == new_authority && *proposed_by == ctx.signer(),
+        _ => false,
+    });
+    assert!(found, "UpdateAuthorityProposed event should be emitted");
+}
+
+// Failure: only the current update authority can propose
+#[test]
+fn test_propose_update_authority_fails_with_wrong_update_authority() {
+    let mut ctx = TestContext::new();
+    let state = seed_state(&mut ctx, Pubkey::default());
+    let other = Keypair::new();
+    let ix = propose_ix(other.pubkey(), state, other.pubkey());
+    let res = ctx.sends_with_signers(&[ix], &[ctx.signer_ref(), &other]);
+    assert_program_error!(res, XorcaStakingProgramError::IncorrectAccountAddress);
+}
+
+// Happy path: the proposed key accepts and becomes the update authority
+#[test]
+fn test_accept_update_authority_transfers_authority() {
+    let mut ctx = TestContext::new();
+    let new_authority = Keypair::new();
+    let state = seed_state(&mut ctx, new_authority.pubkey());
+    let old_authority = ctx.signer();
+    let ix = accept_ix(new_authority.pubkey(), state);
+    let res = ctx.sends_with_signers(&[ix], &[ctx.signer_ref(), &new_authority]);
+    assert!(res.is_ok());
+
+    let state_account = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(state_account.data.update_authority, new_authority.pubkey());
+    assert_eq!(
+        state_account.data.pending_update_authority,
+        Pubkey::default()
+    );
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::UpdateAuthoritySet {
+            new_authority: event_new_authority,
+            set_by,
+        } => *event_new_authority == new_authority.pubkey() && *set_by == old_authority,
+        _ => false,
+    });
+    assert!(found, "UpdateAuthoritySet event should be emitted");
+
+    // The previous authority can no longer update the state
+    let res = ctx.sends(&[propose_ix(old_authority, state, old_authority)]);
+    assert_program_error!(res, XorcaStakingProgramError::IncorrectAccountAddress);
+}
+
+// Failure: a key other than the proposed one cannot accept
+#[test]
+fn test_accept_update_authority_fails_with_wrong_signer() {
+    let mut ctx = TestContext::new();
+    let state = seed_state(&mut ctx, Pubkey::new_unique());
+    let other = Keypair::new();
+    let ix = accept_ix(other.pubkey(), state);
+    let res = ctx.sends_with_signers(&[ix], &[ctx.signer_ref(), &other]);
+    assert_program_error!(res, XorcaStakingProgramError::IncorrectAccountAddress);
+    let state_account = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(state_account.data.update_authority, ctx.signer());
+}
+
+// Failure: nothing to accept without a pending proposal
+#[test]
+fn test_accept_update_authority_fails_without_proposal() {
+    let mut ctx = TestContext::new();
+    let state = seed_state(&mut ctx, Pubkey::default());
+    let res = ctx.sends(&[accept_ix(ctx.signer(), state)]);
+    assert_program_error!(res, XorcaStakingProgramError::IncorrectAccountAddress);
+}
+
+// Failure: the deprecated one-step UpdateUpdateAuthority no longer moves the authority
+#[test]
+fn test_set_rejects_deprecated_update_update_authority() {
+    let mut ctx = TestContext::new();
+    let state = seed_state(&mut ctx, Pubkey::default());
+    let new_authority = Pubkey::new_unique();
+    let ix = Set {
+        update_authority_account: ctx.signer(),
+        state_account: state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::UpdateUpdateAuthority { new_authority },
+    });
+    let res = ctx.sends(&[ix]);
+    assert_program_error!(res, XorcaStakingProgramError::DeprecatedInstruction);
+    let state_account = ctx.get_account::<State>(state).unwrap();
+    assert_eq!(state_account.data.update_authority, ctx.signer());
+    assert_eq!(state_account.data.instant_unstake_fee_bps, 0);
+}
@@ -4,8 +4,8 @@ use crate::utils::assert::{
 };
 use crate::utils::fixture::{Env, PoolSetup, UserSetup};
 use crate::utils::flows::{
-    advance_clock_env, do_unstake, do_withdraw, do_withdraw_with_unique, stake_orca,
-    unstake_and_advance,
+    advance_clock_env, do_apply_pending_config, do_unstake, do_withdraw, do_withdraw_with_unique,
+    stake_orca, unstake_and_advance,
 };
 use crate::{
     assert_program_error, TestContext, ORCA_ID, SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID, XORCA_ID,
@@ -17,6 +17,7 @@ use xorca::{
     Withdraw, WithdrawInstructionArgs, XorcaStakingProgramError,
 };
 use xorca::{Set, SetInstructionArgs, StateUpdateInstruction};
+use xorca_staking_program::state::state::CONFIG_TIMELOCK_S;
 
 // Cost for resizing an account to 0 in Solana runtime
 const RESIZE_TO_ZERO_COST: u64 = 5000;
@@ -1414,9 +1415,13 @@ fn withdraw_event_emission_verification() {
             withdrawable_orca_amount,
             cool_down_period_s,
             withdraw_index,
+            destination,
+            staker_withdraw_index,
         } = e
         {
             assert_eq!(withdraw_index, idx);
+            assert_eq!(staker_withdraw_index, None);
+            assert_eq!(destination, env.staker_orca_ata);
             assert_eq!(withdrawable_orca_amount, pending_withdrawable);
             assert_eq!(cool_down_period_s, state_before.data.cool_down_period_s);
             assert_eq!(
@@ -1886,10 +1891,23 @@ fn withdraw_cooldown_update_mid_flight_policy_change() {
         )
         .unwrap();
 
+    // Queue a new cooldown value (e.g., 100s) and let the timelock pass
+    let ix_set = Set {
+        update_authority_account: env.ctx.signer(),
+        state_account: env.state,
+    }
+    .instruction(SetInstructionArgs {
+        instruction_data: StateUpdateInstruction::UpdateCoolDownPeriod {
+            new_cool_down_period_s: 100,
+        },
+    });
+    assert!(env.ctx.sends(&[ix_set]).is_ok());
+    advance_clock_env(&mut env, CONFIG_TIMELOCK_S);
+
     // Stake to get xORCA tokens first
     let _ = stake_orca(&mut env, 2_000_000);
 
-    // Create a pending withdraw at old cooldown (10s)
+    // Create a pending withdraw at old cooldown (10s), still in effect until applied
     let idx_old = 40u8;
     let pending_old = unstake_and_advance(&mut env, idx_old, 1_000_000, 0);
     let old_pending_ts = env
@@ -1899,17 +1917,8 @@ fn withdraw_cooldown_update_mid_flight_policy_change() {
         .data
         .withdrawable_timestamp;
 
-    // Update cooldown to a new value (e.g., 100s)
-    let ix_set = Set {
-        update_authority_account: env.ctx.signer(),
-        state_account: env.state,
-    }
-    .instruction(SetInstructionArgs {
-        instruction_data: StateUpdateInstruction::UpdateCoolDownPeriod {
-            new_cool_down_period_s: 100,
-        },
-    });
-    assert!(env.ctx.sends(&[ix_set]).is_ok());
+    // Apply the queued cooldown
+    assert!(do_apply_pending_config(&mut env).is_ok());
 
     // New pending should use new cooldown value
     let idx_new = 41u8;
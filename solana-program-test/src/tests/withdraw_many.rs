@@ -0,0 +1,123 @@
+use crate::assert_program_error;
+use crate::utils::assert::{assert_account_closed, decode_events_from_result};
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{advance_clock_env, do_withdraw_many, unstake_and_advance};
+use xorca::{
+    find_pending_withdraw_pda, Event, PendingWithdraw, State, TokenAccount,
+    XorcaStakingProgramError,
+};
+
+const POOL: PoolSetup = PoolSetup {
+    cool_down_period_s: 100,
+    ..PoolSetup::funded()
+};
+
+// Happy path: all matured withdraws are paid in one transfer, closed, and reported in one event
+#[test]
+fn test_withdraw_many_claims_all_matured_withdraws() {
+    let mut env = Env::setup(&POOL, 0, 6_000_000);
+    let indexes = [0u8, 3, 200];
+    let amounts = [1_000_000u64, 2_000_000, 3_000_000];
+    let mut pending_withdraw_accounts = Vec::new();
+    for (idx, amount) in indexes.iter().zip(amounts) {
+        pending_withdraw_accounts.push(unstake_and_advance(&mut env, *idx, amount, 0));
+    }
+    let withdrawable_total: u64 = pending_withdraw_accounts
+        .iter()
+        .map(|account| {
+            env.ctx
+                .get_account::<PendingWithdraw>(*account)
+                .unwrap()
+                .data
+                .withdrawable_orca_amount
+        })
+        .sum();
+    let escrow_before = env
+        .ctx
+        .get_account::<State>(env.state)
+        .unwrap()
+        .data
+        .escrowed_orca_amount;
+    advance_clock_env(&mut env, 100);
+
+    let res = do_withdraw_many(&mut env, &indexes);
+    assert!(res.is_ok());
+
+    for account in &pending_withdraw_accounts {
+        assert_account_closed(&env.ctx, *account, "pending withdraw closed");
+    }
+    let user_orca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_orca_ata)
+        .unwrap();
+    assert_eq!(user_orca.data.amount, withdrawable_total);
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    assert_eq!(
+        state.data.escrowed_orca_amount,
+        escrow_before - withdrawable_total
+    );
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::WithdrawMany {
+            vault_escrowed_orca_amount,
+            withdrawable_orca_amount,
+            withdraw_count,
+        } => {
+            *vault_escrowed_orca_amount == state.data.escrowed_orca_amount
+                && *withdrawable_orca_amount == withdrawable_total
+                && *withdraw_count == 3
+        }
+        _ => false,
+    });
+    assert!(found, "WithdrawMany event should be emitted");
+}
+
+// Failure: one immature withdraw fails the whole batch
+#[test]
+fn test_withdraw_many_fails_if_any_withdraw_is_immature() {
+    let mut env = Env::setup(&POOL, 0, 2_000_000);
+    unstake_and_advance(&mut env, 0, 1_000_000, 100);
+    unstake_and_advance(&mut env, 1, 1_000_000, 0);
+    let res = do_withdraw_many(&mut env, &[0, 1]);
+    assert_program_error!(res, XorcaStakingProgramError::CoolDownPeriodStillActive);
+    let pending0 = find_pending_withdraw_pda(&env.staker, &0).unwrap().0;
+    assert!(env.ctx.get_account::<PendingWithdraw>(pending0).is_ok());
+}
+
+// Failure: at least one pending withdraw account is required
+#[test]
+fn test_withdraw_many_fails_without_pending_withdraws() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000);
+    let res = do_withdraw_many(&mut env, &[]);
+    assert_program_error!(res, XorcaStakingProgramError::NotEnoughAccountKeys);
+}
+
+// Failure: a pending withdraw listed twice is rejected instead of being paid twice
+#[test]
+fn test_withdraw_many_fails_with_duplicate_pending_withdraw() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000);
+    unstake_and_advance(&mut env, 4, 1_000_000, 100);
+    let res = do_withdraw_many(&mut env, &[4, 4]);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidAccountData);
+}
+
+// Failure: an index without a pending withdraw
+#[test]
+fn test_withdraw_many_fails_with_missing_pending_withdraw() {
+    let mut env = Env::setup(&POOL, 0, 1_000_000);
+    unstake_and_advance(&mut env, 0, 1_000_000, 100);
+    let res = do_withdraw_many(&mut env, &[0, 1]);
+    assert_program_error!(res, XorcaStakingProgramError::IncorrectOwner);
+}
+
+// Failure: escrow lower than the combined withdraws
+#[test]
+fn test_withdraw_many_fails_with_insufficient_escrow() {
+    let mut env = Env::setup(&POOL, 0, 2_000_000);
+    unstake_and_advance(&mut env, 0, 1_000_000, 0);
+    unstake_and_advance(&mut env, 1, 1_000_000, 100);
+    env.update_state(|state| state.escrowed_orca_amount = 1_500_000);
+    let res = do_withdraw_many(&mut env, &[0, 1]);
+    assert_program_error!(res, XorcaStakingProgramError::InsufficientEscrow);
+}
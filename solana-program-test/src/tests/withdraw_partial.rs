@@ -0,0 +1,188 @@
+use crate::assert_program_error;
+use crate::utils::assert::{assert_account_closed, decode_events_from_result};
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{
+    do_withdraw, do_withdraw_partial, do_withdraw_partial_with_unique, unstake_and_advance,
+};
+use xorca::{Event, PendingWithdraw, State, TokenAccount, XorcaStakingProgramError};
+
+// Happy path: partial withdraw transfers the amount, lowers escrow and keeps the remainder claimable
+#[test]
+fn test_withdraw_partial_keeps_remainder() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    let idx = 0u8;
+    let pending_withdraw_account = unstake_and_advance(&mut env, idx, 1_000_000, 61);
+    let escrow_before = env
+        .ctx
+        .get_account::<State>(env.state)
+        .unwrap()
+        .data
+        .escrowed_orca_amount;
+    let withdrawable_before = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap()
+        .data
+        .withdrawable_orca_amount;
+    let user_orca_before = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_orca_ata)
+        .unwrap()
+        .data
+        .amount;
+
+    let orca_withdraw_amount = 400_000u64;
+    let res = do_withdraw_partial(
+        &mut env,
+        pending_withdraw_account,
+        idx,
+        orca_withdraw_amount,
+    );
+    assert!(res.is_ok());
+
+    let pending = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap();
+    assert_eq!(
+        pending.data.withdrawable_orca_amount,
+        withdrawable_before - orca_withdraw_amount
+    );
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    assert_eq!(
+        state.data.escrowed_orca_amount,
+        escrow_before - orca_withdraw_amount
+    );
+    let user_orca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_orca_ata)
+        .unwrap();
+    assert_eq!(
+        user_orca.data.amount,
+        user_orca_before + orca_withdraw_amount
+    );
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::WithdrawPartial {
+            orca_withdraw_amount: amount,
+            remaining_orca_amount,
+            withdraw_index,
+            ..
+        } => {
+            *amount == orca_withdraw_amount
+                && *remaining_orca_amount == withdrawable_before - orca_withdraw_amount
+                && *withdraw_index == idx
+        }
+        _ => false,
+    });
+    assert!(found, "WithdrawPartial event should be emitted");
+}
+
+// Happy path: draining the remainder closes the pending withdraw
+#[test]
+fn test_withdraw_partial_closes_account_at_zero() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    let idx = 5u8;
+    let pending_withdraw_account = unstake_and_advance(&mut env, idx, 1_000_000, 61);
+    let withdrawable = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap()
+        .data
+        .withdrawable_orca_amount;
+
+    assert!(do_withdraw_partial(&mut env, pending_withdraw_account, idx, 300_000).is_ok());
+    let res = do_withdraw_partial_with_unique(
+        &mut env,
+        pending_withdraw_account,
+        idx,
+        withdrawable - 300_000,
+        1,
+    );
+    assert!(res.is_ok());
+    assert_account_closed(
+        &env.ctx,
+        pending_withdraw_account,
+        "pending withdraw closed",
+    );
+    let state = env.ctx.get_account::<State>(env.state).unwrap();
+    assert_eq!(state.data.escrowed_orca_amount, 0);
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| {
+        matches!(
+            event,
+            Event::WithdrawPartial {
+                remaining_orca_amount: 0,
+                ..
+            }
+        )
+    });
+    assert!(found, "WithdrawPartial event should report zero remaining");
+}
+
+// Happy path: the full Withdraw instruction still claims whatever remains
+#[test]
+fn test_withdraw_after_partial_withdraw() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    let idx = 1u8;
+    let pending_withdraw_account = unstake_and_advance(&mut env, idx, 1_000_000, 61);
+    assert!(do_withdraw_partial(&mut env, pending_withdraw_account, idx, 250_000).is_ok());
+    assert!(do_withdraw(&mut env, pending_withdraw_account, idx).is_ok());
+    assert_account_closed(
+        &env.ctx,
+        pending_withdraw_account,
+        "pending withdraw closed",
+    );
+    let user_orca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_orca_ata)
+        .unwrap();
+    assert_eq!(user_orca.data.amount, 1_000_000);
+}
+
+// Failure: partial withdraw before the cooldown has elapsed
+#[test]
+fn test_withdraw_partial_fails_before_cooldown() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    let idx = 2u8;
+    let pending_withdraw_account = unstake_and_advance(&mut env, idx, 1_000_000, 10);
+    let res = do_withdraw_partial(&mut env, pending_withdraw_account, idx, 100_000);
+    assert_program_error!(res, XorcaStakingProgramError::CoolDownPeriodStillActive);
+}
+
+// Failure: zero amount is rejected
+#[test]
+fn test_withdraw_partial_fails_with_zero_amount() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    let idx = 3u8;
+    let pending_withdraw_account = unstake_and_advance(&mut env, idx, 1_000_000, 61);
+    let res = do_withdraw_partial(&mut env, pending_withdraw_account, idx, 0);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidWithdrawAmount);
+}
+
+// Failure: amount above the pending balance is rejected
+#[test]
+fn test_withdraw_partial_fails_above_pending_amount() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    let idx = 4u8;
+    let pending_withdraw_account = unstake_and_advance(&mut env, idx, 1_000_000, 61);
+    let withdrawable = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap()
+        .data
+        .withdrawable_orca_amount;
+    let res = do_withdraw_partial(&mut env, pending_withdraw_account, idx, withdrawable + 1);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidWithdrawAmount);
+}
+
+// Failure: pending withdraw account does not match the withdraw index
+#[test]
+fn test_withdraw_partial_fails_with_mismatched_index() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000);
+    let pending_withdraw_account = unstake_and_advance(&mut env, 7, 1_000_000, 61);
+    let res = do_withdraw_partial(&mut env, pending_withdraw_account, 8, 100_000);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidSeeds);
+}
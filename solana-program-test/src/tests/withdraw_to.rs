@@ -0,0 +1,96 @@
+use crate::utils::assert::decode_events_from_result;
+use crate::utils::fixture::{Env, PoolSetup};
+use crate::utils::flows::{do_withdraw_to, unstake_and_advance};
+use crate::{assert_program_error, ORCA_ID, TOKEN_PROGRAM_ID, XORCA_ID};
+use solana_sdk::pubkey::Pubkey;
+use xorca::{Event, PendingWithdraw, TokenAccount, XorcaStakingProgramError};
+
+fn create_token_account(env: &mut Env, mint: Pubkey, owner: Pubkey) -> Pubkey {
+    let token_account = Pubkey::new_unique();
+    env.ctx
+        .write_account(
+            token_account,
+            TOKEN_PROGRAM_ID,
+            crate::token_account_data!(mint => mint, owner => owner, amount => 0),
+        )
+        .unwrap();
+    token_account
+}
+
+// Happy path: matured ORCA goes to the destination while the rent goes back to the unstaker
+#[test]
+fn test_withdraw_to_sends_orca_to_destination() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000_000);
+    let destination = create_token_account(&mut env, ORCA_ID, Pubkey::new_unique());
+    let pending_withdraw_account = unstake_and_advance(&mut env, 0, 1_000_000_000, 61);
+    let withdrawable_orca_amount = env
+        .ctx
+        .get_account::<PendingWithdraw>(pending_withdraw_account)
+        .unwrap()
+        .data
+        .withdrawable_orca_amount;
+    let pending_withdraw_lamports = env
+        .ctx
+        .get_raw_account(pending_withdraw_account)
+        .unwrap()
+        .lamports;
+    let staker_lamports_before = env.ctx.get_raw_account(env.staker).unwrap().lamports;
+
+    let res = do_withdraw_to(&mut env, pending_withdraw_account, 0, destination);
+    assert!(res.is_ok());
+
+    let destination_orca = env.ctx.get_account::<TokenAccount>(destination).unwrap();
+    assert_eq!(destination_orca.data.amount, withdrawable_orca_amount);
+    let staker_orca = env
+        .ctx
+        .get_account::<TokenAccount>(env.staker_orca_ata)
+        .unwrap();
+    assert_eq!(staker_orca.data.amount, 0);
+    assert!(env.ctx.get_raw_account(pending_withdraw_account).is_err());
+    // The staker also pays the transaction fee, so the refund is checked against it
+    let staker_lamports_after = env.ctx.get_raw_account(env.staker).unwrap().lamports;
+    assert!(staker_lamports_after > staker_lamports_before);
+    assert!(staker_lamports_after <= staker_lamports_before + pending_withdraw_lamports);
+
+    let events = decode_events_from_result(&res);
+    let found = events.iter().any(|event| match event {
+        Event::Withdraw {
+            withdrawable_orca_amount: amount,
+            destination: event_destination,
+            ..
+        } => *amount == withdrawable_orca_amount && *event_destination == destination,
+        _ => false,
+    });
+    assert!(found, "Withdraw event should include the destination");
+}
+
+// Failure: the destination must hold ORCA
+#[test]
+fn test_withdraw_to_fails_with_non_orca_destination() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000_000);
+    let destination = create_token_account(&mut env, XORCA_ID, Pubkey::new_unique());
+    let pending_withdraw_account = unstake_and_advance(&mut env, 0, 1_000_000_000, 61);
+    let res = do_withdraw_to(&mut env, pending_withdraw_account, 0, destination);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidAccountData);
+}
+
+// Failure: the vault cannot be the destination
+#[test]
+fn test_withdraw_to_fails_with_vault_destination() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000_000);
+    let pending_withdraw_account = unstake_and_advance(&mut env, 0, 1_000_000_000, 61);
+    let vault = env.vault;
+    let res = do_withdraw_to(&mut env, pending_withdraw_account, 0, vault);
+    assert_program_error!(res, XorcaStakingProgramError::InvalidAccountData);
+    assert!(env.ctx.get_raw_account(pending_withdraw_account).is_ok());
+}
+
+// Failure: the cooldown still applies
+#[test]
+fn test_withdraw_to_fails_before_cooldown() {
+    let mut env = Env::setup(&PoolSetup::funded(), 0, 1_000_000_000);
+    let destination = create_token_account(&mut env, ORCA_ID, Pubkey::new_unique());
+    let pending_withdraw_account = unstake_and_advance(&mut env, 0, 1_000_000_000, 1);
+    let res = do_withdraw_to(&mut env, pending_withdraw_account, 0, destination);
+    assert_program_error!(res, XorcaStakingProgramError::CoolDownPeriodStillActive);
+}
@@ -3,7 +3,8 @@ use crate::{
     TOKEN_PROGRAM_ID, XORCA_ID, XORCA_PROGRAM_ID,
 };
 use solana_sdk::pubkey::Pubkey;
-use xorca::{find_orca_vault_address, find_state_address};
+use xorca::{find_orca_vault_address, find_state_address, State, TokenAccount, TokenMint};
+use xorca_staking_program::util::math::{convert_orca_to_xorca, convert_xorca_to_orca};
 // (reserved) test math helpers could be added here if needed
 
 /// Describe initial pool state in terms of supply, vault ORCA, escrowed ORCA, and cooldown.
@@ -25,6 +26,19 @@ impl Default for PoolSetup {
     }
 }
 
+impl PoolSetup {
+    /// Pool most tests start from: 10_000 xORCA backed by 10_000 ORCA, nothing escrowed and a
+    /// 60 second cooldown. Adjust it with struct update syntax.
+    pub const fn funded() -> Self {
+        Self {
+            xorca_supply: 10_000_000_000,
+            vault_orca: 10_000_000_000,
+            escrowed_orca: 0,
+            cool_down_period_s: 60,
+        }
+    }
+}
+
 /// Per-user starting balances.
 pub struct UserSetup {
     pub staker_orca: u64,
@@ -51,6 +65,15 @@ pub struct Env {
 }
 
 impl Env {
+    /// Fresh context over `pool` with a staker holding `staker_orca` ORCA and `staker_xorca` xORCA.
+    pub fn setup(pool: &PoolSetup, staker_orca: u64, staker_xorca: u64) -> Self {
+        let user = UserSetup {
+            staker_orca,
+            staker_xorca,
+        };
+        Self::new(TestContext::new(), pool, &user)
+    }
+
     pub fn new(mut ctx: TestContext, pool: &PoolSetup, user: &UserSetup) -> Self {
         let (state, state_bump) = find_state_address().unwrap();
         let staker = ctx.signer();
@@ -204,5 +227,39 @@ impl Env {
     }
 }
 
+impl Env {
+    /// Rewrites the state account after applying `update` to its data.
+    pub fn update_state(&mut self, update: impl FnOnce(&mut State)) {
+        let mut state = self.ctx.get_account::<State>(self.state).unwrap().data;
+        update(&mut state);
+        self.ctx
+            .write_account(self.state, XORCA_PROGRAM_ID, state)
+            .unwrap();
+    }
+
+    /// Non-escrowed vault ORCA and xORCA supply the exchange rate is computed from.
+    pub fn pool_amounts(&self) -> (u64, u64) {
+        let vault = self.ctx.get_account::<TokenAccount>(self.vault).unwrap();
+        let state = self.ctx.get_account::<State>(self.state).unwrap();
+        let mint = self.ctx.get_account::<TokenMint>(XORCA_ID).unwrap();
+        (
+            vault.data.amount - state.data.escrowed_orca_amount,
+            mint.data.supply,
+        )
+    }
+
+    /// xORCA minted for staking `orca_stake_amount` at the current rate.
+    pub fn expected_xorca_out(&self, orca_stake_amount: u64) -> u64 {
+        let (non_escrowed_orca_amount, xorca_supply) = self.pool_amounts();
+        convert_orca_to_xorca(orca_stake_amount, non_escrowed_orca_amount, xorca_supply).unwrap()
+    }
+
+    /// ORCA escrowed for unstaking `xorca_unstake_amount` at the current rate.
+    pub fn expected_orca_out(&self, xorca_unstake_amount: u64) -> u64 {
+        let (non_escrowed_orca_amount, xorca_supply) = self.pool_amounts();
+        convert_xorca_to_orca(xorca_unstake_amount, non_escrowed_orca_amount, xorca_supply).unwrap()
+    }
+}
+
 // Note: Any exchange-rate based setup should be expressed via concrete `xorca_supply`,
 // `vault_orca`, and `escrowed_orca` values.
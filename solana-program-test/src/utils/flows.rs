@@ -1,10 +1,19 @@
 use crate::utils::fixture::Env;
 use crate::{ORCA_ID, SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID, XORCA_ID};
 use litesvm::types::TransactionResult;
-use solana_sdk::{clock::Clock, pubkey::Pubkey, system_instruction};
+use solana_sdk::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey, system_instruction};
 use xorca::{
-    find_pending_withdraw_pda, Stake, StakeInstructionArgs, Unstake, UnstakeInstructionArgs,
-    Withdraw, WithdrawInstructionArgs,
+    find_pending_withdraw_pda, find_staker_account_pda, find_staker_pending_withdraw_pda,
+    ApplyPendingConfig, CancelUnstake, CancelUnstakeInstructionArgs, CrankWithdraw,
+    CrankWithdrawInstructionArgs, DepositRewards, DepositRewardsInstructionArgs, Harvest,
+    InitializeStakerAccount, InstantUnstake, InstantUnstakeInstructionArgs, MergePendingWithdraws,
+    MergePendingWithdrawsInstructionArgs, Migrate, Stake, StakeFor, StakeForExactXorca,
+    StakeForExactXorcaInstructionArgs, StakeForInstructionArgs, StakeInstructionArgs,
+    StakeWithSlippage, StakeWithSlippageInstructionArgs, StakerAccount, TransferPendingWithdraw,
+    TransferPendingWithdrawInstructionArgs, Unstake, UnstakeForExactOrca,
+    UnstakeForExactOrcaInstructionArgs, UnstakeInstructionArgs, UnstakeWithSlippage,
+    UnstakeWithSlippageInstructionArgs, Withdraw, WithdrawInstructionArgs, WithdrawMany,
+    WithdrawPartial, WithdrawPartialInstructionArgs, WithdrawTo, WithdrawToInstructionArgs,
 };
 
 pub fn unstake_and_advance(
@@ -26,6 +35,8 @@ pub fn unstake_and_advance(
         orca_mint_account: ORCA_ID,
         system_program_account: SYSTEM_PROGRAM_ID,
         token_program_account: TOKEN_PROGRAM_ID,
+        rent_payer_account: None,
+        staker_account_pda: None,
     }
     .instruction(UnstakeInstructionArgs {
         xorca_unstake_amount,
@@ -36,6 +47,54 @@ pub fn unstake_and_advance(
     pending_withdraw_account
 }
 
+pub fn do_initialize_staker_account(env: &mut Env) -> TransactionResult {
+    let ix = InitializeStakerAccount {
+        staker_account: env.staker,
+        staker_account_pda: find_staker_account_pda(&env.staker).unwrap().0,
+        system_program_account: SYSTEM_PROGRAM_ID,
+    }
+    .instruction();
+    env.ctx.sends(&[ix])
+}
+
+/// Unstakes at the staker account's next withdraw index and returns that index with the
+/// pending withdraw address.
+pub fn unstake_with_staker_account(
+    env: &mut Env,
+    xorca_unstake_amount: u64,
+) -> (u64, Pubkey, TransactionResult) {
+    let staker_account_pda = find_staker_account_pda(&env.staker).unwrap().0;
+    let staker_withdraw_index = env
+        .ctx
+        .get_account::<StakerAccount>(staker_account_pda)
+        .unwrap()
+        .data
+        .next_withdraw_index;
+    let pending_withdraw_account =
+        find_staker_pending_withdraw_pda(&env.staker, &staker_withdraw_index)
+            .unwrap()
+            .0;
+    let ix = Unstake {
+        unstaker_account: env.staker,
+        state_account: env.state,
+        vault_account: env.vault,
+        pending_withdraw_account,
+        unstaker_xorca_ata: env.staker_xorca_ata,
+        xorca_mint_account: XORCA_ID,
+        orca_mint_account: ORCA_ID,
+        system_program_account: SYSTEM_PROGRAM_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+        rent_payer_account: None,
+        staker_account_pda: Some(staker_account_pda),
+    }
+    .instruction(UnstakeInstructionArgs {
+        xorca_unstake_amount,
+        withdraw_index: staker_withdraw_index as u8,
+    });
+    let res = env.ctx.sends(&[ix]);
+    (staker_withdraw_index, pending_withdraw_account, res)
+}
+
 pub fn do_withdraw(
     env: &mut Env,
     pending_withdraw_account: Pubkey,
@@ -68,6 +127,125 @@ pub fn do_withdraw_with_unique(
     env.ctx.sends(&[ix, noop_ix])
 }
 
+pub fn do_withdraw_to(
+    env: &mut Env,
+    pending_withdraw_account: Pubkey,
+    withdraw_index: u8,
+    destination_orca_ata: Pubkey,
+) -> TransactionResult {
+    let ix = WithdrawTo {
+        unstaker_account: env.staker,
+        state_account: env.state,
+        vault_account: env.vault,
+        pending_withdraw_account,
+        destination_orca_ata,
+        orca_mint_account: ORCA_ID,
+        system_program_account: SYSTEM_PROGRAM_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+    }
+    .instruction(WithdrawToInstructionArgs { withdraw_index });
+    env.ctx.sends(&[ix])
+}
+
+pub fn do_crank_withdraw(
+    env: &mut Env,
+    unstaker: Pubkey,
+    unstaker_orca_ata: Pubkey,
+    withdraw_index: u8,
+) -> TransactionResult {
+    let pending_withdraw_account = find_pending_withdraw_pda(&unstaker, &withdraw_index)
+        .unwrap()
+        .0;
+    let ix = CrankWithdraw {
+        unstaker_account: unstaker,
+        state_account: env.state,
+        pending_withdraw_account,
+        unstaker_orca_ata,
+        vault_account: env.vault,
+        orca_mint_account: ORCA_ID,
+        system_program_account: SYSTEM_PROGRAM_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+        cranker_account: env.ctx.signer(),
+    }
+    .instruction(CrankWithdrawInstructionArgs { withdraw_index });
+    env.ctx.sends(&[ix])
+}
+
+pub fn do_transfer_pending_withdraw(
+    env: &mut Env,
+    pending_withdraw_account: Pubkey,
+    withdraw_index: u8,
+    new_owner: Pubkey,
+) -> TransactionResult {
+    let ix = TransferPendingWithdraw {
+        owner_account: env.staker,
+        pending_withdraw_account,
+    }
+    .instruction(TransferPendingWithdrawInstructionArgs {
+        withdraw_index,
+        new_owner,
+    });
+    env.ctx.sends(&[ix])
+}
+
+pub fn do_withdraw_partial(
+    env: &mut Env,
+    pending_withdraw_account: Pubkey,
+    withdraw_index: u8,
+    orca_withdraw_amount: u64,
+) -> TransactionResult {
+    do_withdraw_partial_with_unique(
+        env,
+        pending_withdraw_account,
+        withdraw_index,
+        orca_withdraw_amount,
+        0,
+    )
+}
+
+pub fn do_withdraw_partial_with_unique(
+    env: &mut Env,
+    pending_withdraw_account: Pubkey,
+    withdraw_index: u8,
+    orca_withdraw_amount: u64,
+    unique_id: u64,
+) -> TransactionResult {
+    let ix = WithdrawPartial {
+        unstaker_account: env.staker,
+        state_account: env.state,
+        vault_account: env.vault,
+        pending_withdraw_account,
+        unstaker_orca_ata: env.staker_orca_ata,
+        orca_mint_account: ORCA_ID,
+        system_program_account: SYSTEM_PROGRAM_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+    }
+    .instruction(WithdrawPartialInstructionArgs {
+        withdraw_index,
+        orca_withdraw_amount,
+    });
+
+    // Add a unique no-op instruction to make each transaction unique
+    let noop_ix = system_instruction::transfer(&env.staker, &env.staker, unique_id);
+
+    env.ctx.sends(&[ix, noop_ix])
+}
+
+pub fn do_withdraw_many(env: &mut Env, withdraw_indexes: &[u8]) -> TransactionResult {
+    let ix = WithdrawMany {
+        unstaker_account: env.staker,
+        state_account: env.state,
+        unstaker_orca_ata: env.staker_orca_ata,
+        vault_account: env.vault,
+        orca_mint_account: ORCA_ID,
+        system_program_account: SYSTEM_PROGRAM_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+    }
+    .instruction_with_withdraw_indexes(withdraw_indexes)
+    .unwrap();
+    env.ctx.sends(&[ix])
+}
+
 pub fn do_unstake(
     env: &mut Env,
     withdraw_index: u8,
@@ -95,6 +273,8 @@ pub fn do_unstake_with_unique(
         orca_mint_account: ORCA_ID,
         system_program_account: SYSTEM_PROGRAM_ID,
         token_program_account: TOKEN_PROGRAM_ID,
+        rent_payer_account: None,
+        staker_account_pda: None,
     }
     .instruction(UnstakeInstructionArgs {
         xorca_unstake_amount,
@@ -107,6 +287,179 @@ pub fn do_unstake_with_unique(
     env.ctx.sends(&[ix_unstake, noop_ix])
 }
 
+pub fn do_unstake_with_slippage(
+    env: &mut Env,
+    withdraw_index: u8,
+    xorca_unstake_amount: u64,
+    min_orca_out: u64,
+    deadline: Option<i64>,
+) -> TransactionResult {
+    let pending_withdraw_account = find_pending_withdraw_pda(&env.staker, &withdraw_index)
+        .unwrap()
+        .0;
+    let ix = UnstakeWithSlippage {
+        unstaker_account: env.staker,
+        state_account: env.state,
+        vault_account: env.vault,
+        pending_withdraw_account,
+        unstaker_xorca_ata: env.staker_xorca_ata,
+        xorca_mint_account: XORCA_ID,
+        orca_mint_account: ORCA_ID,
+        system_program_account: SYSTEM_PROGRAM_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+        rent_payer_account: None,
+        staker_account_pda: None,
+    }
+    .instruction(UnstakeWithSlippageInstructionArgs {
+        xorca_unstake_amount,
+        withdraw_index,
+        min_orca_out,
+        deadline,
+    });
+    env.ctx.sends(&[ix])
+}
+
+pub fn do_unstake_for_exact_orca(
+    env: &mut Env,
+    withdraw_index: u8,
+    orca_out: u64,
+    max_xorca_in: u64,
+) -> TransactionResult {
+    let pending_withdraw_account = find_pending_withdraw_pda(&env.staker, &withdraw_index)
+        .unwrap()
+        .0;
+    let ix = UnstakeForExactOrca {
+        unstaker_account: env.staker,
+        state_account: env.state,
+        vault_account: env.vault,
+        pending_withdraw_account,
+        unstaker_xorca_ata: env.staker_xorca_ata,
+        xorca_mint_account: XORCA_ID,
+        orca_mint_account: ORCA_ID,
+        system_program_account: SYSTEM_PROGRAM_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+        rent_payer_account: None,
+        staker_account_pda: None,
+    }
+    .instruction(UnstakeForExactOrcaInstructionArgs {
+        orca_out,
+        max_xorca_in,
+        withdraw_index,
+    });
+    env.ctx.sends(&[ix])
+}
+
+pub fn do_instant_unstake(env: &mut Env, xorca_unstake_amount: u64) -> TransactionResult {
+    do_instant_unstake_with_unique(env, xorca_unstake_amount, 0)
+}
+
+pub fn do_instant_unstake_with_unique(
+    env: &mut Env,
+    xorca_unstake_amount: u64,
+    unique_id: u64,
+) -> TransactionResult {
+    let ix = InstantUnstake {
+        unstaker_account: env.staker,
+        state_account: env.state,
+        vault_account: env.vault,
+        unstaker_xorca_ata: env.staker_xorca_ata,
+        unstaker_orca_ata: env.staker_orca_ata,
+        xorca_mint_account: XORCA_ID,
+        orca_mint_account: ORCA_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+    }
+    .instruction(InstantUnstakeInstructionArgs {
+        xorca_unstake_amount,
+    });
+
+    // Add a unique no-op instruction to make each transaction unique
+    let noop_ix = system_instruction::transfer(&env.staker, &env.staker, unique_id);
+
+    env.ctx.sends(&[ix, noop_ix])
+}
+
+pub fn do_cancel_unstake(env: &mut Env, withdraw_index: u8) -> TransactionResult {
+    do_cancel_unstake_with_unique(env, withdraw_index, 0)
+}
+
+pub fn do_cancel_unstake_with_unique(
+    env: &mut Env,
+    withdraw_index: u8,
+    unique_id: u64,
+) -> TransactionResult {
+    let pending_withdraw_account = find_pending_withdraw_pda(&env.staker, &withdraw_index)
+        .unwrap()
+        .0;
+    let ix = CancelUnstake {
+        unstaker_account: env.staker,
+        state_account: env.state,
+        pending_withdraw_account,
+        unstaker_xorca_ata: env.staker_xorca_ata,
+        xorca_mint_account: XORCA_ID,
+        orca_mint_account: ORCA_ID,
+        vault_account: env.vault,
+        token_program_account: TOKEN_PROGRAM_ID,
+    }
+    .instruction(CancelUnstakeInstructionArgs { withdraw_index });
+
+    // Add a unique no-op instruction to make each transaction unique
+    let noop_ix = system_instruction::transfer(&env.staker, &env.staker, unique_id);
+
+    env.ctx.sends(&[ix, noop_ix])
+}
+
+pub fn do_merge_pending_withdraws(
+    env: &mut Env,
+    withdraw_index: u8,
+    source_withdraw_indices: &[u8],
+) -> TransactionResult {
+    let pending_withdraw_account = find_pending_withdraw_pda(&env.staker, &withdraw_index)
+        .unwrap()
+        .0;
+    let source_pending_withdraw_accounts: Vec<AccountMeta> = source_withdraw_indices
+        .iter()
+        .map(|idx| {
+            AccountMeta::new(
+                find_pending_withdraw_pda(&env.staker, idx).unwrap().0,
+                false,
+            )
+        })
+        .collect();
+    let ix = MergePendingWithdraws {
+        unstaker_account: env.staker,
+        state_account: env.state,
+        pending_withdraw_account,
+    }
+    .instruction_with_remaining_accounts(
+        MergePendingWithdrawsInstructionArgs { withdraw_index },
+        &source_pending_withdraw_accounts,
+    );
+    env.ctx.sends(&[ix])
+}
+
+pub fn do_apply_pending_config(env: &mut Env) -> TransactionResult {
+    let ix = ApplyPendingConfig {
+        state_account: env.state,
+    }
+    .instruction();
+    env.ctx.sends(&[ix])
+}
+
+/// Migrates the state and the given accounts, signing as the staker, so the state's
+/// update authority must be set to the staker first.
+pub fn do_migrate(env: &mut Env, accounts: &[Pubkey]) -> TransactionResult {
+    let remaining_accounts: Vec<AccountMeta> = accounts
+        .iter()
+        .map(|account| AccountMeta::new(*account, false))
+        .collect();
+    let ix = Migrate {
+        update_authority_account: env.staker,
+        state_account: env.state,
+    }
+    .instruction_with_remaining_accounts(&remaining_accounts);
+    env.ctx.sends(&[ix])
+}
+
 pub fn advance_clock_env(env: &mut Env, advance_secs: i64) {
     if advance_secs == 0 {
         return;
@@ -145,6 +498,134 @@ pub fn stake_orca_with_unique(
     env.ctx.sends(&[ix, noop_ix])
 }
 
+pub fn stake_orca_for(
+    env: &mut Env,
+    beneficiary_xorca_ata: Pubkey,
+    orca_amount: u64,
+) -> TransactionResult {
+    let ix = StakeFor {
+        staker_account: env.staker,
+        state_account: env.state,
+        vault_account: env.vault,
+        staker_orca_ata: env.staker_orca_ata,
+        beneficiary_xorca_ata,
+        xorca_mint_account: XORCA_ID,
+        orca_mint_account: ORCA_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+    }
+    .instruction(StakeForInstructionArgs {
+        orca_stake_amount: orca_amount,
+    });
+    env.ctx.sends(&[ix])
+}
+
+pub fn stake_orca_with_slippage(
+    env: &mut Env,
+    orca_amount: u64,
+    min_xorca_out: u64,
+    deadline: Option<i64>,
+) -> TransactionResult {
+    let ix = StakeWithSlippage {
+        staker_account: env.staker,
+        state_account: env.state,
+        vault_account: env.vault,
+        staker_orca_ata: env.staker_orca_ata,
+        staker_xorca_ata: env.staker_xorca_ata,
+        xorca_mint_account: XORCA_ID,
+        orca_mint_account: ORCA_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+    }
+    .instruction(StakeWithSlippageInstructionArgs {
+        orca_stake_amount: orca_amount,
+        min_xorca_out,
+        deadline,
+    });
+    env.ctx.sends(&[ix])
+}
+
+pub fn stake_orca_for_exact_xorca(
+    env: &mut Env,
+    xorca_out: u64,
+    max_orca_in: u64,
+) -> TransactionResult {
+    let ix = StakeForExactXorca {
+        staker_account: env.staker,
+        state_account: env.state,
+        vault_account: env.vault,
+        staker_orca_ata: env.staker_orca_ata,
+        staker_xorca_ata: env.staker_xorca_ata,
+        xorca_mint_account: XORCA_ID,
+        orca_mint_account: ORCA_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+    }
+    .instruction(StakeForExactXorcaInstructionArgs {
+        xorca_out,
+        max_orca_in,
+    });
+    env.ctx.sends(&[ix])
+}
+
+pub fn do_deposit_rewards(env: &mut Env, amount: u64, vesting_period_s: i64) -> TransactionResult {
+    do_deposit_rewards_with_unique(env, amount, vesting_period_s, 0)
+}
+
+pub fn do_deposit_rewards_with_unique(
+    env: &mut Env,
+    amount: u64,
+    vesting_period_s: i64,
+    unique_id: u64,
+) -> TransactionResult {
+    let ix = DepositRewards {
+        depositor_account: env.staker,
+        state_account: env.state,
+        depositor_orca_ata: env.staker_orca_ata,
+        vault_account: env.vault,
+        orca_mint_account: ORCA_ID,
+        xorca_mint_account: XORCA_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+    }
+    .instruction(DepositRewardsInstructionArgs {
+        amount,
+        vesting_period_s,
+    });
+
+    // Add a unique no-op instruction to make each transaction unique
+    let noop_ix = system_instruction::transfer(&env.staker, &env.staker, unique_id);
+
+    env.ctx.sends(&[ix, noop_ix])
+}
+
+pub fn do_harvest(
+    env: &mut Env,
+    treasury: Pubkey,
+    treasury_xorca_ata: Pubkey,
+) -> TransactionResult {
+    do_harvest_with_unique(env, treasury, treasury_xorca_ata, 0)
+}
+
+pub fn do_harvest_with_unique(
+    env: &mut Env,
+    treasury: Pubkey,
+    treasury_xorca_ata: Pubkey,
+    unique_id: u64,
+) -> TransactionResult {
+    let ix = Harvest {
+        state_account: env.state,
+        vault_account: env.vault,
+        xorca_mint_account: XORCA_ID,
+        treasury_account: treasury,
+        treasury_xorca_ata,
+        orca_mint_account: ORCA_ID,
+        token_program_account: TOKEN_PROGRAM_ID,
+    }
+    .instruction();
+
+    // Add a unique no-op instruction to make each transaction unique
+    let noop_ix = system_instruction::transfer(&env.staker, &env.staker, unique_id);
+
+    env.ctx.sends(&[ix, noop_ix])
+}
+
 pub fn deposit_yield_into_vault(env: &mut Env, orca_amount: u64, label: &str) {
     let before = env
         .ctx
@@ -1,7 +1,7 @@
 use crate::{
     cpi::token::TokenAccount,
     error::ErrorCode,
-    state::{AccountDiscriminator, ProgramAccount},
+    state::{pending_withdraw::LEGACY_PENDING_WITHDRAW_LEN, AccountDiscriminator, ProgramAccount},
 };
 use base58::ToBase58;
 use borsh::BorshDeserialize;
@@ -130,8 +130,9 @@ pub fn assert_account_address(account: &impl Key, address: &Pubkey) -> ProgramRe
 pub fn assert_account_data<T: ProgramAccount>(
     account: &AccountInfo,
 ) -> Result<Ref<'_, T>, ProgramError> {
-    assert_account_len(account, T::LEN)?;
+    assert_program_account_len::<T>(account)?;
     assert_account_discriminator(account, &[T::DISCRIMINATOR])?;
+    assert_account_version::<T>(account)?;
 
     let data = account.try_borrow_data()?;
     Ok(T::from_bytes(data))
@@ -140,17 +141,44 @@ pub fn assert_account_data<T: ProgramAccount>(
 pub fn assert_account_data_mut<T: ProgramAccount>(
     account: &AccountInfo,
 ) -> Result<RefMut<'_, T>, ProgramError> {
-    assert_account_len(account, T::LEN)?;
+    assert_program_account_len::<T>(account)?;
     assert_account_discriminator(account, &[T::DISCRIMINATOR])?;
+    assert_account_version::<T>(account)?;
 
     let data = account.try_borrow_mut_data()?;
     Ok(T::from_bytes_mut(data))
 }
 
+/// Rejects layouts newer than `T::VERSION`. Older versions keep the current field offsets and
+/// are read as-is until Migrate upgrades them.
+pub fn assert_account_version<T: ProgramAccount>(account: &AccountInfo) -> ProgramResult {
+    let version = account.try_borrow_data()?[T::VERSION_OFFSET];
+    if version > T::VERSION {
+        log!(
+            "Account {} has unsupported version {}",
+            account.key().to_base58().as_str(),
+            version,
+        );
+        return Err(ErrorCode::UnsupportedAccountVersion.into());
+    }
+    Ok(())
+}
+
+/// Checks the account length is exactly `T::LEN`. Pending withdraws created before the compact
+/// layout may also be `LEGACY_PENDING_WITHDRAW_LEN` until Migrate shrinks them.
+pub fn assert_program_account_len<T: ProgramAccount>(account: &AccountInfo) -> ProgramResult {
+    if T::DISCRIMINATOR == AccountDiscriminator::PendingWithdraw
+        && account.data_len() == LEGACY_PENDING_WITHDRAW_LEN
+    {
+        return Ok(());
+    }
+    assert_account_len(account, T::LEN)
+}
+
 pub fn assert_account_len(account: &AccountInfo, length: usize) -> ProgramResult {
-    if account.data_len() < length {
+    if account.data_len() != length {
         log!(
-            "Account {} is incorrect size. Expected at least {} but got {}",
+            "Account {} is incorrect size. Expected {} but got {}",
             account.key().to_base58().as_str(),
             length as u64,
             account.data_len() as u64,
@@ -1 +1,3 @@
 pub mod account;
+pub mod staker_account;
+pub mod state;
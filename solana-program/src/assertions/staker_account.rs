@@ -0,0 +1,43 @@
+use crate::{
+    assertions::account::{
+        assert_account_data_mut, assert_account_owner, assert_account_role, AccountRole,
+    },
+    error::ErrorCode,
+    state::{staker_account::StakerAccount, AccountDiscriminator, ProgramAccount},
+};
+use pinocchio::{
+    account_info::{AccountInfo, RefMut},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Asserts that `staker_account` is the writable staker account of `staker` and borrows it.
+pub fn assert_staker_account_mut<'a>(
+    staker_account: &'a AccountInfo,
+    staker: &Pubkey,
+) -> Result<RefMut<'a, StakerAccount>, ProgramError> {
+    assert_account_role(staker_account, &[AccountRole::Writable])?;
+    assert_account_owner(staker_account, &crate::ID)?;
+    let staker_account_data = assert_account_data_mut::<StakerAccount>(staker_account)?;
+    if staker_account_data.staker != *staker {
+        return Err(ErrorCode::InvalidAccountData.into());
+    }
+    StakerAccount::verify_address_with_bump(
+        staker_account,
+        staker,
+        &crate::ID,
+        staker_account_data.bump,
+    )?;
+    Ok(staker_account_data)
+}
+
+/// Whether `account` holds a staker account. Lets instructions taking pending withdraws as
+/// remaining accounts accept staker accounts alongside them.
+pub fn is_staker_account(account: &AccountInfo) -> bool {
+    if !account.is_owned_by(&crate::ID) || account.data_len() < StakerAccount::LEN {
+        return false;
+    }
+    account
+        .try_borrow_data()
+        .is_ok_and(|data| data[0] == AccountDiscriminator::StakerAccount as u8)
+}
@@ -0,0 +1,15 @@
+use crate::{
+    assertions::account::{assert_account_data, assert_account_owner},
+    error::ErrorCode,
+    state::state::State,
+};
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+
+pub fn assert_not_paused(state_account: &AccountInfo, pause_flag: u16) -> ProgramResult {
+    assert_account_owner(state_account, &crate::ID)?;
+    let state = assert_account_data::<State>(state_account)?;
+    if state.is_paused(pause_flag) {
+        return Err(ErrorCode::ProgramPaused.into());
+    }
+    Ok(())
+}
@@ -0,0 +1,39 @@
+//! Addresses of the cluster the program is built for.
+//!
+//! They default to mainnet. A devnet or localnet build sets all three at build time:
+//!
+//! ```sh
+//! XORCA_PROGRAM_ID=<program> XORCA_ORCA_MINT=<orca mint> XORCA_XORCA_MINT=<xorca mint> \
+//!     cargo build-sbf --manifest-path solana-program/Cargo.toml
+//! ```
+//!
+//! The `xorca` client reads the same variables, so crates built in one environment agree.
+
+use pinocchio::pubkey::Pubkey;
+use pinocchio_pubkey::decode_32_const;
+
+pub const MAINNET_PROGRAM_ID: &str = "StaKE6XNKVVhG8Qu9hDJBqCW3eRe7MDGLz17nJZetLT";
+pub const MAINNET_ORCA_MINT: &str = "orcaEKTdK7LKz57vaAYr9QeNsVEPfiu6QeMU1kektZE";
+pub const MAINNET_XORCA_MINT: &str = "xorcaYqbXUNz3474ubUMJAdu2xgPsew3rUCe5ughT3N";
+
+// A partial override would mix clusters, e.g. a devnet program checking mainnet mints
+const _: () = assert!(
+    option_env!("XORCA_PROGRAM_ID").is_some() == option_env!("XORCA_ORCA_MINT").is_some()
+        && option_env!("XORCA_PROGRAM_ID").is_some() == option_env!("XORCA_XORCA_MINT").is_some(),
+    "XORCA_PROGRAM_ID, XORCA_ORCA_MINT and XORCA_XORCA_MINT must be set together"
+);
+
+pub const PROGRAM_ID: Pubkey = decode_32_const(match option_env!("XORCA_PROGRAM_ID") {
+    Some(address) => address,
+    None => MAINNET_PROGRAM_ID,
+});
+
+pub const ORCA_MINT_ID: Pubkey = decode_32_const(match option_env!("XORCA_ORCA_MINT") {
+    Some(address) => address,
+    None => MAINNET_ORCA_MINT,
+});
+
+pub const XORCA_MINT_ID: Pubkey = decode_32_const(match option_env!("XORCA_XORCA_MINT") {
+    Some(address) => address,
+    None => MAINNET_XORCA_MINT,
+});
@@ -0,0 +1,16 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::pubkey::Pubkey;
+
+// UpgradeableLoaderState variant tag of a ProgramData account
+pub const PROGRAM_DATA_ACCOUNT_TYPE: u32 = 3;
+
+/// Metadata at the start of a BPF Upgradeable Loader `ProgramData` account. The loader
+/// serializes it with bincode, whose enum tag (u32) and `Option` flag (u8) read the same as
+/// the fields below. The program ELF follows.
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct ProgramData {
+    pub account_type: u32,
+    pub slot: u64,
+    pub upgrade_authority_flag: u8,
+    pub upgrade_authority: Pubkey,
+}
@@ -1,2 +1,3 @@
+pub mod loader;
 pub mod system;
 pub mod token;
@@ -1,8 +1,4 @@
-use pinocchio::pubkey::Pubkey;
-use pinocchio_pubkey::pubkey;
-
 mod state;
 pub use state::*;
 
-pub const ORCA_MINT_ID: Pubkey = pubkey!("orcaEKTdK7LKz57vaAYr9QeNsVEPfiu6QeMU1kektZE");
-pub const XORCA_MINT_ID: Pubkey = pubkey!("xorcaYqbXUNz3474ubUMJAdu2xgPsew3rUCe5ughT3N");
+pub use crate::cluster::{ORCA_MINT_ID, XORCA_MINT_ID};
@@ -4,7 +4,7 @@ use pinocchio_log::log;
 
 use crate::{
     error::ErrorCode,
-    instructions::{self, Instruction},
+    instructions::{self, withdraw::WithdrawMode, Instruction},
 };
 
 #[cfg(target_os = "solana")]
@@ -30,7 +30,13 @@ pub fn process_instruction(
             instructions::initialize::process_instruction(accounts, cool_down_period_s)?;
         }
         Instruction::Stake { orca_stake_amount } => {
-            instructions::stake::process_instruction(accounts, orca_stake_amount)?;
+            instructions::stake::process_instruction(
+                accounts,
+                orca_stake_amount,
+                &0,
+                &None,
+                false,
+            )?;
         }
         Instruction::Unstake {
             xorca_unstake_amount,
@@ -40,14 +46,141 @@ pub fn process_instruction(
                 accounts,
                 xorca_unstake_amount,
                 withdraw_index,
+                &0,
+                &None,
             )?;
         }
         Instruction::Withdraw { withdraw_index } => {
-            instructions::withdraw::process_instruction(accounts, withdraw_index)?;
+            instructions::withdraw::process_instruction(
+                accounts,
+                withdraw_index,
+                WithdrawMode::Unstaker,
+            )?;
         }
         Instruction::Set { instruction_data } => {
             instructions::set::process_instruction(accounts, instruction_data)?;
         }
+        Instruction::InstantUnstake {
+            xorca_unstake_amount,
+        } => {
+            instructions::instant_unstake::process_instruction(accounts, xorca_unstake_amount)?;
+        }
+        Instruction::CancelUnstake { withdraw_index } => {
+            instructions::cancel_unstake::process_instruction(accounts, withdraw_index)?;
+        }
+        Instruction::WithdrawPartial {
+            withdraw_index,
+            orca_withdraw_amount,
+        } => {
+            instructions::withdraw_partial::process_instruction(
+                accounts,
+                withdraw_index,
+                orca_withdraw_amount,
+            )?;
+        }
+        Instruction::MergePendingWithdraws { withdraw_index } => {
+            instructions::merge_pending_withdraws::process_instruction(accounts, withdraw_index)?;
+        }
+        Instruction::WithdrawMany => {
+            instructions::withdraw_many::process_instruction(accounts)?;
+        }
+        Instruction::StakeWithSlippage {
+            orca_stake_amount,
+            min_xorca_out,
+            deadline,
+        } => {
+            instructions::stake::process_instruction(
+                accounts,
+                orca_stake_amount,
+                min_xorca_out,
+                deadline,
+                false,
+            )?;
+        }
+        Instruction::UnstakeWithSlippage {
+            xorca_unstake_amount,
+            withdraw_index,
+            min_orca_out,
+            deadline,
+        } => {
+            instructions::unstake::process_instruction(
+                accounts,
+                xorca_unstake_amount,
+                withdraw_index,
+                min_orca_out,
+                deadline,
+            )?;
+        }
+        Instruction::StakeForExactXorca {
+            xorca_out,
+            max_orca_in,
+        } => {
+            instructions::stake_for_exact_xorca::process_instruction(
+                accounts,
+                xorca_out,
+                max_orca_in,
+            )?;
+        }
+        Instruction::UnstakeForExactOrca {
+            orca_out,
+            max_xorca_in,
+            withdraw_index,
+        } => {
+            instructions::unstake_for_exact_orca::process_instruction(
+                accounts,
+                orca_out,
+                max_xorca_in,
+                withdraw_index,
+            )?;
+        }
+        Instruction::AcceptUpdateAuthority => {
+            instructions::accept_update_authority::process_instruction(accounts)?;
+        }
+        Instruction::ApplyPendingConfig => {
+            instructions::apply_pending_config::process_instruction(accounts)?;
+        }
+        Instruction::DepositRewards {
+            amount,
+            vesting_period_s,
+        } => {
+            instructions::deposit_rewards::process_instruction(accounts, amount, vesting_period_s)?;
+        }
+        Instruction::Harvest => {
+            instructions::harvest::process_instruction(accounts)?;
+        }
+        Instruction::StakeFor { orca_stake_amount } => {
+            instructions::stake::process_instruction(accounts, orca_stake_amount, &0, &None, true)?;
+        }
+        Instruction::WithdrawTo { withdraw_index } => {
+            instructions::withdraw::process_instruction(
+                accounts,
+                withdraw_index,
+                WithdrawMode::To,
+            )?;
+        }
+        Instruction::CrankWithdraw { withdraw_index } => {
+            instructions::withdraw::process_instruction(
+                accounts,
+                withdraw_index,
+                WithdrawMode::Crank,
+            )?;
+        }
+        Instruction::TransferPendingWithdraw {
+            withdraw_index,
+            new_owner,
+        } => {
+            instructions::transfer_pending_withdraw::process_instruction(
+                accounts,
+                withdraw_index,
+                new_owner,
+            )?;
+        }
+        Instruction::InitializeStakerAccount => {
+            instructions::initialize_staker_account::process_instruction(accounts)?;
+        }
+        Instruction::Migrate => {
+            instructions::migrate::process_instruction(accounts)?;
+        }
     }
     Ok(())
 }
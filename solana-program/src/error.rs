@@ -40,7 +40,7 @@ pub enum ErrorCode {
 
     #[error("Event serialization failed")]
     EmitEventError = 6012, // 0x177C
-    #[error("Invalid cooldown period: must be non-negative")]
+    #[error("Invalid cooldown period: must be non-negative and not exceed the maximum")]
     InvalidCoolDownPeriod = 6013, // 0x177D
 
     #[error("Stake amount too small to mint any xORCA")]
@@ -60,6 +60,63 @@ pub enum ErrorCode {
 
     #[error("Unauthorized deployer access")]
     UnauthorizedDeployerAccess = 6019, // 0x1783
+
+    #[error("Invalid instant unstake fee: must not exceed 10000 bps")]
+    InvalidInstantUnstakeFee = 6020, // 0x1784
+
+    #[error("Invalid withdraw amount: must be non-zero and not exceed the pending withdraw")]
+    InvalidWithdrawAmount = 6021, // 0x1785
+
+    #[error("Slippage exceeded: output is below the requested minimum")]
+    SlippageExceeded = 6022, // 0x1786
+
+    #[error("Transaction deadline exceeded")]
+    DeadlineExceeded = 6023, // 0x1787
+
+    #[error("Program is paused for this instruction")]
+    ProgramPaused = 6024, // 0x1788
+
+    #[error("No pending config change to apply")]
+    NoPendingConfig = 6025, // 0x1789
+
+    #[error("Pending config change is still timelocked")]
+    ConfigTimelockActive = 6026, // 0x178A
+
+    #[error("Invalid vesting period: must be non-negative and not exceed the maximum")]
+    InvalidVestingPeriod = 6027, // 0x178B
+
+    #[error("Invalid rewards amount: must be non-zero")]
+    InvalidRewardsAmount = 6028, // 0x178C
+
+    #[error("Invalid performance fee: must not exceed 10000 bps")]
+    InvalidPerformanceFee = 6029, // 0x178D
+
+    #[error("Stake would exceed the vault ORCA cap")]
+    MaxVaultOrcaExceeded = 6030, // 0x178E
+
+    #[error("Stake amount exceeds the per-transaction cap")]
+    MaxStakePerTxExceeded = 6031, // 0x178F
+
+    #[error("Stake amount is below the configured minimum")]
+    StakeAmountBelowMinimum = 6032, // 0x1790
+
+    #[error("Unstake amount is below the configured minimum")]
+    UnstakeAmountBelowMinimum = 6033, // 0x1791
+
+    #[error("Signer does not own the pending withdraw")]
+    InvalidPendingWithdrawOwner = 6034, // 0x1792
+
+    #[error("Pending withdraw is indexed by a staker account that was not provided")]
+    StakerAccountRequired = 6035, // 0x1793
+
+    #[error("Account layout version is not supported by this program")]
+    UnsupportedAccountVersion = 6036, // 0x1794
+
+    #[error("Invalid stake caps: the per-transaction cap must not exceed the vault ORCA cap")]
+    InvalidStakeCaps = 6037, // 0x1795
+
+    #[error("Instruction is deprecated and no longer supported")]
+    DeprecatedInstruction = 6038, // 0x1796
 }
 
 impl From<ErrorCode> for pinocchio::program_error::ProgramError {
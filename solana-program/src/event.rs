@@ -11,6 +11,8 @@ pub enum Event<'a> {
         vault_escrowed_orca_amount: &'a u64,
         xorca_mint_supply: &'a u64,
         xorca_to_mint: &'a u64,
+        payer: &'a Pubkey,
+        beneficiary: &'a Pubkey,
     },
     Unstake {
         xorca_unstake_amount: &'a u64,
@@ -20,17 +22,137 @@ pub enum Event<'a> {
         withdrawable_orca_amount: &'a u64,
         cool_down_period_s: &'a i64,
         withdraw_index: &'a u8,
+        staker_withdraw_index: &'a Option<u64>,
     },
     Withdraw {
         vault_escrowed_orca_amount: &'a u64,
         withdrawable_orca_amount: &'a u64,
         cool_down_period_s: &'a i64,
         withdraw_index: &'a u8,
+        destination: &'a Pubkey,
+        staker_withdraw_index: &'a Option<u64>,
     },
     UpdateAuthoritySet {
         new_authority: &'a Pubkey,
         set_by: &'a Pubkey,
     },
+    InstantUnstake {
+        xorca_unstake_amount: &'a u64,
+        vault_orca_amount: &'a u64,
+        vault_escrowed_orca_amount: &'a u64,
+        xorca_mint_supply: &'a u64,
+        orca_unstake_amount: &'a u64,
+        fee_orca_amount: &'a u64,
+        instant_unstake_fee_bps: &'a u16,
+    },
+    CancelUnstake {
+        withdrawable_orca_amount: &'a u64,
+        vault_orca_amount: &'a u64,
+        vault_escrowed_orca_amount: &'a u64,
+        xorca_mint_supply: &'a u64,
+        xorca_to_mint: &'a u64,
+        withdraw_index: &'a u8,
+    },
+    WithdrawPartial {
+        vault_escrowed_orca_amount: &'a u64,
+        orca_withdraw_amount: &'a u64,
+        remaining_orca_amount: &'a u64,
+        withdraw_index: &'a u8,
+    },
+    MergePendingWithdraws {
+        withdrawable_orca_amount: &'a u64,
+        withdrawable_timestamp: &'a i64,
+        merged_count: &'a u16,
+        withdraw_index: &'a u8,
+    },
+    WithdrawMany {
+        vault_escrowed_orca_amount: &'a u64,
+        withdrawable_orca_amount: &'a u64,
+        withdraw_count: &'a u16,
+    },
+    PauseFlagsSet {
+        new_pause_flags: &'a u16,
+        old_pause_flags: &'a u16,
+        set_by: &'a Pubkey,
+    },
+    UpdateAuthorityProposed {
+        proposed_authority: &'a Pubkey,
+        proposed_by: &'a Pubkey,
+    },
+    CoolDownPeriodChangeQueued {
+        new_cool_down_period_s: &'a i64,
+        activation_timestamp: &'a i64,
+        queued_by: &'a Pubkey,
+    },
+    PendingConfigApplied {
+        old_cool_down_period_s: &'a i64,
+        new_cool_down_period_s: &'a i64,
+    },
+    RewardsDeposited {
+        rewards_amount: &'a u64,
+        vault_orca_amount: &'a u64,
+        vesting_rewards_amount: &'a u64,
+        vesting_end_timestamp: &'a i64,
+        total_rewards_deposited: &'a u64,
+        non_escrowed_orca_amount: &'a u64,
+        xorca_mint_supply: &'a u64,
+    },
+    RewardsAuthoritySet {
+        new_rewards_authority: &'a Pubkey,
+        set_by: &'a Pubkey,
+    },
+    FeeMinted {
+        yield_orca_amount: &'a u64,
+        fee_orca_amount: &'a u64,
+        fee_xorca_amount: &'a u64,
+        xorca_mint_supply: &'a u64,
+        treasury: &'a Pubkey,
+    },
+    PendingWithdrawTransferred {
+        unstaker: &'a Pubkey,
+        withdraw_index: &'a u8,
+        previous_owner: &'a Pubkey,
+        new_owner: &'a Pubkey,
+    },
+    AccountsMigrated {
+        migrated_count: &'a u16,
+        migrated_by: &'a Pubkey,
+    },
+    InstantUnstakeFeeSet {
+        new_fee_bps: &'a u16,
+        old_fee_bps: &'a u16,
+        set_by: &'a Pubkey,
+    },
+    PerformanceFeeSet {
+        new_fee_bps: &'a u16,
+        old_fee_bps: &'a u16,
+        set_by: &'a Pubkey,
+    },
+    TreasurySet {
+        new_treasury: &'a Pubkey,
+        old_treasury: &'a Pubkey,
+        set_by: &'a Pubkey,
+    },
+    MaxVaultOrcaSet {
+        new_max_vault_orca: &'a u64,
+        old_max_vault_orca: &'a u64,
+        set_by: &'a Pubkey,
+    },
+    MaxStakePerTxSet {
+        new_max_stake_per_tx: &'a u64,
+        old_max_stake_per_tx: &'a u64,
+        set_by: &'a Pubkey,
+    },
+    MinStakeOrcaSet {
+        new_min_stake_orca: &'a u64,
+        old_min_stake_orca: &'a u64,
+        set_by: &'a Pubkey,
+    },
+    MinUnstakeXorcaSet {
+        new_min_unstake_xorca: &'a u64,
+        old_min_unstake_xorca: &'a u64,
+        set_by: &'a Pubkey,
+    },
 }
 
 pub fn sol_log_data(data: &[&[u8]]) {
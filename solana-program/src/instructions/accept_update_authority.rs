@@ -0,0 +1,47 @@
+use crate::{
+    assertions::account::{
+        assert_account_address, assert_account_data_mut, assert_account_owner, assert_account_role,
+        AccountRole,
+    },
+    error::ErrorCode,
+    event::Event,
+    state::state::State,
+    util::account::get_account_info,
+};
+use pinocchio::{account_info::AccountInfo, pubkey::Pubkey, ProgramResult};
+
+pub fn process_instruction(accounts: &[AccountInfo]) -> ProgramResult {
+    let pending_update_authority_account = get_account_info(accounts, 0)?;
+    let state_account = get_account_info(accounts, 1)?;
+
+    // 1. Pending Update Authority Account Assertions
+    assert_account_role(
+        pending_update_authority_account,
+        &[AccountRole::Signer, AccountRole::Writable],
+    )?;
+
+    // 2. xOrca State Account Assertions
+    assert_account_role(state_account, &[AccountRole::Writable])?;
+    assert_account_owner(state_account, &crate::ID)?;
+    let mut state_view = assert_account_data_mut::<State>(state_account)?;
+    State::verify_address_with_bump(state_account, &crate::ID, state_view.bump)
+        .map_err(|_| ErrorCode::InvalidSeeds)?;
+    // The signer must be the proposed authority. With no pending proposal the stored key is
+    // Pubkey::default(), which can never sign.
+    assert_account_address(
+        pending_update_authority_account,
+        &state_view.pending_update_authority,
+    )?;
+
+    let old_authority = state_view.update_authority;
+    state_view.update_authority = *pending_update_authority_account.key();
+    state_view.pending_update_authority = Pubkey::default();
+
+    Event::UpdateAuthoritySet {
+        new_authority: pending_update_authority_account.key(),
+        set_by: &old_authority,
+    }
+    .emit()?;
+
+    Ok(())
+}
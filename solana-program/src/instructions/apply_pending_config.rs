@@ -0,0 +1,44 @@
+use crate::{
+    assertions::account::{
+        assert_account_data_mut, assert_account_owner, assert_account_role, AccountRole,
+    },
+    cpi::system::get_current_unix_timestamp,
+    error::ErrorCode,
+    event::Event,
+    state::state::State,
+    util::account::get_account_info,
+};
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+
+pub fn process_instruction(accounts: &[AccountInfo]) -> ProgramResult {
+    let state_account = get_account_info(accounts, 0)?;
+
+    // 1. xOrca State Account Assertions
+    assert_account_role(state_account, &[AccountRole::Writable])?;
+    assert_account_owner(state_account, &crate::ID)?;
+    let mut state_view = assert_account_data_mut::<State>(state_account)?;
+    State::verify_address_with_bump(state_account, &crate::ID, state_view.bump)
+        .map_err(|_| ErrorCode::InvalidSeeds)?;
+
+    // 2. Pending Config Assertions
+    if state_view.pending_config_activation_timestamp == 0 {
+        return Err(ErrorCode::NoPendingConfig.into());
+    }
+    if get_current_unix_timestamp()? < state_view.pending_config_activation_timestamp {
+        return Err(ErrorCode::ConfigTimelockActive.into());
+    }
+
+    // Anyone can apply the queued config once the timelock has passed
+    let old_cool_down_period_s = state_view.cool_down_period_s;
+    state_view.cool_down_period_s = state_view.pending_cool_down_period_s;
+    state_view.pending_cool_down_period_s = 0;
+    state_view.pending_config_activation_timestamp = 0;
+
+    Event::PendingConfigApplied {
+        old_cool_down_period_s: &old_cool_down_period_s,
+        new_cool_down_period_s: &state_view.cool_down_period_s,
+    }
+    .emit()?;
+
+    Ok(())
+}
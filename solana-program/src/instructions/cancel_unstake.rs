@@ -0,0 +1,198 @@
+use crate::{
+    assertions::account::{
+        assert_account_address, assert_account_data, assert_account_data_mut, assert_account_owner,
+        assert_account_role, assert_external_account_data, make_owner_token_account_assertions,
+        AccountRole,
+    },
+    assertions::state::assert_not_paused,
+    cpi::{
+        system::get_current_unix_timestamp,
+        token::{TokenMint, ORCA_MINT_ID, XORCA_MINT_ID},
+    },
+    error::ErrorCode,
+    event::Event,
+    state::{
+        pending_withdraw::PendingWithdraw,
+        state::{State, PAUSE_CANCEL_UNSTAKE, PAUSE_STAKE},
+    },
+    util::{
+        account::{close_program_account, get_account_info},
+        math::convert_orca_to_xorca,
+        staker_account::record_staker_settlement,
+    },
+};
+use pinocchio::{account_info::AccountInfo, instruction::Seed, ProgramResult};
+use pinocchio_token::{instructions::MintTo, ID as SPL_TOKEN_PROGRAM_ID};
+
+pub fn process_instruction(accounts: &[AccountInfo], withdraw_index: &u8) -> ProgramResult {
+    let unstaker_account = get_account_info(accounts, 0)?;
+    let state_account = get_account_info(accounts, 1)?;
+    let pending_withdraw_account = get_account_info(accounts, 2)?;
+    let unstaker_xorca_ata = get_account_info(accounts, 3)?;
+    let xorca_mint_account = get_account_info(accounts, 4)?;
+    let orca_mint_account = get_account_info(accounts, 5)?;
+    let vault_account = get_account_info(accounts, 6)?;
+    let token_program_account = get_account_info(accounts, 7)?;
+    // Optional staker account, required when the pending withdraw is staker-indexed
+    let staker_accounts = accounts.get(8..).unwrap_or_default();
+
+    // 0. Pause Assertions
+    // Cancelling stakes the pending amount again, so it is also paused with staking
+    assert_not_paused(state_account, PAUSE_CANCEL_UNSTAKE)?;
+    assert_not_paused(state_account, PAUSE_STAKE)?;
+
+    // 1. Unstaker Account Assertions
+    assert_account_role(
+        unstaker_account,
+        &[AccountRole::Signer, AccountRole::Writable],
+    )?;
+
+    // 2. Account Address Assertions
+    assert_account_address(xorca_mint_account, &XORCA_MINT_ID)?;
+    assert_account_address(orca_mint_account, &ORCA_MINT_ID)?;
+    assert_account_address(token_program_account, &SPL_TOKEN_PROGRAM_ID)?;
+
+    // 3. xOrca State Account Assertions
+    assert_account_role(state_account, &[AccountRole::Writable])?;
+    assert_account_owner(state_account, &crate::ID)?;
+    let mut state_seeds = State::seeds();
+    let (state_bump_value, initial_escrowed_orca_amount, unvested_rewards_amount) = {
+        let state_view = assert_account_data::<State>(state_account)?;
+
+        State::verify_address_with_bump(state_account, &crate::ID, state_view.bump)
+            .map_err(|_| ErrorCode::InvalidSeeds)?;
+
+        // Verify vault address using stored vault_bump
+        State::verify_vault_address_with_bump(
+            state_account,
+            vault_account,
+            orca_mint_account,
+            state_view.vault_bump,
+        )
+        .map_err(|_| ErrorCode::InvalidSeeds)?;
+
+        (
+            state_view.bump,
+            state_view.escrowed_orca_amount,
+            state_view.unvested_rewards_amount(get_current_unix_timestamp()?)?,
+        )
+    };
+    let bump_bytes = [state_bump_value];
+    state_seeds.push(Seed::from(&bump_bytes));
+
+    // 4. Pending Withdraw Account Assertions
+    assert_account_role(pending_withdraw_account, &[AccountRole::Writable])?;
+    assert_account_owner(pending_withdraw_account, &crate::ID)?;
+    let withdraw_index_bytes = [*withdraw_index];
+    let (withdrawable_orca_amount, staker_indexed, unstaker) = {
+        let pending_withdraw_data =
+            assert_account_data::<PendingWithdraw>(pending_withdraw_account)?;
+        pending_withdraw_data.verify_address_and_owner(
+            pending_withdraw_account,
+            unstaker_account.key(),
+            &withdraw_index_bytes,
+        )?;
+        (
+            pending_withdraw_data.withdrawable_orca_amount,
+            pending_withdraw_data.is_staker_indexed(),
+            pending_withdraw_data.unstaker,
+        )
+    };
+
+    // 5. Vault Account Assertions
+    let vault_account_data = make_owner_token_account_assertions(
+        vault_account,
+        state_account,
+        orca_mint_account,
+        false,
+    )?;
+
+    // 6. Unstaker xOrca ATA Assertions
+    make_owner_token_account_assertions(
+        unstaker_xorca_ata,
+        unstaker_account,
+        xorca_mint_account,
+        true,
+    )?;
+
+    // 7. xOrca Mint Account Assertions
+    assert_account_owner(xorca_mint_account, &SPL_TOKEN_PROGRAM_ID)?;
+    assert_account_role(xorca_mint_account, &[AccountRole::Writable])?;
+    let xorca_mint_data = assert_external_account_data::<TokenMint>(xorca_mint_account)?;
+    // Enforce xORCA mint authority must be the state and freeze authority must be None
+    if xorca_mint_data.mint_authority_flag == 0
+        || xorca_mint_data.mint_authority != *state_account.key()
+    {
+        return Err(ErrorCode::InvalidAccountData.into());
+    }
+    if xorca_mint_data.freeze_authority_flag != 0 {
+        return Err(ErrorCode::InvalidAccountData.into());
+    }
+
+    // 8. Orca Mint Account Assertions
+    assert_account_owner(orca_mint_account, &SPL_TOKEN_PROGRAM_ID)?;
+
+    // Calculate xOrca to mint as if the pending amount were staked again at the current rate
+    let non_escrowed_orca_amount = vault_account_data
+        .amount
+        .checked_sub(initial_escrowed_orca_amount)
+        .and_then(|amount| amount.checked_sub(unvested_rewards_amount))
+        .ok_or(ErrorCode::InsufficientVaultBacking)?;
+    let xorca_to_mint = convert_orca_to_xorca(
+        withdrawable_orca_amount,
+        non_escrowed_orca_amount,
+        xorca_mint_data.supply,
+    )?;
+    if xorca_to_mint == 0 {
+        return Err(ErrorCode::InsufficientStakeAmount.into());
+    }
+
+    // The pending amount is staked again: it must meet the minimum stake and, as if withdrawn
+    // and deposited back, keep the vault within the deposit caps
+    let vault_orca_amount_after_withdraw = vault_account_data
+        .amount
+        .checked_sub(withdrawable_orca_amount)
+        .ok_or(ErrorCode::InsufficientVaultBacking)?;
+    assert_account_data::<State>(state_account)?
+        .assert_stake_limits(vault_orca_amount_after_withdraw, withdrawable_orca_amount)?;
+
+    // Remove tokens from escrow
+    let final_escrowed_orca_amount = {
+        let mut state = assert_account_data_mut::<State>(state_account)?;
+        state.escrowed_orca_amount = state
+            .escrowed_orca_amount
+            .checked_sub(withdrawable_orca_amount)
+            .ok_or(ErrorCode::InsufficientEscrow)?;
+        state.escrowed_orca_amount
+    };
+
+    // Mint xOrca to unstaker xOrca ATA
+    let mint_to_instruction = MintTo {
+        mint: xorca_mint_account,
+        account: unstaker_xorca_ata,
+        mint_authority: state_account,
+        amount: xorca_to_mint,
+    };
+    mint_to_instruction.invoke_signed(&[state_seeds.as_slice().into()])?;
+
+    // Close the pending_withdraw account and refund lamports to unstaker
+    close_program_account(pending_withdraw_account, unstaker_account)?;
+    if staker_indexed {
+        // The ORCA is staked again rather than withdrawn
+        record_staker_settlement(staker_accounts, &unstaker, 0, true)?;
+    }
+
+    let final_xorca_supply = xorca_mint_data.supply + xorca_to_mint;
+
+    Event::CancelUnstake {
+        withdrawable_orca_amount: &withdrawable_orca_amount,
+        vault_orca_amount: &vault_account_data.amount,
+        vault_escrowed_orca_amount: &final_escrowed_orca_amount,
+        xorca_mint_supply: &final_xorca_supply,
+        xorca_to_mint: &xorca_to_mint,
+        withdraw_index,
+    }
+    .emit()?;
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use crate::{
+    assertions::{
+        account::{
+            assert_account_address, assert_account_data_mut, assert_account_owner,
+            assert_account_role, assert_external_account_data, make_owner_token_account_assertions,
+            AccountRole,
+        },
+        state::assert_not_paused,
+    },
+    cpi::{
+        system::get_current_unix_timestamp,
+        token::{TokenMint, ORCA_MINT_ID, XORCA_MINT_ID},
+    },
+    error::ErrorCode,
+    event::Event,
+    state::state::{State, MAX_VESTING_PERIOD_S, PAUSE_DEPOSIT_REWARDS},
+    util::account::get_account_info,
+};
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+use pinocchio_token::{instructions::Transfer, ID as SPL_TOKEN_PROGRAM_ID};
+
+pub fn process_instruction(
+    accounts: &[AccountInfo],
+    amount: &u64,
+    vesting_period_s: &i64,
+) -> ProgramResult {
+    let depositor_account = get_account_info(accounts, 0)?;
+    let state_account = get_account_info(accounts, 1)?;
+    let depositor_orca_ata = get_account_info(accounts, 2)?;
+    let vault_account = get_account_info(accounts, 3)?;
+    let orca_mint_account = get_account_info(accounts, 4)?;
+    let xorca_mint_account = get_account_info(accounts, 5)?;
+    let token_program_account = get_account_info(accounts, 6)?;
+
+    // 0. Pause Assertions
+    assert_not_paused(state_account, PAUSE_DEPOSIT_REWARDS)?;
+
+    // 1. Depositor Account Assertions
+    assert_account_role(
+        depositor_account,
+        &[AccountRole::Signer, AccountRole::Writable],
+    )?;
+
+    // 2. Account Address Assertions
+    assert_account_address(orca_mint_account, &ORCA_MINT_ID)?;
+    assert_account_address(xorca_mint_account, &XORCA_MINT_ID)?;
+    assert_account_address(token_program_account, &SPL_TOKEN_PROGRAM_ID)?;
+
+    // 3. Argument Assertions
+    if *amount == 0 {
+        return Err(ErrorCode::InvalidRewardsAmount.into());
+    }
+    if *vesting_period_s < 0 || *vesting_period_s > MAX_VESTING_PERIOD_S {
+        return Err(ErrorCode::InvalidVestingPeriod.into());
+    }
+
+    // 4. Depositor Orca ATA Assertions
+    let depositor_orca_ata_data = make_owner_token_account_assertions(
+        depositor_orca_ata,
+        depositor_account,
+        orca_mint_account,
+        true,
+    )?;
+    if depositor_orca_ata_data.amount < *amount {
+        return Err(ErrorCode::InsufficientFunds.into());
+    }
+
+    // 5. xOrca State Account Assertions
+    assert_account_role(state_account, &[AccountRole::Writable])?;
+    assert_account_owner(state_account, &crate::ID)?;
+    let mut state = assert_account_data_mut::<State>(state_account)?;
+    State::verify_address_with_bump(state_account, &crate::ID, state.bump)
+        .map_err(|_| ErrorCode::InvalidSeeds)?;
+    assert_account_address(depositor_account, &state.rewards_authority)?;
+    State::verify_vault_address_with_bump(
+        state_account,
+        vault_account,
+        orca_mint_account,
+        state.vault_bump,
+    )
+    .map_err(|_| ErrorCode::InvalidSeeds)?;
+
+    // 6. Vault Account Assertions
+    let vault_account_data =
+        make_owner_token_account_assertions(vault_account, state_account, orca_mint_account, true)?;
+
+    // 7. xOrca Mint Account Assertions
+    assert_account_owner(xorca_mint_account, &SPL_TOKEN_PROGRAM_ID)?;
+    let xorca_mint_data = assert_external_account_data::<TokenMint>(xorca_mint_account)?;
+
+    // Transfer rewards from depositor ATA to vault
+    let transfer_instruction = Transfer {
+        from: depositor_orca_ata,
+        to: vault_account,
+        authority: depositor_account,
+        amount: *amount,
+    };
+    transfer_instruction.invoke()?;
+
+    // Vest the rewards linearly so the exchange rate cannot be sandwiched
+    let current_unix_timestamp = get_current_unix_timestamp()?;
+    state.add_vesting_rewards(*amount, *vesting_period_s, current_unix_timestamp)?;
+    state.total_rewards_deposited = state
+        .total_rewards_deposited
+        .checked_add(*amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    let final_vault_amount = vault_account_data
+        .amount
+        .checked_add(*amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    // Exchange rate backing after the deposit: non_escrowed_orca_amount / xorca_mint_supply
+    let unvested_rewards_amount = state.unvested_rewards_amount(current_unix_timestamp)?;
+    let non_escrowed_orca_amount = final_vault_amount
+        .checked_sub(state.escrowed_orca_amount)
+        .and_then(|amount| amount.checked_sub(unvested_rewards_amount))
+        .ok_or(ErrorCode::InsufficientVaultBacking)?;
+
+    Event::RewardsDeposited {
+        rewards_amount: amount,
+        vault_orca_amount: &final_vault_amount,
+        vesting_rewards_amount: &state.vesting_rewards_amount,
+        vesting_end_timestamp: &state.vesting_end_timestamp,
+        total_rewards_deposited: &state.total_rewards_deposited,
+        non_escrowed_orca_amount: &non_escrowed_orca_amount,
+        xorca_mint_supply: &xorca_mint_data.supply,
+    }
+    .emit()?;
+
+    Ok(())
+}
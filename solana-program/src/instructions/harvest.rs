@@ -0,0 +1,165 @@
+use crate::{
+    assertions::account::{
+        assert_account_address, assert_account_data, assert_account_data_mut, assert_account_owner,
+        assert_account_role, assert_external_account_data, make_owner_token_account_assertions,
+        AccountRole,
+    },
+    assertions::state::assert_not_paused,
+    cpi::{
+        system::get_current_unix_timestamp,
+        token::{TokenMint, ORCA_MINT_ID, XORCA_MINT_ID},
+    },
+    error::ErrorCode,
+    event::Event,
+    state::state::{State, PAUSE_HARVEST},
+    util::{
+        account::get_account_info,
+        math::{calculate_performance_fee, convert_orca_to_xorca, convert_xorca_to_orca},
+    },
+};
+use pinocchio::{account_info::AccountInfo, instruction::Seed, ProgramResult};
+use pinocchio_token::{instructions::MintTo, ID as SPL_TOKEN_PROGRAM_ID};
+
+pub fn process_instruction(accounts: &[AccountInfo]) -> ProgramResult {
+    let state_account = get_account_info(accounts, 0)?;
+    let vault_account = get_account_info(accounts, 1)?;
+    let xorca_mint_account = get_account_info(accounts, 2)?;
+    let treasury_account = get_account_info(accounts, 3)?;
+    let treasury_xorca_ata = get_account_info(accounts, 4)?;
+    let orca_mint_account = get_account_info(accounts, 5)?;
+    let token_program_account = get_account_info(accounts, 6)?;
+
+    // 0. Pause Assertions
+    assert_not_paused(state_account, PAUSE_HARVEST)?;
+
+    // 1. Account Address Assertions
+    assert_account_address(orca_mint_account, &ORCA_MINT_ID)?;
+    assert_account_address(xorca_mint_account, &XORCA_MINT_ID)?;
+    assert_account_address(token_program_account, &SPL_TOKEN_PROGRAM_ID)?;
+
+    // 2. xOrca State Account Assertions
+    assert_account_role(state_account, &[AccountRole::Writable])?;
+    assert_account_owner(state_account, &crate::ID)?;
+    let (
+        state_bump,
+        escrowed_orca_amount,
+        unvested_rewards_amount,
+        fee_checkpoint_orca_amount,
+        fee_checkpoint_xorca_supply,
+        performance_fee_bps,
+    ) = {
+        let state_view = assert_account_data::<State>(state_account)?;
+        State::verify_address_with_bump(state_account, &crate::ID, state_view.bump)
+            .map_err(|_| ErrorCode::InvalidSeeds)?;
+        State::verify_vault_address_with_bump(
+            state_account,
+            vault_account,
+            orca_mint_account,
+            state_view.vault_bump,
+        )
+        .map_err(|_| ErrorCode::InvalidSeeds)?;
+
+        // 3. Treasury Account Assertions
+        assert_account_address(treasury_account, &state_view.treasury)?;
+        make_owner_token_account_assertions(
+            treasury_xorca_ata,
+            treasury_account,
+            xorca_mint_account,
+            true,
+        )?;
+
+        (
+            state_view.bump,
+            state_view.escrowed_orca_amount,
+            state_view.unvested_rewards_amount(get_current_unix_timestamp()?)?,
+            state_view.fee_checkpoint_orca_amount,
+            state_view.fee_checkpoint_xorca_supply,
+            state_view.performance_fee_bps,
+        )
+    };
+
+    // 4. Vault Account Assertions
+    let vault_account_data = make_owner_token_account_assertions(
+        vault_account,
+        state_account,
+        orca_mint_account,
+        false,
+    )?;
+
+    // 5. xOrca Mint Account Assertions
+    assert_account_role(xorca_mint_account, &[AccountRole::Writable])?;
+    assert_account_owner(xorca_mint_account, &SPL_TOKEN_PROGRAM_ID)?;
+    let xorca_mint_data = assert_external_account_data::<TokenMint>(xorca_mint_account)?;
+
+    // Unvested rewards are not yield yet; they are harvested once they vest
+    let non_escrowed_orca_amount = vault_account_data
+        .amount
+        .checked_sub(escrowed_orca_amount)
+        .and_then(|amount| amount.checked_sub(unvested_rewards_amount))
+        .ok_or(ErrorCode::InsufficientVaultBacking)?;
+
+    // Yield is the ORCA backing the current supply above what it was worth at the checkpoint rate.
+    // Every source that raises the rate counts: vested rewards, instant-unstake fees left in the
+    // vault and ORCA transferred to the vault directly.
+    // Without a checkpoint there is nothing to compare against, so the first harvest only records one.
+    let mut yield_orca_amount = 0;
+    if fee_checkpoint_xorca_supply != 0 && xorca_mint_data.supply != 0 {
+        let checkpoint_orca_amount = convert_xorca_to_orca(
+            xorca_mint_data.supply,
+            fee_checkpoint_orca_amount,
+            fee_checkpoint_xorca_supply,
+        )?;
+        yield_orca_amount = non_escrowed_orca_amount.saturating_sub(checkpoint_orca_amount);
+    }
+
+    let fee_orca_amount = calculate_performance_fee(yield_orca_amount, performance_fee_bps)?;
+    // Mint shares worth the fee against the pool without the fee, so existing holders are diluted by exactly the fee
+    let fee_xorca_amount = if fee_orca_amount == 0 {
+        0
+    } else {
+        convert_orca_to_xorca(
+            fee_orca_amount,
+            non_escrowed_orca_amount - fee_orca_amount,
+            xorca_mint_data.supply,
+        )?
+    };
+
+    let final_xorca_supply = xorca_mint_data
+        .supply
+        .checked_add(fee_xorca_amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    // The checkpoint is a high-water mark: it only moves up, so a recovery after a drop in the
+    // rate is not charged as yield again
+    if fee_checkpoint_xorca_supply == 0 || yield_orca_amount > 0 {
+        let mut state = assert_account_data_mut::<State>(state_account)?;
+        state.fee_checkpoint_orca_amount = non_escrowed_orca_amount;
+        state.fee_checkpoint_xorca_supply = final_xorca_supply;
+    }
+
+    if fee_xorca_amount == 0 {
+        return Ok(());
+    }
+
+    // Mint the fee to the treasury
+    let mut state_seeds = State::seeds();
+    let bump_bytes = [state_bump];
+    state_seeds.push(Seed::from(&bump_bytes));
+    let mint_to_instruction = MintTo {
+        mint: xorca_mint_account,
+        account: treasury_xorca_ata,
+        mint_authority: state_account,
+        amount: fee_xorca_amount,
+    };
+    mint_to_instruction.invoke_signed(&[state_seeds.as_slice().into()])?;
+
+    Event::FeeMinted {
+        yield_orca_amount: &yield_orca_amount,
+        fee_orca_amount: &fee_orca_amount,
+        fee_xorca_amount: &fee_xorca_amount,
+        xorca_mint_supply: &final_xorca_supply,
+        treasury: treasury_account.key(),
+    }
+    .emit()?;
+
+    Ok(())
+}
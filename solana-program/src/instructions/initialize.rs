@@ -3,11 +3,13 @@ use crate::{
         assert_account_address, assert_account_owner, assert_account_role, assert_account_seeds,
         assert_external_account_data, AccountRole,
     },
-    cpi::token::{TokenMint, ORCA_MINT_ID, XORCA_MINT_ID},
+    cpi::{
+        loader::{ProgramData, BPF_LOADER_UPGRADEABLE_ID, PROGRAM_DATA_ACCOUNT_TYPE},
+        token::{TokenMint, ORCA_MINT_ID, XORCA_MINT_ID},
+    },
     error::ErrorCode,
-    state::state::State,
+    state::state::{State, MAX_COOL_DOWN_PERIOD_S},
     util::account::{create_program_account_borsh, get_account_info},
-    DEPLOYER_ADDRESS,
 };
 use pinocchio::{
     account_info::AccountInfo, instruction::Seed, pubkey::find_program_address, ProgramResult,
@@ -28,12 +30,23 @@ pub fn process_instruction(accounts: &[AccountInfo], cool_down_period_s: &i64) -
     let system_program_account = get_account_info(accounts, 6)?;
     let token_program_account = get_account_info(accounts, 7)?;
     let associated_token_program_account = get_account_info(accounts, 8)?;
+    let program_data_account = get_account_info(accounts, 9)?;
 
     // 1. Payer Account Assertions
     assert_account_role(payer_account, &[AccountRole::Signer, AccountRole::Writable])?;
 
-    // 1.1. Deployer Authorization - only the deployer can call initialize
-    if payer_account.key() != &DEPLOYER_ADDRESS {
+    // 1.1. Deployer Authorization - only the program's upgrade authority can call initialize
+    assert_account_owner(program_data_account, &BPF_LOADER_UPGRADEABLE_ID)?;
+    let (program_data_address, _) = crate::pda::find_program_data_address();
+    assert_account_address(program_data_account, &program_data_address)?;
+    let program_data = assert_external_account_data::<ProgramData>(program_data_account)?;
+    if program_data.account_type != PROGRAM_DATA_ACCOUNT_TYPE {
+        return Err(ErrorCode::InvalidAccountData.into());
+    }
+    // An immutable program has no upgrade authority and can no longer be initialized
+    if program_data.upgrade_authority_flag != 1
+        || program_data.upgrade_authority != *payer_account.key()
+    {
         return Err(ErrorCode::UnauthorizedDeployerAccess.into());
     }
 
@@ -125,13 +138,14 @@ pub fn process_instruction(accounts: &[AccountInfo], cool_down_period_s: &i64) -
 
     // Create the State struct
     let mut state_data = State::default();
-    if *cool_down_period_s < 0 {
+    if *cool_down_period_s < 0 || *cool_down_period_s > MAX_COOL_DOWN_PERIOD_S {
         return Err(ErrorCode::InvalidCoolDownPeriod.into());
     }
     state_data.cool_down_period_s = *cool_down_period_s;
     state_data.bump = state_bump[0];
     state_data.vault_bump = vault_bump;
     state_data.update_authority = *update_authority_account.key();
+    state_data.rewards_authority = *update_authority_account.key();
 
     create_program_account_borsh(
         payer_account,
@@ -0,0 +1,44 @@
+use crate::{
+    assertions::account::{
+        assert_account_address, assert_account_owner, assert_account_role, assert_account_seeds,
+        AccountRole,
+    },
+    state::staker_account::StakerAccount,
+    util::account::{create_program_account_secure, get_account_info},
+};
+use pinocchio::{account_info::AccountInfo, instruction::Seed, ProgramResult};
+use pinocchio_system::ID as SYSTEM_PROGRAM_ID;
+
+pub fn process_instruction(accounts: &[AccountInfo]) -> ProgramResult {
+    let staker_account = get_account_info(accounts, 0)?;
+    let staker_account_pda = get_account_info(accounts, 1)?;
+    let system_program_account = get_account_info(accounts, 2)?;
+
+    // 1. Staker Account Assertions
+    assert_account_role(
+        staker_account,
+        &[AccountRole::Signer, AccountRole::Writable],
+    )?;
+
+    // 2. System Program Account Assertions
+    assert_account_address(system_program_account, &SYSTEM_PROGRAM_ID)?;
+
+    // 3. Staker Account PDA Assertions
+    assert_account_role(staker_account_pda, &[AccountRole::Writable])?;
+    assert_account_owner(staker_account_pda, &SYSTEM_PROGRAM_ID)?;
+    let mut staker_account_seeds = StakerAccount::seeds(staker_account.key());
+    let staker_account_bump =
+        assert_account_seeds(staker_account_pda, &crate::ID, &staker_account_seeds)?;
+    staker_account_seeds.push(Seed::from(&staker_account_bump));
+
+    // Create the staker account; withdraw indices and totals start at zero
+    let mut staker_account_data = create_program_account_secure::<StakerAccount>(
+        staker_account,
+        staker_account_pda,
+        &[staker_account_seeds.as_slice().into()],
+    )?;
+    staker_account_data.bump = staker_account_bump[0];
+    staker_account_data.staker = *staker_account.key();
+
+    Ok(())
+}
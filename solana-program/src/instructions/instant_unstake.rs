@@ -0,0 +1,184 @@
+use crate::{
+    assertions::account::{
+        assert_account_address, assert_account_data, assert_account_owner, assert_account_role,
+        assert_external_account_data, make_owner_token_account_assertions, AccountRole,
+    },
+    assertions::state::assert_not_paused,
+    cpi::{
+        system::get_current_unix_timestamp,
+        token::{TokenMint, ORCA_MINT_ID, XORCA_MINT_ID},
+    },
+    error::ErrorCode,
+    event::Event,
+    state::state::{State, PAUSE_INSTANT_UNSTAKE},
+    util::{
+        account::get_account_info,
+        math::{calculate_instant_unstake_fee, convert_xorca_to_orca},
+    },
+};
+use pinocchio::{account_info::AccountInfo, instruction::Seed, ProgramResult};
+use pinocchio_token::{
+    instructions::{Burn, Transfer},
+    ID as SPL_TOKEN_PROGRAM_ID,
+};
+
+pub fn process_instruction(accounts: &[AccountInfo], xorca_unstake_amount: &u64) -> ProgramResult {
+    let unstaker_account = get_account_info(accounts, 0)?;
+    let state_account = get_account_info(accounts, 1)?;
+    let vault_account = get_account_info(accounts, 2)?;
+    let unstaker_xorca_ata = get_account_info(accounts, 3)?;
+    let unstaker_orca_ata = get_account_info(accounts, 4)?;
+    let xorca_mint_account = get_account_info(accounts, 5)?;
+    let orca_mint_account = get_account_info(accounts, 6)?;
+    let token_program_account = get_account_info(accounts, 7)?;
+
+    // 0. Pause Assertions
+    assert_not_paused(state_account, PAUSE_INSTANT_UNSTAKE)?;
+
+    // 1. Unstaker Account Assertions
+    assert_account_role(
+        unstaker_account,
+        &[AccountRole::Signer, AccountRole::Writable],
+    )?;
+
+    // 2. Account Address Assertions
+    assert_account_address(xorca_mint_account, &XORCA_MINT_ID)?;
+    assert_account_address(orca_mint_account, &ORCA_MINT_ID)?;
+    assert_account_address(token_program_account, &SPL_TOKEN_PROGRAM_ID)?;
+
+    // 3. xOrca State Account Assertions
+    assert_account_owner(state_account, &crate::ID)?;
+    let mut state_seeds = State::seeds();
+    let (
+        state_bump_value,
+        initial_escrowed_orca_amount,
+        instant_unstake_fee_bps,
+        unvested_rewards_amount,
+        min_unstake_xorca,
+    ) = {
+        let state_view = assert_account_data::<State>(state_account)?;
+
+        State::verify_address_with_bump(state_account, &crate::ID, state_view.bump)
+            .map_err(|_| ErrorCode::InvalidSeeds)?;
+
+        // Verify vault address using stored vault_bump
+        State::verify_vault_address_with_bump(
+            state_account,
+            vault_account,
+            orca_mint_account,
+            state_view.vault_bump,
+        )
+        .map_err(|_| ErrorCode::InvalidSeeds)?;
+
+        (
+            state_view.bump,
+            state_view.escrowed_orca_amount,
+            state_view.instant_unstake_fee_bps,
+            state_view.unvested_rewards_amount(get_current_unix_timestamp()?)?,
+            state_view.min_unstake_xorca,
+        )
+    };
+    let bump_bytes = [state_bump_value];
+    state_seeds.push(Seed::from(&bump_bytes));
+
+    // 4. Vault Account Assertions
+    let vault_account_data =
+        make_owner_token_account_assertions(vault_account, state_account, orca_mint_account, true)?;
+
+    // 5. Unstaker Token Account Assertions
+    let unstaker_xorca_ata_data = make_owner_token_account_assertions(
+        unstaker_xorca_ata,
+        unstaker_account,
+        xorca_mint_account,
+        true,
+    )?;
+    if unstaker_xorca_ata_data.amount < *xorca_unstake_amount {
+        return Err(ErrorCode::InsufficientFunds.into());
+    }
+    make_owner_token_account_assertions(
+        unstaker_orca_ata,
+        unstaker_account,
+        orca_mint_account,
+        true,
+    )?;
+
+    // 6. xOrca Mint Account Assertions
+    assert_account_owner(xorca_mint_account, &SPL_TOKEN_PROGRAM_ID)?;
+    assert_account_role(xorca_mint_account, &[AccountRole::Writable])?;
+    let xorca_mint_data = assert_external_account_data::<TokenMint>(xorca_mint_account)?;
+    // Enforce xORCA mint authority must be the state and freeze authority must be None
+    if xorca_mint_data.mint_authority_flag == 0
+        || xorca_mint_data.mint_authority != *state_account.key()
+    {
+        return Err(ErrorCode::InvalidAccountData.into());
+    }
+    if xorca_mint_data.freeze_authority_flag != 0 {
+        return Err(ErrorCode::InvalidAccountData.into());
+    }
+
+    // 7. Orca Mint Account Assertions
+    assert_account_owner(orca_mint_account, &SPL_TOKEN_PROGRAM_ID)?;
+    assert_external_account_data::<TokenMint>(orca_mint_account)?;
+
+    // Calculate the ORCA amount owed and the fee that stays in the vault
+    let non_escrowed_orca_amount = vault_account_data
+        .amount
+        .checked_sub(initial_escrowed_orca_amount)
+        .and_then(|amount| amount.checked_sub(unvested_rewards_amount))
+        .ok_or(ErrorCode::InsufficientVaultBacking)?;
+    let gross_orca_amount = convert_xorca_to_orca(
+        *xorca_unstake_amount,
+        non_escrowed_orca_amount,
+        xorca_mint_data.supply,
+    )?;
+    let fee_orca_amount =
+        calculate_instant_unstake_fee(gross_orca_amount, instant_unstake_fee_bps)?;
+    let orca_unstake_amount = gross_orca_amount
+        .checked_sub(fee_orca_amount)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // The minimum applies to every unstake path, not just the ones that open a pending withdraw
+    if *xorca_unstake_amount < min_unstake_xorca {
+        return Err(ErrorCode::UnstakeAmountBelowMinimum.into());
+    }
+    if orca_unstake_amount == 0 {
+        return Err(ErrorCode::InsufficientUnstakeAmount.into());
+    }
+    if orca_unstake_amount > non_escrowed_orca_amount {
+        return Err(ErrorCode::InsufficientVaultBacking.into());
+    }
+
+    // Burn unstaker's LST tokens
+    let burn_instruction = Burn {
+        mint: xorca_mint_account,
+        account: unstaker_xorca_ata,
+        authority: unstaker_account,
+        amount: *xorca_unstake_amount,
+    };
+    burn_instruction.invoke()?;
+
+    // Transfer ORCA (net of fee) from the vault to the unstaker ATA
+    let transfer_instruction = Transfer {
+        from: vault_account,
+        to: unstaker_orca_ata,
+        authority: state_account,
+        amount: orca_unstake_amount,
+    };
+    transfer_instruction.invoke_signed(&[state_seeds.as_slice().into()])?;
+
+    let final_vault_amount = vault_account_data.amount - orca_unstake_amount;
+    let final_xorca_supply = xorca_mint_data.supply - *xorca_unstake_amount;
+
+    Event::InstantUnstake {
+        xorca_unstake_amount,
+        vault_orca_amount: &final_vault_amount,
+        vault_escrowed_orca_amount: &initial_escrowed_orca_amount,
+        xorca_mint_supply: &final_xorca_supply,
+        orca_unstake_amount: &orca_unstake_amount,
+        fee_orca_amount: &fee_orca_amount,
+        instant_unstake_fee_bps: &instant_unstake_fee_bps,
+    }
+    .emit()?;
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use crate::{
+    assertions::{
+        account::{
+            assert_account_data, assert_account_data_mut, assert_account_owner,
+            assert_account_role, AccountRole,
+        },
+        staker_account::is_staker_account,
+        state::assert_not_paused,
+    },
+    error::ErrorCode,
+    event::Event,
+    state::{
+        pending_withdraw::PendingWithdraw,
+        state::{State, PAUSE_WITHDRAW},
+    },
+    util::{
+        account::{close_program_account, get_account_info},
+        staker_account::record_staker_settlement,
+    },
+};
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+
+pub fn process_instruction(accounts: &[AccountInfo], withdraw_index: &u8) -> ProgramResult {
+    let unstaker_account = get_account_info(accounts, 0)?;
+    let state_account = get_account_info(accounts, 1)?;
+    let pending_withdraw_account = get_account_info(accounts, 2)?;
+    // Source pending withdraws are passed as remaining accounts, along with the staker accounts
+    // of any staker-indexed ones
+    let remaining_accounts = &accounts[3..];
+
+    // 0. Pause Assertions
+    // Merging reshapes pending withdraws, so it is halted together with withdrawals
+    assert_not_paused(state_account, PAUSE_WITHDRAW)?;
+
+    // 1. Unstaker Account Assertions
+    assert_account_role(
+        unstaker_account,
+        &[AccountRole::Signer, AccountRole::Writable],
+    )?;
+
+    // 2. xOrca State Account Assertions
+    {
+        let state_view = assert_account_data::<State>(state_account)?;
+        State::verify_address_with_bump(state_account, &crate::ID, state_view.bump)
+            .map_err(|_| ErrorCode::InvalidSeeds)?;
+    }
+
+    // 3. Target Pending Withdraw Account Assertions
+    assert_account_role(pending_withdraw_account, &[AccountRole::Writable])?;
+    assert_account_owner(pending_withdraw_account, &crate::ID)?;
+    let withdraw_index_bytes = [*withdraw_index];
+    let (mut withdrawable_orca_amount, mut withdrawable_timestamp) = {
+        let pending_withdraw_data =
+            assert_account_data::<PendingWithdraw>(pending_withdraw_account)?;
+        pending_withdraw_data.verify_address_and_owner(
+            pending_withdraw_account,
+            unstaker_account.key(),
+            &withdraw_index_bytes,
+        )?;
+        (
+            pending_withdraw_data.withdrawable_orca_amount,
+            pending_withdraw_data.withdrawable_timestamp,
+        )
+    };
+
+    // 4. Source Pending Withdraw Account Assertions
+    let mut merged_count: u16 = 0;
+    for source_pending_withdraw_account in remaining_accounts {
+        if is_staker_account(source_pending_withdraw_account) {
+            continue;
+        }
+        if source_pending_withdraw_account.key() == pending_withdraw_account.key() {
+            return Err(ErrorCode::InvalidAccountData.into());
+        }
+        assert_account_role(source_pending_withdraw_account, &[AccountRole::Writable])?;
+        assert_account_owner(source_pending_withdraw_account, &crate::ID)?;
+        let (source_orca_amount, source_timestamp, source_staker_indexed, source_unstaker) = {
+            let source_data =
+                assert_account_data::<PendingWithdraw>(source_pending_withdraw_account)?;
+            source_data.verify_address_and_owner(
+                source_pending_withdraw_account,
+                unstaker_account.key(),
+                &[source_data.withdraw_index],
+            )?;
+            (
+                source_data.withdrawable_orca_amount,
+                source_data.withdrawable_timestamp,
+                source_data.is_staker_indexed(),
+                source_data.unstaker,
+            )
+        };
+
+        withdrawable_orca_amount = withdrawable_orca_amount
+            .checked_add(source_orca_amount)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        withdrawable_timestamp = withdrawable_timestamp.max(source_timestamp);
+
+        // Close the source account and refund lamports to unstaker. A duplicated source
+        // fails the owner assertion on its second occurrence.
+        close_program_account(source_pending_withdraw_account, unstaker_account)?;
+        if source_staker_indexed {
+            record_staker_settlement(remaining_accounts, &source_unstaker, 0, true)?;
+        }
+        merged_count = merged_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticError)?;
+    }
+    if merged_count == 0 {
+        return Err(ErrorCode::NotEnoughAccountKeys.into());
+    }
+
+    // Update the target with the merged amount and the latest unlock time
+    let mut pending_withdraw_data =
+        assert_account_data_mut::<PendingWithdraw>(pending_withdraw_account)?;
+    pending_withdraw_data.withdrawable_orca_amount = withdrawable_orca_amount;
+    pending_withdraw_data.withdrawable_timestamp = withdrawable_timestamp;
+
+    Event::MergePendingWithdraws {
+        withdrawable_orca_amount: &withdrawable_orca_amount,
+        withdrawable_timestamp: &withdrawable_timestamp,
+        merged_count: &merged_count,
+        withdraw_index,
+    }
+    .emit()?;
+
+    Ok(())
+}
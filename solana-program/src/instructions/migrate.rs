@@ -0,0 +1,95 @@
+use crate::{
+    assertions::account::{
+        assert_account_address, assert_account_data, assert_account_data_mut,
+        assert_account_discriminator, assert_account_owner, assert_account_role, AccountRole,
+    },
+    error::ErrorCode,
+    event::Event,
+    state::{
+        pending_withdraw::PendingWithdraw, staker_account::StakerAccount, state::State,
+        AccountDiscriminator, ProgramAccount,
+    },
+    util::account::get_account_info,
+};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub fn process_instruction(accounts: &[AccountInfo]) -> ProgramResult {
+    let update_authority_account = get_account_info(accounts, 0)?;
+    let state_account = get_account_info(accounts, 1)?;
+    // Pending withdraws and staker accounts to migrate are passed as remaining accounts
+    let remaining_accounts = &accounts[2..];
+
+    // 1. Update Authority Account Assertions
+    assert_account_role(update_authority_account, &[AccountRole::Signer])?;
+
+    // 2. xOrca State Account Assertions
+    assert_account_role(state_account, &[AccountRole::Writable])?;
+    assert_account_owner(state_account, &crate::ID)?;
+    {
+        let state_view = assert_account_data::<State>(state_account)?;
+        State::verify_address_with_bump(state_account, &crate::ID, state_view.bump)
+            .map_err(|_| ErrorCode::InvalidSeeds)?;
+        assert_account_address(update_authority_account, &state_view.update_authority)?;
+    }
+
+    // The state migrates along with every call; remaining accounts are opt-in
+    let mut migrated_count: u16 = 0;
+    if migrate_account::<State>(state_account, State::migrate)? {
+        migrated_count += 1;
+    }
+
+    // 3. Remaining Account Migrations
+    for account in remaining_accounts {
+        assert_account_role(account, &[AccountRole::Writable])?;
+        assert_account_owner(account, &crate::ID)?;
+        assert_account_discriminator(
+            account,
+            &[
+                AccountDiscriminator::PendingWithdraw,
+                AccountDiscriminator::StakerAccount,
+            ],
+        )?;
+        let discriminator = account.try_borrow_data()?[0];
+        let migrated = if discriminator == AccountDiscriminator::PendingWithdraw as u8 {
+            migrate_account::<PendingWithdraw>(account, PendingWithdraw::migrate)?
+        } else {
+            migrate_account::<StakerAccount>(account, StakerAccount::migrate)?
+        };
+        if migrated {
+            migrated_count = migrated_count
+                .checked_add(1)
+                .ok_or(ErrorCode::ArithmeticError)?;
+        }
+    }
+
+    Event::AccountsMigrated {
+        migrated_count: &migrated_count,
+        migrated_by: update_authority_account.key(),
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Runs `migrate` on an account behind `T::VERSION` and shrinks it to `T::LEN` if it was
+/// created larger. Returns whether the account changed.
+fn migrate_account<T: ProgramAccount>(
+    account: &AccountInfo,
+    migrate: fn(&mut T),
+) -> Result<bool, ProgramError> {
+    assert_account_data::<T>(account)?;
+    if account.try_borrow_data()?[T::VERSION_OFFSET] == T::VERSION {
+        return Ok(false);
+    }
+    let mut account_data = assert_account_data_mut::<T>(account)?;
+    migrate(&mut account_data);
+    drop(account_data);
+
+    // Shrinking a 1024-byte pending withdraw frees 896 bytes of rent (about 0.0062 SOL). The
+    // holder is not among the Migrate accounts, so the lamports stay in the account and go back
+    // to the holder with the rest of the rent when the pending withdraw is closed
+    if account.data_len() > T::LEN {
+        account.resize(T::LEN)?;
+    }
+    Ok(true)
+}
@@ -1,8 +1,22 @@
+pub mod accept_update_authority;
+pub mod apply_pending_config;
+pub mod cancel_unstake;
+pub mod deposit_rewards;
+pub mod harvest;
 pub mod initialize;
+pub mod initialize_staker_account;
+pub mod instant_unstake;
+pub mod merge_pending_withdraws;
+pub mod migrate;
 pub mod set;
 pub mod stake;
+pub mod stake_for_exact_xorca;
+pub mod transfer_pending_withdraw;
 pub mod unstake;
+pub mod unstake_for_exact_orca;
 pub mod withdraw;
+pub mod withdraw_many;
+pub mod withdraw_partial;
 use pinocchio::pubkey::Pubkey;
 
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -37,6 +51,8 @@ pub enum Instruction {
     #[account(6, name = "vault_account")]
     #[account(7, name = "system_program_account")]
     #[account(8, name = "token_program_account")]
+    #[account(9, optional, writable, signer, name = "rent_payer_account")]
+    #[account(10, optional, writable, name = "staker_account_pda")]
     Unstake {
         xorca_unstake_amount: u64,
         withdraw_index: u8,
@@ -61,6 +77,7 @@ pub enum Instruction {
     #[account(6, name = "system_program_account")]
     #[account(7, name = "token_program_account")]
     #[account(8, name = "associated_token_program_account")]
+    #[account(9, name = "program_data_account")]
     Initialize { cool_down_period_s: i64 },
 
     #[account(0, writable, signer, name = "update_authority_account")]
@@ -68,6 +85,184 @@ pub enum Instruction {
     Set {
         instruction_data: StateUpdateInstruction,
     },
+
+    #[account(0, writable, signer, name = "unstaker_account")]
+    #[account(1, name = "state_account")]
+    #[account(2, writable, name = "vault_account")]
+    #[account(3, writable, name = "unstaker_xorca_ata")]
+    #[account(4, writable, name = "unstaker_orca_ata")]
+    #[account(5, writable, name = "xorca_mint_account")]
+    #[account(6, name = "orca_mint_account")]
+    #[account(7, name = "token_program_account")]
+    InstantUnstake { xorca_unstake_amount: u64 },
+
+    #[account(0, writable, signer, name = "unstaker_account")]
+    #[account(1, writable, name = "state_account")]
+    #[account(2, writable, name = "pending_withdraw_account")]
+    #[account(3, writable, name = "unstaker_xorca_ata")]
+    #[account(4, writable, name = "xorca_mint_account")]
+    #[account(5, name = "orca_mint_account")]
+    #[account(6, name = "vault_account")]
+    #[account(7, name = "token_program_account")]
+    CancelUnstake { withdraw_index: u8 },
+
+    #[account(0, writable, signer, name = "unstaker_account")]
+    #[account(1, writable, name = "state_account")]
+    #[account(2, writable, name = "pending_withdraw_account")]
+    #[account(3, writable, name = "unstaker_orca_ata")]
+    #[account(4, writable, name = "vault_account")]
+    #[account(5, name = "orca_mint_account")]
+    #[account(6, name = "system_program_account")]
+    #[account(7, name = "token_program_account")]
+    WithdrawPartial {
+        withdraw_index: u8,
+        orca_withdraw_amount: u64,
+    },
+
+    #[account(0, writable, signer, name = "unstaker_account")]
+    #[account(1, name = "state_account")]
+    #[account(2, writable, name = "pending_withdraw_account")]
+    MergePendingWithdraws { withdraw_index: u8 },
+
+    #[account(0, writable, signer, name = "unstaker_account")]
+    #[account(1, writable, name = "state_account")]
+    #[account(2, writable, name = "unstaker_orca_ata")]
+    #[account(3, writable, name = "vault_account")]
+    #[account(4, name = "orca_mint_account")]
+    #[account(5, name = "system_program_account")]
+    #[account(6, name = "token_program_account")]
+    WithdrawMany,
+
+    #[account(0, writable, signer, name = "staker_account")]
+    #[account(1, writable, name = "vault_account")]
+    #[account(2, writable, name = "staker_orca_ata")]
+    #[account(3, writable, name = "staker_xorca_ata")]
+    #[account(4, writable, name = "xorca_mint_account")]
+    #[account(5, name = "state_account")]
+    #[account(6, name = "orca_mint_account")]
+    #[account(7, name = "token_program_account")]
+    StakeWithSlippage {
+        orca_stake_amount: u64,
+        min_xorca_out: u64,
+        deadline: Option<i64>,
+    },
+
+    #[account(0, writable, signer, name = "unstaker_account")]
+    #[account(1, writable, name = "state_account")]
+    #[account(2, writable, name = "pending_withdraw_account")]
+    #[account(3, writable, name = "unstaker_xorca_ata")]
+    #[account(4, writable, name = "xorca_mint_account")]
+    #[account(5, name = "orca_mint_account")]
+    #[account(6, name = "vault_account")]
+    #[account(7, name = "system_program_account")]
+    #[account(8, name = "token_program_account")]
+    #[account(9, optional, writable, signer, name = "rent_payer_account")]
+    #[account(10, optional, writable, name = "staker_account_pda")]
+    UnstakeWithSlippage {
+        xorca_unstake_amount: u64,
+        withdraw_index: u8,
+        min_orca_out: u64,
+        deadline: Option<i64>,
+    },
+
+    #[account(0, writable, signer, name = "staker_account")]
+    #[account(1, writable, name = "vault_account")]
+    #[account(2, writable, name = "staker_orca_ata")]
+    #[account(3, writable, name = "staker_xorca_ata")]
+    #[account(4, writable, name = "xorca_mint_account")]
+    #[account(5, name = "state_account")]
+    #[account(6, name = "orca_mint_account")]
+    #[account(7, name = "token_program_account")]
+    StakeForExactXorca { xorca_out: u64, max_orca_in: u64 },
+
+    #[account(0, writable, signer, name = "unstaker_account")]
+    #[account(1, writable, name = "state_account")]
+    #[account(2, writable, name = "pending_withdraw_account")]
+    #[account(3, writable, name = "unstaker_xorca_ata")]
+    #[account(4, writable, name = "xorca_mint_account")]
+    #[account(5, name = "orca_mint_account")]
+    #[account(6, name = "vault_account")]
+    #[account(7, name = "system_program_account")]
+    #[account(8, name = "token_program_account")]
+    #[account(9, optional, writable, signer, name = "rent_payer_account")]
+    #[account(10, optional, writable, name = "staker_account_pda")]
+    UnstakeForExactOrca {
+        orca_out: u64,
+        max_xorca_in: u64,
+        withdraw_index: u8,
+    },
+
+    #[account(0, writable, signer, name = "pending_update_authority_account")]
+    #[account(1, writable, name = "state_account")]
+    AcceptUpdateAuthority,
+
+    #[account(0, writable, name = "state_account")]
+    ApplyPendingConfig,
+
+    #[account(0, writable, signer, name = "depositor_account")]
+    #[account(1, writable, name = "state_account")]
+    #[account(2, writable, name = "depositor_orca_ata")]
+    #[account(3, writable, name = "vault_account")]
+    #[account(4, name = "orca_mint_account")]
+    #[account(5, name = "xorca_mint_account")]
+    #[account(6, name = "token_program_account")]
+    DepositRewards { amount: u64, vesting_period_s: i64 },
+
+    #[account(0, writable, name = "state_account")]
+    #[account(1, name = "vault_account")]
+    #[account(2, writable, name = "xorca_mint_account")]
+    #[account(3, name = "treasury_account")]
+    #[account(4, writable, name = "treasury_xorca_ata")]
+    #[account(5, name = "orca_mint_account")]
+    #[account(6, name = "token_program_account")]
+    Harvest,
+
+    #[account(0, writable, signer, name = "staker_account")]
+    #[account(1, writable, name = "vault_account")]
+    #[account(2, writable, name = "staker_orca_ata")]
+    #[account(3, writable, name = "beneficiary_xorca_ata")]
+    #[account(4, writable, name = "xorca_mint_account")]
+    #[account(5, name = "state_account")]
+    #[account(6, name = "orca_mint_account")]
+    #[account(7, name = "token_program_account")]
+    StakeFor { orca_stake_amount: u64 },
+
+    #[account(0, writable, signer, name = "unstaker_account")]
+    #[account(1, writable, name = "state_account")]
+    #[account(2, writable, name = "pending_withdraw_account")]
+    #[account(3, writable, name = "destination_orca_ata")]
+    #[account(4, writable, name = "vault_account")]
+    #[account(5, name = "orca_mint_account")]
+    #[account(6, name = "system_program_account")]
+    #[account(7, name = "token_program_account")]
+    WithdrawTo { withdraw_index: u8 },
+
+    #[account(0, writable, name = "unstaker_account")]
+    #[account(1, writable, name = "state_account")]
+    #[account(2, writable, name = "pending_withdraw_account")]
+    #[account(3, writable, name = "unstaker_orca_ata")]
+    #[account(4, writable, name = "vault_account")]
+    #[account(5, name = "orca_mint_account")]
+    #[account(6, name = "system_program_account")]
+    #[account(7, name = "token_program_account")]
+    #[account(8, signer, name = "cranker_account")]
+    CrankWithdraw { withdraw_index: u8 },
+
+    #[account(0, signer, name = "owner_account")]
+    #[account(1, writable, name = "pending_withdraw_account")]
+    TransferPendingWithdraw {
+        withdraw_index: u8,
+        new_owner: Pubkey,
+    },
+
+    #[account(0, writable, signer, name = "staker_account")]
+    #[account(1, writable, name = "staker_account_pda")]
+    #[account(2, name = "system_program_account")]
+    InitializeStakerAccount,
+
+    #[account(0, signer, name = "update_authority_account")]
+    #[account(1, writable, name = "state_account")]
+    Migrate,
 }
 
 impl InstructionDiscriminator {
@@ -82,6 +277,42 @@ impl InstructionDiscriminator {
     derive(BorshSerialize, BorshDeserialize, FromRepr)
 )]
 pub enum StateUpdateInstruction {
-    UpdateCoolDownPeriod { new_cool_down_period_s: i64 },
-    UpdateUpdateAuthority { new_authority: Pubkey },
+    UpdateCoolDownPeriod {
+        new_cool_down_period_s: i64,
+    },
+    /// Replaced by `ProposeUpdateAuthority` and `AcceptUpdateAuthority`; kept so its wire
+    /// index stays reserved and old clients get `DeprecatedInstruction`
+    UpdateUpdateAuthority {
+        new_authority: Pubkey,
+    },
+    UpdateInstantUnstakeFee {
+        new_instant_unstake_fee_bps: u16,
+    },
+    SetPauseFlags {
+        new_pause_flags: u16,
+    },
+    ProposeUpdateAuthority {
+        new_authority: Pubkey,
+    },
+    UpdateRewardsAuthority {
+        new_rewards_authority: Pubkey,
+    },
+    UpdatePerformanceFee {
+        new_performance_fee_bps: u16,
+    },
+    UpdateTreasury {
+        new_treasury: Pubkey,
+    },
+    UpdateMaxVaultOrca {
+        new_max_vault_orca: u64,
+    },
+    UpdateMaxStakePerTx {
+        new_max_stake_per_tx: u64,
+    },
+    UpdateMinStakeOrca {
+        new_min_stake_orca: u64,
+    },
+    UpdateMinUnstakeXorca {
+        new_min_unstake_xorca: u64,
+    },
 }
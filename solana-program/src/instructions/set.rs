@@ -3,11 +3,12 @@ use crate::{
         assert_account_address, assert_account_data_mut, assert_account_owner, assert_account_role,
         AccountRole,
     },
+    cpi::system::get_current_unix_timestamp,
     error::ErrorCode,
     event::Event,
     instructions::StateUpdateInstruction,
-    state::state::State,
-    util::account::get_account_info,
+    state::state::{State, CONFIG_TIMELOCK_S, MAX_COOL_DOWN_PERIOD_S},
+    util::{account::get_account_info, math::BPS_DENOMINATOR},
 };
 use pinocchio::{account_info::AccountInfo, ProgramResult};
 
@@ -38,17 +39,138 @@ pub fn process_instruction(
         StateUpdateInstruction::UpdateCoolDownPeriod {
             new_cool_down_period_s,
         } => {
-            if *new_cool_down_period_s < 0 {
+            if *new_cool_down_period_s < 0 || *new_cool_down_period_s > MAX_COOL_DOWN_PERIOD_S {
                 return Err(ErrorCode::InvalidCoolDownPeriod.into());
             }
-            state_view.cool_down_period_s = *new_cool_down_period_s;
+            // Queue the change so stakers get notice before the exit terms change.
+            // Queueing again replaces the pending value and restarts the timelock.
+            let activation_timestamp = get_current_unix_timestamp()?
+                .checked_add(CONFIG_TIMELOCK_S)
+                .ok_or(ErrorCode::ArithmeticError)?;
+            state_view.pending_cool_down_period_s = *new_cool_down_period_s;
+            state_view.pending_config_activation_timestamp = activation_timestamp;
+            Event::CoolDownPeriodChangeQueued {
+                new_cool_down_period_s,
+                activation_timestamp: &activation_timestamp,
+                queued_by: update_authority_account.key(),
+            }
+            .emit()?;
+        }
+        StateUpdateInstruction::UpdateUpdateAuthority { .. } => {
+            return Err(ErrorCode::DeprecatedInstruction.into());
+        }
+        StateUpdateInstruction::UpdateInstantUnstakeFee {
+            new_instant_unstake_fee_bps,
+        } => {
+            if *new_instant_unstake_fee_bps > BPS_DENOMINATOR {
+                return Err(ErrorCode::InvalidInstantUnstakeFee.into());
+            }
+            let old_fee_bps = state_view.instant_unstake_fee_bps;
+            state_view.instant_unstake_fee_bps = *new_instant_unstake_fee_bps;
+            Event::InstantUnstakeFeeSet {
+                new_fee_bps: new_instant_unstake_fee_bps,
+                old_fee_bps: &old_fee_bps,
+                set_by: update_authority_account.key(),
+            }
+            .emit()?;
         }
-        StateUpdateInstruction::UpdateUpdateAuthority { new_authority } => {
-            let old_authority = state_view.update_authority;
-            state_view.update_authority = *new_authority;
-            Event::UpdateAuthoritySet {
-                new_authority: new_authority,
-                set_by: &old_authority,
+        StateUpdateInstruction::SetPauseFlags { new_pause_flags } => {
+            let old_pause_flags = state_view.pause_flags;
+            state_view.pause_flags = *new_pause_flags;
+            Event::PauseFlagsSet {
+                new_pause_flags,
+                old_pause_flags: &old_pause_flags,
+                set_by: update_authority_account.key(),
+            }
+            .emit()?;
+        }
+        StateUpdateInstruction::ProposeUpdateAuthority { new_authority } => {
+            // Proposing Pubkey::default() cancels an outstanding proposal
+            state_view.pending_update_authority = *new_authority;
+            Event::UpdateAuthorityProposed {
+                proposed_authority: new_authority,
+                proposed_by: update_authority_account.key(),
+            }
+            .emit()?;
+        }
+        StateUpdateInstruction::UpdateRewardsAuthority {
+            new_rewards_authority,
+        } => {
+            state_view.rewards_authority = *new_rewards_authority;
+            Event::RewardsAuthoritySet {
+                new_rewards_authority,
+                set_by: update_authority_account.key(),
+            }
+            .emit()?;
+        }
+        StateUpdateInstruction::UpdatePerformanceFee {
+            new_performance_fee_bps,
+        } => {
+            if *new_performance_fee_bps > BPS_DENOMINATOR {
+                return Err(ErrorCode::InvalidPerformanceFee.into());
+            }
+            let old_fee_bps = state_view.performance_fee_bps;
+            state_view.performance_fee_bps = *new_performance_fee_bps;
+            Event::PerformanceFeeSet {
+                new_fee_bps: new_performance_fee_bps,
+                old_fee_bps: &old_fee_bps,
+                set_by: update_authority_account.key(),
+            }
+            .emit()?;
+        }
+        StateUpdateInstruction::UpdateTreasury { new_treasury } => {
+            let old_treasury = state_view.treasury;
+            state_view.treasury = *new_treasury;
+            Event::TreasurySet {
+                new_treasury,
+                old_treasury: &old_treasury,
+                set_by: update_authority_account.key(),
+            }
+            .emit()?;
+        }
+        StateUpdateInstruction::UpdateMaxVaultOrca { new_max_vault_orca } => {
+            let old_max_vault_orca = state_view.max_vault_orca;
+            state_view.max_vault_orca = *new_max_vault_orca;
+            state_view.assert_valid_stake_caps()?;
+            Event::MaxVaultOrcaSet {
+                new_max_vault_orca,
+                old_max_vault_orca: &old_max_vault_orca,
+                set_by: update_authority_account.key(),
+            }
+            .emit()?;
+        }
+        StateUpdateInstruction::UpdateMaxStakePerTx {
+            new_max_stake_per_tx,
+        } => {
+            let old_max_stake_per_tx = state_view.max_stake_per_tx;
+            state_view.max_stake_per_tx = *new_max_stake_per_tx;
+            state_view.assert_valid_stake_caps()?;
+            Event::MaxStakePerTxSet {
+                new_max_stake_per_tx,
+                old_max_stake_per_tx: &old_max_stake_per_tx,
+                set_by: update_authority_account.key(),
+            }
+            .emit()?;
+        }
+        StateUpdateInstruction::UpdateMinStakeOrca { new_min_stake_orca } => {
+            let old_min_stake_orca = state_view.min_stake_orca;
+            state_view.min_stake_orca = *new_min_stake_orca;
+            Event::MinStakeOrcaSet {
+                new_min_stake_orca,
+                old_min_stake_orca: &old_min_stake_orca,
+                set_by: update_authority_account.key(),
+            }
+            .emit()?;
+        }
+        StateUpdateInstruction::UpdateMinUnstakeXorca {
+            new_min_unstake_xorca,
+        } => {
+            let old_min_unstake_xorca = state_view.min_unstake_xorca;
+            state_view.min_unstake_xorca = *new_min_unstake_xorca;
+            Event::MinUnstakeXorcaSet {
+                new_min_unstake_xorca,
+                old_min_unstake_xorca: &old_min_unstake_xorca,
+                set_by: update_authority_account.key(),
             }
             .emit()?;
         }
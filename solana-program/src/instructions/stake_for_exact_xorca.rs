@@ -0,0 +1,33 @@
+use crate::{
+    error::ErrorCode,
+    util::{math::convert_xorca_to_orca_round_up, stake::StakeAccounts},
+};
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+
+pub fn process_instruction(
+    accounts: &[AccountInfo],
+    xorca_out: &u64,
+    max_orca_in: &u64,
+) -> ProgramResult {
+    let stake_accounts = StakeAccounts::load(accounts, false)?;
+
+    if *xorca_out == 0 {
+        return Err(ErrorCode::InsufficientStakeAmount.into());
+    }
+
+    // Calculate the ORCA needed to mint exactly xorca_out (rounded up in favor of the vault)
+    let orca_stake_amount = convert_xorca_to_orca_round_up(
+        *xorca_out,
+        stake_accounts.non_escrowed_orca_amount,
+        stake_accounts.xorca_supply,
+    )?;
+
+    if orca_stake_amount > *max_orca_in {
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
+
+    // Balance, minimum stake and deposit caps
+    stake_accounts.assert_stake_amount(orca_stake_amount)?;
+
+    stake_accounts.stake(orca_stake_amount, *xorca_out)
+}
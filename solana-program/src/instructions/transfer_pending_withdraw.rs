@@ -0,0 +1,51 @@
+use crate::{
+    assertions::account::{
+        assert_account_data_mut, assert_account_owner, assert_account_role, AccountRole,
+    },
+    error::ErrorCode,
+    event::Event,
+    state::pending_withdraw::PendingWithdraw,
+    util::account::get_account_info,
+};
+use pinocchio::{account_info::AccountInfo, pubkey::Pubkey, ProgramResult};
+
+pub fn process_instruction(
+    accounts: &[AccountInfo],
+    withdraw_index: &u8,
+    new_owner: &Pubkey,
+) -> ProgramResult {
+    let owner_account = get_account_info(accounts, 0)?;
+    let pending_withdraw_account = get_account_info(accounts, 1)?;
+
+    // 1. Owner Account Assertions
+    assert_account_role(owner_account, &[AccountRole::Signer])?;
+
+    // An all-zero owner would hand the position back to the unstaker
+    if *new_owner == Pubkey::default() {
+        return Err(ErrorCode::InvalidPendingWithdrawOwner.into());
+    }
+
+    // 2. Pending Withdraw Account Assertions
+    assert_account_role(pending_withdraw_account, &[AccountRole::Writable])?;
+    assert_account_owner(pending_withdraw_account, &crate::ID)?;
+    let mut pending_withdraw_data =
+        assert_account_data_mut::<PendingWithdraw>(pending_withdraw_account)?;
+    pending_withdraw_data.verify_address_and_owner(
+        pending_withdraw_account,
+        owner_account.key(),
+        &[*withdraw_index],
+    )?;
+
+    // The address stays derived from the unstaker; only the holder changes
+    pending_withdraw_data.owner = *new_owner;
+
+    Event::PendingWithdrawTransferred {
+        unstaker: &pending_withdraw_data.unstaker,
+        withdraw_index,
+        previous_owner: owner_account.key(),
+        new_owner,
+    }
+    .emit()?;
+
+    Ok(())
+}
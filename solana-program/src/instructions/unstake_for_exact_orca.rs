@@ -0,0 +1,37 @@
+use crate::{
+    error::ErrorCode,
+    util::{math::convert_orca_to_xorca_round_up, unstake::UnstakeAccounts},
+};
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+
+pub fn process_instruction(
+    accounts: &[AccountInfo],
+    orca_out: &u64,
+    max_xorca_in: &u64,
+    withdraw_index: &u8,
+) -> ProgramResult {
+    let unstake_accounts = UnstakeAccounts::load(accounts, *withdraw_index)?;
+
+    if *orca_out == 0 {
+        return Err(ErrorCode::InsufficientUnstakeAmount.into());
+    }
+    if *orca_out > unstake_accounts.non_escrowed_orca_amount {
+        return Err(ErrorCode::InsufficientVaultBacking.into());
+    }
+
+    // Calculate the xORCA to burn for exactly orca_out (rounded up in favor of the vault)
+    let xorca_unstake_amount = convert_orca_to_xorca_round_up(
+        *orca_out,
+        unstake_accounts.non_escrowed_orca_amount,
+        unstake_accounts.xorca_supply,
+    )?;
+
+    if xorca_unstake_amount > *max_xorca_in {
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
+
+    // Balance and minimum unstake
+    unstake_accounts.assert_unstake_amount(xorca_unstake_amount)?;
+
+    unstake_accounts.unstake(xorca_unstake_amount, *orca_out)
+}
@@ -1,19 +1,45 @@
 use crate::{
     assertions::account::{
         assert_account_address, assert_account_data, assert_account_data_mut, assert_account_owner,
-        assert_account_role, make_owner_token_account_assertions, AccountRole,
+        assert_account_role, assert_account_seeds, assert_external_account_data,
+        make_owner_token_account_assertions, AccountRole,
+    },
+    assertions::state::assert_not_paused,
+    cpi::{
+        system::get_current_unix_timestamp,
+        token::{TokenAccount, ORCA_MINT_ID},
     },
-    cpi::{system::get_current_unix_timestamp, token::ORCA_MINT_ID},
     error::ErrorCode,
     event::Event,
-    state::{pending_withdraw::PendingWithdraw, state::State},
-    util::account::{close_program_account, get_account_info},
+    state::{
+        pending_withdraw::PendingWithdraw,
+        state::{State, PAUSE_WITHDRAW},
+    },
+    util::{
+        account::{close_program_account, get_account_info},
+        staker_account::record_staker_settlement,
+    },
 };
 use pinocchio::{account_info::AccountInfo, instruction::Seed, ProgramResult};
+use pinocchio_associated_token_account::ID as ASSOCIATED_TOKEN_PROGRAM_ID;
 use pinocchio_system::ID as SYSTEM_PROGRAM_ID;
 use pinocchio_token::{instructions::Transfer, ID as SPL_TOKEN_PROGRAM_ID};
 
-pub fn process_instruction(accounts: &[AccountInfo], withdraw_index: &u8) -> ProgramResult {
+/// Who has to sign a withdraw and where the ORCA may go.
+pub enum WithdrawMode {
+    /// Withdraw: the unstaker signs and receives the ORCA in a token account they own.
+    Unstaker,
+    /// WithdrawTo: the unstaker signs and picks any ORCA token account.
+    To,
+    /// CrankWithdraw: anyone signs and the ORCA goes to the unstaker's canonical ORCA ATA.
+    Crank,
+}
+
+pub fn process_instruction(
+    accounts: &[AccountInfo],
+    withdraw_index: &u8,
+    mode: WithdrawMode,
+) -> ProgramResult {
     let unstaker_account = get_account_info(accounts, 0)?;
     let state_account = get_account_info(accounts, 1)?;
     let pending_withdraw_account = get_account_info(accounts, 2)?;
@@ -22,12 +48,28 @@ pub fn process_instruction(accounts: &[AccountInfo], withdraw_index: &u8) -> Pro
     let orca_mint_account = get_account_info(accounts, 5)?;
     let system_program_account = get_account_info(accounts, 6)?;
     let token_program_account = get_account_info(accounts, 7)?;
+    // Optional staker account, required when the pending withdraw is staker-indexed
+    let staker_accounts = match mode {
+        WithdrawMode::Crank => accounts.get(9..),
+        _ => accounts.get(8..),
+    }
+    .unwrap_or_default();
+
+    // 0. Pause Assertions
+    assert_not_paused(state_account, PAUSE_WITHDRAW)?;
 
     // 1. Unstaker Account Assertions
-    assert_account_role(
-        unstaker_account,
-        &[AccountRole::Signer, AccountRole::Writable],
-    )?;
+    if let WithdrawMode::Crank = mode {
+        // The unstaker only receives the rent; the cranker signs instead
+        assert_account_role(unstaker_account, &[AccountRole::Writable])?;
+        let cranker_account = get_account_info(accounts, 8)?;
+        assert_account_role(cranker_account, &[AccountRole::Signer])?;
+    } else {
+        assert_account_role(
+            unstaker_account,
+            &[AccountRole::Signer, AccountRole::Writable],
+        )?;
+    }
 
     // 6. Orca Mint Account Assertions
     assert_account_address(orca_mint_account, &ORCA_MINT_ID)?;
@@ -62,38 +104,68 @@ pub fn process_instruction(accounts: &[AccountInfo], withdraw_index: &u8) -> Pro
     assert_account_role(pending_withdraw_account, &[AccountRole::Writable])?;
     assert_account_owner(pending_withdraw_account, &crate::ID)?;
     let withdraw_index_bytes = [*withdraw_index];
-    // Prefer cached bump from account data if present; else compute and verify
-    let pending_withdraw_bump_byte = {
-        let data = assert_account_data::<PendingWithdraw>(pending_withdraw_account)?;
-        data.bump
-    };
-
-    // Use derive_address for verification when we have the stored bump
-    PendingWithdraw::verify_address_with_bump(
-        pending_withdraw_account,
-        unstaker_account.key(),
-        &withdraw_index_bytes,
-        &crate::ID,
-        pending_withdraw_bump_byte,
-    )
-    .map_err(|_| ErrorCode::InvalidSeeds)?;
-
-    let (withdrawable_orca_amount, withdrawable_timestamp) = {
+    let (withdrawable_orca_amount, withdrawable_timestamp, staker_withdraw_index, unstaker) = {
         let pending_withdraw_data =
             assert_account_data::<PendingWithdraw>(pending_withdraw_account)?;
+        // Seeds come from the stored unstaker; the account passed as unstaker must hold the position
+        pending_withdraw_data.verify_address_and_owner(
+            pending_withdraw_account,
+            unstaker_account.key(),
+            &withdraw_index_bytes,
+        )?;
         (
             pending_withdraw_data.withdrawable_orca_amount,
             pending_withdraw_data.withdrawable_timestamp,
+            pending_withdraw_data
+                .is_staker_indexed()
+                .then_some(pending_withdraw_data.staker_withdraw_index),
+            pending_withdraw_data.unstaker,
         )
     };
 
     // 5. Unstaker Stake Token Account Assertions
-    make_owner_token_account_assertions(
-        unstaker_orca_ata,
-        unstaker_account,
-        orca_mint_account,
-        true,
-    )?;
+    match mode {
+        WithdrawMode::Unstaker => {
+            make_owner_token_account_assertions(
+                unstaker_orca_ata,
+                unstaker_account,
+                orca_mint_account,
+                true,
+            )?;
+        }
+        WithdrawMode::To => {
+            // Sending to the vault would release the escrow without paying anyone out
+            if unstaker_orca_ata.key() == vault_account.key() {
+                return Err(ErrorCode::InvalidAccountData.into());
+            }
+            assert_account_role(unstaker_orca_ata, &[AccountRole::Writable])?;
+            assert_account_owner(unstaker_orca_ata, &SPL_TOKEN_PROGRAM_ID)?;
+            let destination_orca_ata_data =
+                assert_external_account_data::<TokenAccount>(unstaker_orca_ata)?;
+            if destination_orca_ata_data.mint != *orca_mint_account.key() {
+                return Err(ErrorCode::InvalidAccountData.into());
+            }
+        }
+        WithdrawMode::Crank => {
+            // Same seeds as the vault ATA, derived for the unstaker
+            let unstaker_orca_ata_seeds = crate::pda::seeds::vault_seeds(
+                unstaker_account.key(),
+                &SPL_TOKEN_PROGRAM_ID,
+                orca_mint_account.key(),
+            );
+            assert_account_seeds(
+                unstaker_orca_ata,
+                &ASSOCIATED_TOKEN_PROGRAM_ID,
+                &unstaker_orca_ata_seeds,
+            )?;
+            make_owner_token_account_assertions(
+                unstaker_orca_ata,
+                unstaker_account,
+                orca_mint_account,
+                true,
+            )?;
+        }
+    }
 
     // 6. Vault Account Assertions
     // Use stored vault_bump for verification - more efficient than assert_account_seeds
@@ -130,6 +202,9 @@ pub fn process_instruction(accounts: &[AccountInfo], withdraw_index: &u8) -> Pro
 
     // Close the pending_withdraw account and refund lamports to unstaker
     close_program_account(pending_withdraw_account, unstaker_account)?;
+    if staker_withdraw_index.is_some() {
+        record_staker_settlement(staker_accounts, &unstaker, withdrawable_orca_amount, true)?;
+    }
 
     // Remove tokens from escrow
     let mut state = assert_account_data_mut::<State>(state_account)?;
@@ -143,6 +218,8 @@ pub fn process_instruction(accounts: &[AccountInfo], withdraw_index: &u8) -> Pro
         withdrawable_orca_amount: &withdrawable_orca_amount,
         cool_down_period_s: &state.cool_down_period_s,
         withdraw_index: withdraw_index,
+        destination: unstaker_orca_ata.key(),
+        staker_withdraw_index: &staker_withdraw_index,
     }
     .emit()?;
 
@@ -0,0 +1,178 @@
+use crate::{
+    assertions::account::{
+        assert_account_address, assert_account_data, assert_account_data_mut, assert_account_owner,
+        assert_account_role, make_owner_token_account_assertions, AccountRole,
+    },
+    assertions::{staker_account::is_staker_account, state::assert_not_paused},
+    cpi::{system::get_current_unix_timestamp, token::ORCA_MINT_ID},
+    error::ErrorCode,
+    event::Event,
+    state::{
+        pending_withdraw::PendingWithdraw,
+        state::{State, PAUSE_WITHDRAW},
+    },
+    util::{
+        account::{close_program_account, get_account_info},
+        staker_account::record_staker_settlement,
+    },
+};
+use pinocchio::{account_info::AccountInfo, instruction::Seed, ProgramResult};
+use pinocchio_system::ID as SYSTEM_PROGRAM_ID;
+use pinocchio_token::{instructions::Transfer, ID as SPL_TOKEN_PROGRAM_ID};
+
+pub fn process_instruction(accounts: &[AccountInfo]) -> ProgramResult {
+    let unstaker_account = get_account_info(accounts, 0)?;
+    let state_account = get_account_info(accounts, 1)?;
+    let unstaker_orca_ata = get_account_info(accounts, 2)?;
+    let vault_account = get_account_info(accounts, 3)?;
+    let orca_mint_account = get_account_info(accounts, 4)?;
+    let system_program_account = get_account_info(accounts, 5)?;
+    let token_program_account = get_account_info(accounts, 6)?;
+    // Pending withdraws are passed as remaining accounts, along with the staker accounts of
+    // any staker-indexed ones
+    let remaining_accounts = &accounts[7..];
+
+    // 0. Pause Assertions
+    assert_not_paused(state_account, PAUSE_WITHDRAW)?;
+
+    // 1. Unstaker Account Assertions
+    assert_account_role(
+        unstaker_account,
+        &[AccountRole::Signer, AccountRole::Writable],
+    )?;
+
+    // 2. Orca Mint Account Assertions
+    assert_account_address(orca_mint_account, &ORCA_MINT_ID)?;
+    assert_account_owner(orca_mint_account, &SPL_TOKEN_PROGRAM_ID)?;
+
+    // 3. Xorca State Account Assertions
+    assert_account_role(state_account, &[AccountRole::Writable])?;
+    assert_account_owner(state_account, &crate::ID)?;
+    let mut state_seeds = State::seeds();
+    let state_bump_value = {
+        let state_view = assert_account_data::<State>(state_account)?;
+
+        State::verify_address_with_bump(state_account, &crate::ID, state_view.bump)
+            .map_err(|_| ErrorCode::InvalidSeeds)?;
+
+        // Verify vault address using stored vault_bump
+        State::verify_vault_address_with_bump(
+            state_account,
+            vault_account,
+            orca_mint_account,
+            state_view.vault_bump,
+        )
+        .map_err(|_| ErrorCode::InvalidSeeds)?;
+
+        state_view.bump
+    };
+
+    let bump_bytes = [state_bump_value];
+    state_seeds.push(Seed::from(&bump_bytes));
+
+    // 4. Unstaker Stake Token Account Assertions
+    make_owner_token_account_assertions(
+        unstaker_orca_ata,
+        unstaker_account,
+        orca_mint_account,
+        true,
+    )?;
+
+    // 5. Vault Account Assertions
+    make_owner_token_account_assertions(vault_account, state_account, orca_mint_account, true)?;
+
+    // 6. System Program Account Assertions
+    assert_account_address(system_program_account, &SYSTEM_PROGRAM_ID)?;
+
+    // 7. Token Program Account Assertions
+    assert_account_address(token_program_account, &SPL_TOKEN_PROGRAM_ID)?;
+
+    // 8. Pending Withdraw Account Assertions
+    let current_unix_timestamp = get_current_unix_timestamp()?;
+    let mut withdrawable_orca_amount: u64 = 0;
+    let mut withdraw_count: u16 = 0;
+    for (position, pending_withdraw_account) in remaining_accounts.iter().enumerate() {
+        if is_staker_account(pending_withdraw_account) {
+            continue;
+        }
+        assert_account_role(pending_withdraw_account, &[AccountRole::Writable])?;
+        assert_account_owner(pending_withdraw_account, &crate::ID)?;
+        let pending_withdraw_data =
+            assert_account_data::<PendingWithdraw>(pending_withdraw_account)?;
+        pending_withdraw_data.verify_address_and_owner(
+            pending_withdraw_account,
+            unstaker_account.key(),
+            &[pending_withdraw_data.withdraw_index],
+        )?;
+
+        // Positions bought from other unstakers can share a withdraw index, so compare addresses
+        if remaining_accounts[..position]
+            .iter()
+            .any(|account| account.key() == pending_withdraw_account.key())
+        {
+            return Err(ErrorCode::InvalidAccountData.into());
+        }
+
+        if current_unix_timestamp < pending_withdraw_data.withdrawable_timestamp {
+            return Err(ErrorCode::CoolDownPeriodStillActive.into());
+        }
+        withdrawable_orca_amount = withdrawable_orca_amount
+            .checked_add(pending_withdraw_data.withdrawable_orca_amount)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        withdraw_count = withdraw_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        if pending_withdraw_data.is_staker_indexed() {
+            record_staker_settlement(
+                remaining_accounts,
+                &pending_withdraw_data.unstaker,
+                pending_withdraw_data.withdrawable_orca_amount,
+                true,
+            )?;
+        }
+    }
+    if withdraw_count == 0 {
+        return Err(ErrorCode::NotEnoughAccountKeys.into());
+    }
+
+    // Pre-check escrow underflow before CPI
+    {
+        let state_data = assert_account_data::<State>(state_account)?;
+        if state_data.escrowed_orca_amount < withdrawable_orca_amount {
+            return Err(ErrorCode::InsufficientEscrow.into());
+        }
+    }
+
+    // Transfer the total withdrawable stake tokens from xOrca state ATA to unstaker ATA
+    let transfer_instruction = Transfer {
+        from: vault_account,
+        to: unstaker_orca_ata,
+        authority: state_account,
+        amount: withdrawable_orca_amount,
+    };
+    transfer_instruction.invoke_signed(&[state_seeds.as_slice().into()])?;
+
+    // Close every pending_withdraw account and refund lamports to unstaker
+    for pending_withdraw_account in remaining_accounts
+        .iter()
+        .filter(|account| !is_staker_account(account))
+    {
+        close_program_account(pending_withdraw_account, unstaker_account)?;
+    }
+
+    // Remove tokens from escrow
+    let mut state = assert_account_data_mut::<State>(state_account)?;
+    state.escrowed_orca_amount = state
+        .escrowed_orca_amount
+        .checked_sub(withdrawable_orca_amount)
+        .ok_or(ErrorCode::InsufficientEscrow)?;
+
+    Event::WithdrawMany {
+        vault_escrowed_orca_amount: &state.escrowed_orca_amount,
+        withdrawable_orca_amount: &withdrawable_orca_amount,
+        withdraw_count: &withdraw_count,
+    }
+    .emit()?;
+
+    Ok(())
+}
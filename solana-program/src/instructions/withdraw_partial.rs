@@ -0,0 +1,174 @@
+use crate::{
+    assertions::account::{
+        assert_account_address, assert_account_data, assert_account_data_mut, assert_account_owner,
+        assert_account_role, make_owner_token_account_assertions, AccountRole,
+    },
+    assertions::state::assert_not_paused,
+    cpi::{system::get_current_unix_timestamp, token::ORCA_MINT_ID},
+    error::ErrorCode,
+    event::Event,
+    state::{
+        pending_withdraw::PendingWithdraw,
+        state::{State, PAUSE_WITHDRAW},
+    },
+    util::{
+        account::{close_program_account, get_account_info},
+        staker_account::record_staker_settlement,
+    },
+};
+use pinocchio::{account_info::AccountInfo, instruction::Seed, ProgramResult};
+use pinocchio_system::ID as SYSTEM_PROGRAM_ID;
+use pinocchio_token::{instructions::Transfer, ID as SPL_TOKEN_PROGRAM_ID};
+
+pub fn process_instruction(
+    accounts: &[AccountInfo],
+    withdraw_index: &u8,
+    orca_withdraw_amount: &u64,
+) -> ProgramResult {
+    let unstaker_account = get_account_info(accounts, 0)?;
+    let state_account = get_account_info(accounts, 1)?;
+    let pending_withdraw_account = get_account_info(accounts, 2)?;
+    let unstaker_orca_ata = get_account_info(accounts, 3)?;
+    let vault_account = get_account_info(accounts, 4)?;
+    let orca_mint_account = get_account_info(accounts, 5)?;
+    let system_program_account = get_account_info(accounts, 6)?;
+    let token_program_account = get_account_info(accounts, 7)?;
+    // Optional staker account, required when the pending withdraw is staker-indexed
+    let staker_accounts = accounts.get(8..).unwrap_or_default();
+
+    // 0. Pause Assertions
+    assert_not_paused(state_account, PAUSE_WITHDRAW)?;
+
+    // 1. Unstaker Account Assertions
+    assert_account_role(
+        unstaker_account,
+        &[AccountRole::Signer, AccountRole::Writable],
+    )?;
+
+    // 2. Orca Mint Account Assertions
+    assert_account_address(orca_mint_account, &ORCA_MINT_ID)?;
+    assert_account_owner(orca_mint_account, &SPL_TOKEN_PROGRAM_ID)?;
+
+    // 3. Xorca State Account Assertions
+    assert_account_role(state_account, &[AccountRole::Writable])?;
+    assert_account_owner(state_account, &crate::ID)?;
+    let mut state_seeds = State::seeds();
+    let state_bump_value = {
+        let state_view = assert_account_data::<State>(state_account)?;
+
+        State::verify_address_with_bump(state_account, &crate::ID, state_view.bump)
+            .map_err(|_| ErrorCode::InvalidSeeds)?;
+
+        // Verify vault address using stored vault_bump
+        State::verify_vault_address_with_bump(
+            state_account,
+            vault_account,
+            orca_mint_account,
+            state_view.vault_bump,
+        )
+        .map_err(|_| ErrorCode::InvalidSeeds)?;
+
+        state_view.bump
+    };
+
+    let bump_bytes = [state_bump_value];
+    state_seeds.push(Seed::from(&bump_bytes));
+
+    // 4. Pending Withdraw Account Assertions
+    assert_account_role(pending_withdraw_account, &[AccountRole::Writable])?;
+    assert_account_owner(pending_withdraw_account, &crate::ID)?;
+    let withdraw_index_bytes = [*withdraw_index];
+    let (withdrawable_orca_amount, withdrawable_timestamp, staker_indexed, unstaker) = {
+        let pending_withdraw_data =
+            assert_account_data::<PendingWithdraw>(pending_withdraw_account)?;
+        pending_withdraw_data.verify_address_and_owner(
+            pending_withdraw_account,
+            unstaker_account.key(),
+            &withdraw_index_bytes,
+        )?;
+        (
+            pending_withdraw_data.withdrawable_orca_amount,
+            pending_withdraw_data.withdrawable_timestamp,
+            pending_withdraw_data.is_staker_indexed(),
+            pending_withdraw_data.unstaker,
+        )
+    };
+
+    // 5. Unstaker Stake Token Account Assertions
+    make_owner_token_account_assertions(
+        unstaker_orca_ata,
+        unstaker_account,
+        orca_mint_account,
+        true,
+    )?;
+
+    // 6. Vault Account Assertions
+    make_owner_token_account_assertions(vault_account, state_account, orca_mint_account, true)?;
+
+    // 7. System Program Account Assertions
+    assert_account_address(system_program_account, &SYSTEM_PROGRAM_ID)?;
+
+    // 8. Token Program Account Assertions
+    assert_account_address(token_program_account, &SPL_TOKEN_PROGRAM_ID)?;
+
+    // Validate pending withdraw
+    let current_unix_timestamp = get_current_unix_timestamp()?;
+    if current_unix_timestamp < withdrawable_timestamp {
+        return Err(ErrorCode::CoolDownPeriodStillActive.into());
+    }
+    if *orca_withdraw_amount == 0 || *orca_withdraw_amount > withdrawable_orca_amount {
+        return Err(ErrorCode::InvalidWithdrawAmount.into());
+    }
+    let remaining_orca_amount = withdrawable_orca_amount - *orca_withdraw_amount;
+
+    // Pre-check escrow underflow before CPI
+    {
+        let state_data = assert_account_data::<State>(state_account)?;
+        if state_data.escrowed_orca_amount < *orca_withdraw_amount {
+            return Err(ErrorCode::InsufficientEscrow.into());
+        }
+    }
+
+    // Transfer the requested stake tokens from xOrca state ATA to unstaker ATA
+    let transfer_instruction = Transfer {
+        from: vault_account,
+        to: unstaker_orca_ata,
+        authority: state_account,
+        amount: *orca_withdraw_amount,
+    };
+    transfer_instruction.invoke_signed(&[state_seeds.as_slice().into()])?;
+
+    // Close the pending_withdraw account once fully drained, otherwise keep the remainder claimable
+    if remaining_orca_amount == 0 {
+        close_program_account(pending_withdraw_account, unstaker_account)?;
+    } else {
+        let mut pending_withdraw_data =
+            assert_account_data_mut::<PendingWithdraw>(pending_withdraw_account)?;
+        pending_withdraw_data.withdrawable_orca_amount = remaining_orca_amount;
+    }
+    if staker_indexed {
+        record_staker_settlement(
+            staker_accounts,
+            &unstaker,
+            *orca_withdraw_amount,
+            remaining_orca_amount == 0,
+        )?;
+    }
+
+    // Remove tokens from escrow
+    let mut state = assert_account_data_mut::<State>(state_account)?;
+    state.escrowed_orca_amount = state
+        .escrowed_orca_amount
+        .checked_sub(*orca_withdraw_amount)
+        .ok_or(ErrorCode::InsufficientEscrow)?;
+
+    Event::WithdrawPartial {
+        vault_escrowed_orca_amount: &state.escrowed_orca_amount,
+        orca_withdraw_amount,
+        remaining_orca_amount: &remaining_orca_amount,
+        withdraw_index,
+    }
+    .emit()?;
+
+    Ok(())
+}
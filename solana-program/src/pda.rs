@@ -1,3 +1,4 @@
+use crate::cpi::loader::BPF_LOADER_UPGRADEABLE_ID;
 use pinocchio::{instruction::Seed, pubkey::Pubkey};
 use pinocchio_associated_token_account::ID as ATA_PROGRAM_ID;
 
@@ -34,6 +35,19 @@ pub mod seeds {
             .collect()
     }
 
+    /// StakerAccount seeds - returns raw byte arrays for derive_address
+    pub fn staker_account_seeds_raw(staker: &Pubkey) -> [&[u8]; 2] {
+        [b"staker", staker.as_ref()]
+    }
+
+    /// StakerAccount seeds - returns Seeds for invoke_signed
+    pub fn staker_account_seeds(staker: &Pubkey) -> Vec<Seed<'_>> {
+        staker_account_seeds_raw(staker)
+            .into_iter()
+            .map(Seed::from)
+            .collect()
+    }
+
     /// Vault (ATA) seeds - returns raw byte arrays for derive_address
     pub fn vault_seeds_raw<'a>(
         state: &'a Pubkey,
@@ -54,6 +68,11 @@ pub mod seeds {
             .map(Seed::from)
             .collect()
     }
+
+    /// ProgramData seeds under the BPF Upgradeable Loader - returns raw byte arrays
+    pub fn program_data_seeds_raw(program_id: &Pubkey) -> [&[u8]; 1] {
+        [program_id.as_ref()]
+    }
 }
 
 /// Helper functions for finding PDA addresses
@@ -73,6 +92,12 @@ pub mod addresses {
         find_program_address(&seeds, &crate::ID)
     }
 
+    /// Find the staker account address and bump
+    pub fn find_staker_account_address(staker: &Pubkey) -> (Pubkey, u8) {
+        let seeds = seeds::staker_account_seeds_raw(staker);
+        find_program_address(&seeds, &crate::ID)
+    }
+
     /// Find the vault (ATA) address and bump for a given state
     pub fn find_vault_address(
         state: &Pubkey,
@@ -82,6 +107,12 @@ pub mod addresses {
         let seeds = seeds::vault_seeds_raw(state, token_program, orca_mint);
         find_program_address(&seeds, &ATA_PROGRAM_ID)
     }
+
+    /// Find this program's ProgramData account address and bump
+    pub fn find_program_data_address() -> (Pubkey, u8) {
+        let seeds = seeds::program_data_seeds_raw(&crate::ID);
+        find_program_address(&seeds, &BPF_LOADER_UPGRADEABLE_ID)
+    }
 }
 
 /// Re-export commonly used functions for convenience
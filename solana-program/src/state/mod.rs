@@ -4,6 +4,7 @@ use shank::ShankType;
 use strum::Display;
 
 pub mod pending_withdraw;
+pub mod staker_account;
 pub mod state;
 
 #[derive(
@@ -15,6 +16,7 @@ pub enum AccountDiscriminator {
     State,           // 1
     PendingWithdraw, // 2
     Closed,          // 3
+    StakerAccount,   // 4
 }
 
 // Program accounts must be bytemuck <> borsh interoperable. If repr(C) is used, the struct
@@ -24,6 +26,11 @@ pub enum AccountDiscriminator {
 pub trait ProgramAccount: BorshSerialize + BorshDeserialize {
     const LEN: usize;
     const DISCRIMINATOR: AccountDiscriminator;
+    // Layout version new accounts are created at and Migrate upgrades to. Version 0 is the
+    // layout from before versioning, whose version byte was padding.
+    const VERSION: u8;
+    // Offset of the version byte in the account data
+    const VERSION_OFFSET: usize;
 
     fn from_bytes(bytes: Ref<'_, [u8]>) -> Ref<'_, Self> {
         Ref::map(bytes, |bytes| unsafe { &*(bytes.as_ptr() as *const Self) })
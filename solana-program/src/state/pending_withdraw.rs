@@ -7,38 +7,55 @@ use crate::error::ErrorCode;
 
 use super::{AccountDiscriminator, ProgramAccount};
 
-const PENDING_WITHDRAW_LEN: usize = 1024;
+const PENDING_WITHDRAW_LEN: usize = 128;
+// Size of pending withdraws created before the compact v2 layout. Their fields sit at the same
+// offsets, so they are read through the same struct until Migrate shrinks them.
+pub const LEGACY_PENDING_WITHDRAW_LEN: usize = 1024;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, BorshSerialize, BorshDeserialize, ShankAccount)]
 #[repr(C)]
 pub struct PendingWithdraw {
     pub discriminator: AccountDiscriminator, // 1 byte
+    // 1 when the address is seeded by staker_withdraw_index from a StakerAccount,
+    // 0 when it is seeded by the one-byte withdraw_index
+    pub staker_indexed: u8, // 1 byte
+    // Layout version, see ProgramAccount::VERSION
+    pub version: u8, // 1 byte
     // Explicit padding to ensure that the next field (u64) is 8-byte aligned
     // in memory when #[repr(C)] is used.
-    // Calculation: use 5 bytes + 1-byte bump + 1-byte withdraw_index to reach 8-byte alignment.
-    pub padding1: [u8; 5],
+    // Calculation: use 3 bytes + 1-byte bump + 1-byte withdraw_index to reach 8-byte alignment.
+    pub padding1: [u8; 3],
     // Cached bump for PDA derivation of this pending withdraw account
     pub bump: u8,                      // 1 byte
     pub withdraw_index: u8,            // 1 byte
     pub unstaker: Pubkey,              // 32 bytes
     pub withdrawable_orca_amount: u64, // 8 bytes
     pub withdrawable_timestamp: i64,   // 8 bytes
-    // Remaining bytes to fill PENDING_WITHDRAW_LEN
-    // Calculation: PENDING_WITHDRAW_LEN - (1 + 5 + 1 + 1 + 32 + 8 + 8) = 968 bytes.
-    pub padding2: [u8; 968],
+    // Current holder of the position; all zeros means the unstaker still holds it
+    pub owner: Pubkey, // 32 bytes
+    // Index from the staker account seeding the address when staker_indexed is set;
+    // withdraw_index then holds its lowest byte
+    pub staker_withdraw_index: u64, // 8 bytes
+    // Reserved bytes to fill PENDING_WITHDRAW_LEN
+    // Calculation: PENDING_WITHDRAW_LEN - (1 + 1 + 1 + 3 + 1 + 1 + 32 + 8 + 8 + 32 + 8) = 32 bytes.
+    pub padding2: [u8; 32],
 }
 
 impl Default for PendingWithdraw {
     fn default() -> Self {
         Self {
             discriminator: AccountDiscriminator::PendingWithdraw,
-            padding1: [0; 5],
+            staker_indexed: 0,
+            version: PendingWithdraw::VERSION,
+            padding1: [0; 3],
             bump: 0,
             withdraw_index: 0,
             unstaker: [0; 32],
             withdrawable_orca_amount: 0,
             withdrawable_timestamp: 0,
-            padding2: [0; 968],
+            owner: [0; 32],
+            staker_withdraw_index: 0,
+            padding2: [0; 32],
         }
     }
 }
@@ -66,11 +83,74 @@ impl PendingWithdraw {
         }
         Ok(())
     }
+
+    /// The key allowed to withdraw, cancel, merge or transfer this position. Accounts created
+    /// before ownership was tracked have no stored owner and belong to the unstaker.
+    pub fn owner(&self) -> &Pubkey {
+        if self.owner == [0; 32] {
+            &self.unstaker
+        } else {
+            &self.owner
+        }
+    }
+
+    /// Upgrades the layout one version at a time up to `PendingWithdraw::VERSION`.
+    pub fn migrate(&mut self) {
+        if self.version == 0 {
+            // v1: the holder is stored instead of falling back to the unstaker
+            if self.owner == Pubkey::default() {
+                self.owner = self.unstaker;
+            }
+            self.version = 1;
+        }
+        if self.version == 1 {
+            // v2: compact account; the fields keep their offsets and Migrate shrinks the
+            // account to PENDING_WITHDRAW_LEN
+            self.version = 2;
+        }
+    }
+
+    pub fn is_staker_indexed(&self) -> bool {
+        self.staker_indexed != 0
+    }
+
+    /// Verifies the account address from the stored unstaker and bump, then that `owner`
+    /// currently holds the position. Staker-indexed positions are seeded by their stored
+    /// index, and `withdraw_index` must match its lowest byte.
+    pub fn verify_address_and_owner(
+        &self,
+        account: &pinocchio::account_info::AccountInfo,
+        owner: &Pubkey,
+        withdraw_index: &[u8],
+    ) -> Result<(), ErrorCode> {
+        let staker_withdraw_index_bytes = self.staker_withdraw_index.to_le_bytes();
+        let withdraw_index_seed = if self.is_staker_indexed() {
+            if withdraw_index != [self.withdraw_index] {
+                return Err(ErrorCode::InvalidSeeds);
+            }
+            &staker_withdraw_index_bytes[..]
+        } else {
+            withdraw_index
+        };
+        Self::verify_address_with_bump(
+            account,
+            &self.unstaker,
+            withdraw_index_seed,
+            &crate::ID,
+            self.bump,
+        )?;
+        if self.owner() != owner {
+            return Err(ErrorCode::InvalidPendingWithdrawOwner);
+        }
+        Ok(())
+    }
 }
 
 impl ProgramAccount for PendingWithdraw {
     const LEN: usize = PENDING_WITHDRAW_LEN;
     const DISCRIMINATOR: AccountDiscriminator = AccountDiscriminator::PendingWithdraw;
+    const VERSION: u8 = 2;
+    const VERSION_OFFSET: usize = 2;
 }
 #[cfg(test)]
 mod tests {
@@ -84,13 +164,17 @@ mod tests {
         // are correctly serialized/deserialized and reinterpreted.
         let expected = PendingWithdraw {
             discriminator: AccountDiscriminator::PendingWithdraw,
-            padding1: [0xAA; 5],
+            staker_indexed: 0x01,
+            version: 0x02,
+            padding1: [0xAA; 3],
             bump: 0x45,
             withdraw_index: 0x33,
             unstaker: Pubkey::default(),
             withdrawable_orca_amount: 0x1122334455667788,
             withdrawable_timestamp: 0x0123456789ABCDEF,
-            padding2: [0xCC; 968],
+            owner: [0x5A; 32],
+            staker_withdraw_index: 0x0F1E2D3C4B5A6978,
+            padding2: [0xCC; 32],
         };
 
         // 1. Serialize the struct using Borsh.
@@ -127,6 +211,8 @@ mod tests {
 
         // 4. Assert that all fields (including padding) match.
         assert_eq!(actual.discriminator, expected.discriminator);
+        assert_eq!(actual.staker_indexed, expected.staker_indexed);
+        assert_eq!(actual.version, expected.version, "Version mismatch");
         assert_eq!(actual.padding1, expected.padding1, "Padding1 mismatch");
         assert_eq!(actual.bump, expected.bump, "Bump mismatch");
         assert_eq!(
@@ -141,6 +227,8 @@ mod tests {
             actual.withdrawable_timestamp,
             expected.withdrawable_timestamp
         );
+        assert_eq!(actual.owner, expected.owner, "Owner mismatch");
+        assert_eq!(actual.staker_withdraw_index, expected.staker_withdraw_index);
         assert_eq!(actual.padding2, expected.padding2, "Padding2 mismatch");
 
         // 5. Sanity check: Ensure standard Borsh deserialization also works as expected.
@@ -151,16 +239,80 @@ mod tests {
     #[test]
     fn test_pending_withdraw_calculated_sizes() {
         let core_data_with_internal_padding_size: usize = size_of::<AccountDiscriminator>() // 1 byte
-            + size_of::<[u8; 5]>() // 5 bytes (padding1)
+            + size_of::<u8>() // 1 byte (staker_indexed)
+            + size_of::<u8>() // 1 byte (version)
+            + size_of::<[u8; 3]>() // 3 bytes (padding1)
             + size_of::<u8>() // 1 byte (bump)
             + size_of::<u8>() // 1 byte (withdraw_index)
+            + size_of::<Pubkey>() // 32 bytes (unstaker)
             + size_of::<u64>() // 8 bytes
-            + size_of::<i64>(); // 8 bytes
-        assert_eq!(core_data_with_internal_padding_size, 24);
+            + size_of::<i64>() // 8 bytes
+            + size_of::<Pubkey>() // 32 bytes (owner)
+            + size_of::<u64>(); // 8 bytes (staker_withdraw_index)
+        assert_eq!(core_data_with_internal_padding_size, 96);
         let total_calculated_struct_size =
-            core_data_with_internal_padding_size + size_of::<[u8; 1000]>();
+            core_data_with_internal_padding_size + size_of::<[u8; 32]>();
+        assert_eq!(total_calculated_struct_size, 128);
         assert_eq!(total_calculated_struct_size, PENDING_WITHDRAW_LEN);
         assert_eq!(size_of::<PendingWithdraw>(), PENDING_WITHDRAW_LEN);
         assert_eq!(size_of::<PendingWithdraw>(), total_calculated_struct_size);
     }
+
+    #[test]
+    fn test_pending_withdraw_owner_defaults_to_unstaker() {
+        let mut pending_withdraw = PendingWithdraw {
+            unstaker: [1; 32],
+            ..PendingWithdraw::default()
+        };
+        assert_eq!(pending_withdraw.owner(), &[1; 32]);
+
+        pending_withdraw.owner = [2; 32];
+        assert_eq!(pending_withdraw.owner(), &[2; 32]);
+    }
+
+    #[test]
+    fn test_pending_withdraw_migrate_stores_owner() {
+        let mut pending_withdraw = PendingWithdraw {
+            version: 0,
+            unstaker: [1; 32],
+            ..PendingWithdraw::default()
+        };
+        pending_withdraw.migrate();
+        assert_eq!(pending_withdraw.version, PendingWithdraw::VERSION);
+        assert_eq!(pending_withdraw.owner, [1; 32]);
+
+        // Transferred positions keep their holder
+        let mut pending_withdraw = PendingWithdraw {
+            version: 0,
+            unstaker: [1; 32],
+            owner: [2; 32],
+            ..PendingWithdraw::default()
+        };
+        pending_withdraw.migrate();
+        assert_eq!(pending_withdraw.owner, [2; 32]);
+    }
+
+    #[test]
+    fn test_pending_withdraw_legacy_layout_reads_as_compact() {
+        let expected = PendingWithdraw {
+            version: 1,
+            bump: 0x45,
+            withdraw_index: 0x33,
+            unstaker: [1; 32],
+            withdrawable_orca_amount: 0x1122334455667788,
+            withdrawable_timestamp: 0x0123456789ABCDEF,
+            owner: [2; 32],
+            ..PendingWithdraw::default()
+        };
+        let mut bytes = borsh::to_vec(&expected).unwrap();
+        bytes.resize(LEGACY_PENDING_WITHDRAW_LEN, 0);
+
+        let actual = unsafe { &*(bytes.as_ptr() as *const PendingWithdraw) };
+        assert_eq!(actual, &expected);
+
+        let mut migrated = *actual;
+        migrated.migrate();
+        assert_eq!(migrated.version, PendingWithdraw::VERSION);
+        assert_eq!(migrated.owner, [2; 32]);
+    }
 }
@@ -0,0 +1,212 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{instruction::Seed, pubkey::Pubkey};
+use pinocchio_pubkey::derive_address;
+use shank::ShankAccount;
+
+use crate::error::ErrorCode;
+
+use super::{AccountDiscriminator, ProgramAccount};
+
+const STAKER_ACCOUNT_LEN: usize = 512;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, BorshSerialize, BorshDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct StakerAccount {
+    pub discriminator: AccountDiscriminator, // 1 byte
+    // Layout version, see ProgramAccount::VERSION
+    pub version: u8, // 1 byte
+    // Explicit padding to ensure that the next field (Pubkey) is 8-byte aligned
+    // in memory when #[repr(C)] is used.
+    // Calculation: use 1-byte version + 5 bytes + 1-byte bump to reach 8-byte alignment.
+    pub padding1: [u8; 5],
+    // Cached bump for PDA derivation of this staker account
+    pub bump: u8,       // 1 byte
+    pub staker: Pubkey, // 32 bytes
+    // Index seeding the next pending withdraw created through this account
+    pub next_withdraw_index: u64, // 8 bytes
+    // Pending withdraws created through this account that are not yet closed
+    pub open_withdraw_count: u64, // 8 bytes
+    // Lifetime totals over the pending withdraws created through this account
+    pub total_xorca_unstaked: u64, // 8 bytes
+    pub total_orca_unstaked: u64,  // 8 bytes
+    pub total_orca_withdrawn: u64, // 8 bytes
+    // Remaining bytes to fill STAKER_ACCOUNT_LEN
+    // Calculation: STAKER_ACCOUNT_LEN - (1 + 1 + 5 + 1 + 32 + 8 + 8 + 8 + 8 + 8) = 432 bytes.
+    pub padding2: [u8; 432],
+}
+
+impl Default for StakerAccount {
+    fn default() -> Self {
+        Self {
+            discriminator: AccountDiscriminator::StakerAccount,
+            version: StakerAccount::VERSION,
+            padding1: [0; 5],
+            bump: 0,
+            staker: [0; 32],
+            next_withdraw_index: 0,
+            open_withdraw_count: 0,
+            total_xorca_unstaked: 0,
+            total_orca_unstaked: 0,
+            total_orca_withdrawn: 0,
+            padding2: [0; 432],
+        }
+    }
+}
+
+impl StakerAccount {
+    pub fn seeds(staker: &Pubkey) -> Vec<Seed<'_>> {
+        crate::pda::staker_account_seeds(staker)
+    }
+
+    pub fn verify_address_with_bump(
+        account: &pinocchio::account_info::AccountInfo,
+        staker: &Pubkey,
+        program_id: &Pubkey,
+        stored_bump: u8,
+    ) -> Result<(), ErrorCode> {
+        let derived_address = derive_address(
+            &crate::pda::staker_account_seeds_raw(staker),
+            Some(stored_bump),
+            program_id,
+        );
+        if account.key() != &derived_address {
+            return Err(ErrorCode::InvalidSeeds);
+        }
+        Ok(())
+    }
+
+    /// Upgrades the layout one version at a time up to `StakerAccount::VERSION`.
+    pub fn migrate(&mut self) {
+        if self.version == 0 {
+            // v1: the version byte is set; no fields change
+            self.version = 1;
+        }
+    }
+
+    /// Takes the next withdraw index for a new pending withdraw and adds it to the totals.
+    pub fn record_unstake(
+        &mut self,
+        xorca_unstake_amount: u64,
+        withdrawable_orca_amount: u64,
+    ) -> Result<u64, ErrorCode> {
+        let withdraw_index = self.next_withdraw_index;
+        self.next_withdraw_index = withdraw_index
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        self.open_withdraw_count = self
+            .open_withdraw_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        self.total_xorca_unstaked = self
+            .total_xorca_unstaked
+            .checked_add(xorca_unstake_amount)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        self.total_orca_unstaked = self
+            .total_orca_unstaked
+            .checked_add(withdrawable_orca_amount)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        Ok(withdraw_index)
+    }
+
+    /// Records ORCA paid out of one of this account's pending withdraws, and whether the
+    /// pending withdraw was closed.
+    pub fn record_settlement(
+        &mut self,
+        orca_withdrawn: u64,
+        closed: bool,
+    ) -> Result<(), ErrorCode> {
+        self.total_orca_withdrawn = self
+            .total_orca_withdrawn
+            .checked_add(orca_withdrawn)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        if closed {
+            self.open_withdraw_count = self
+                .open_withdraw_count
+                .checked_sub(1)
+                .ok_or(ErrorCode::ArithmeticError)?;
+        }
+        Ok(())
+    }
+}
+
+impl ProgramAccount for StakerAccount {
+    const LEN: usize = STAKER_ACCOUNT_LEN;
+    const DISCRIMINATOR: AccountDiscriminator = AccountDiscriminator::StakerAccount;
+    const VERSION: u8 = 1;
+    const VERSION_OFFSET: usize = 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use borsh::BorshDeserialize;
+    use std::mem::size_of;
+
+    #[test]
+    fn test_staker_account_byte_alignment() {
+        let expected = StakerAccount {
+            discriminator: AccountDiscriminator::StakerAccount,
+            version: 0x01,
+            padding1: [0xAA; 5],
+            bump: 0x45,
+            staker: [0x11; 32],
+            next_withdraw_index: 0x0102030405060708,
+            open_withdraw_count: 0x1112131415161718,
+            total_xorca_unstaked: 0x2122232425262728,
+            total_orca_unstaked: 0x3132333435363738,
+            total_orca_withdrawn: 0x4142434445464748,
+            padding2: [0xCC; 432],
+        };
+
+        let bytes = borsh::to_vec(&expected).unwrap();
+        assert_eq!(bytes.len(), StakerAccount::LEN);
+        assert_eq!(bytes.len(), size_of::<StakerAccount>());
+
+        let actual = unsafe {
+            let ptr = bytes.as_ptr() as *const StakerAccount;
+            &*ptr
+        };
+        assert_eq!(*actual, expected);
+
+        let deserialized = StakerAccount::try_from_slice(&bytes).unwrap();
+        assert_eq!(deserialized, expected);
+    }
+
+    #[test]
+    fn test_staker_account_calculated_sizes() {
+        let core_data_with_internal_padding_size: usize = size_of::<AccountDiscriminator>() // 1 byte
+            + size_of::<u8>() // 1 byte (version)
+            + size_of::<[u8; 5]>() // 5 bytes (padding1)
+            + size_of::<u8>() // 1 byte (bump)
+            + size_of::<Pubkey>() // 32 bytes (staker)
+            + 5 * size_of::<u64>(); // 40 bytes (index, count and totals)
+        assert_eq!(core_data_with_internal_padding_size, 80);
+        assert_eq!(
+            core_data_with_internal_padding_size + size_of::<[u8; 432]>(),
+            STAKER_ACCOUNT_LEN
+        );
+        assert_eq!(size_of::<StakerAccount>(), STAKER_ACCOUNT_LEN);
+    }
+
+    #[test]
+    fn test_staker_account_records_unstakes_and_settlements() {
+        let mut staker_account = StakerAccount::default();
+        assert_eq!(staker_account.record_unstake(10, 20).unwrap(), 0);
+        assert_eq!(staker_account.record_unstake(1, 2).unwrap(), 1);
+        assert_eq!(staker_account.next_withdraw_index, 2);
+        assert_eq!(staker_account.open_withdraw_count, 2);
+        assert_eq!(staker_account.total_xorca_unstaked, 11);
+        assert_eq!(staker_account.total_orca_unstaked, 22);
+
+        staker_account.record_settlement(5, false).unwrap();
+        staker_account.record_settlement(15, true).unwrap();
+        assert_eq!(staker_account.open_withdraw_count, 1);
+        assert_eq!(staker_account.total_orca_withdrawn, 20);
+
+        staker_account.record_settlement(0, true).unwrap();
+        assert_eq!(
+            staker_account.record_settlement(0, true),
+            Err(ErrorCode::ArithmeticError)
+        );
+    }
+}
@@ -9,14 +9,32 @@ use crate::error::ErrorCode;
 
 const STATE_ACCOUNT_LEN: usize = 2048;
 
+// Bits of `State::pause_flags`. A set bit pauses the matching instructions.
+pub const PAUSE_STAKE: u16 = 1 << 0;
+pub const PAUSE_UNSTAKE: u16 = 1 << 1;
+pub const PAUSE_WITHDRAW: u16 = 1 << 2;
+pub const PAUSE_INSTANT_UNSTAKE: u16 = 1 << 3;
+pub const PAUSE_CANCEL_UNSTAKE: u16 = 1 << 4;
+pub const PAUSE_DEPOSIT_REWARDS: u16 = 1 << 5;
+pub const PAUSE_HARVEST: u16 = 1 << 6;
+
+// Upper bound on `State::cool_down_period_s` (30 days).
+pub const MAX_COOL_DOWN_PERIOD_S: i64 = 30 * 24 * 60 * 60;
+// Minimum delay between queueing a config change and applying it (2 days).
+pub const CONFIG_TIMELOCK_S: i64 = 2 * 24 * 60 * 60;
+// Upper bound on the vesting period of a reward deposit (30 days).
+pub const MAX_VESTING_PERIOD_S: i64 = 30 * 24 * 60 * 60;
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, BorshSerialize, BorshDeserialize, ShankAccount)]
 #[repr(C)]
 pub struct State {
     pub discriminator: AccountDiscriminator, // 1 byte
+    // Layout version, see ProgramAccount::VERSION
+    pub version: u8, // 1 byte
     // Explicit padding to ensure that the next field (u64) is 8-byte aligned
     // in memory when #[repr(C)] is used.
-    // Calculation: use 5 bytes + 1-byte bump + 1-byte vault_bump to reach 8-byte alignment.
-    pub padding1: [u8; 5],
+    // Calculation: 1-byte version + 4 bytes + 1-byte bump + 1-byte vault_bump reach 8-byte alignment.
+    pub padding1: [u8; 4],
     // Cached bump for PDA derivation of the state account.
     pub bump: u8, // 1 byte
     // Cached bump for vault ATA derivation
@@ -24,26 +42,207 @@ pub struct State {
     pub escrowed_orca_amount: u64, // 8 bytes
     pub cool_down_period_s: i64,   // 8 bytes
     pub update_authority: Pubkey,  // 32 bytes
-    // STATE_ACCOUNT_LEN (2048 bytes) - (1 + 5 + 1 + 1 + 8 + 8 + 32) = 1992 bytes.
-    pub padding2: [u8; 1992],
+    // Fee charged on instant unstakes, in basis points. The fee stays in the vault.
+    pub instant_unstake_fee_bps: u16, // 2 bytes
+    // Bitmask of paused instructions (see the PAUSE_* constants).
+    pub pause_flags: u16, // 2 bytes
+    // Authority proposed by the update authority. Becomes the update authority once it
+    // signs AcceptUpdateAuthority. Pubkey::default() when there is no pending proposal.
+    pub pending_update_authority: Pubkey, // 32 bytes
+    // Explicit padding to ensure that the next field (i64) is 8-byte aligned.
+    pub padding3: [u8; 4],
+    // Cooldown period queued by the update authority, applied by ApplyPendingConfig.
+    pub pending_cool_down_period_s: i64, // 8 bytes
+    // Unix timestamp from which the queued config can be applied. 0 when nothing is queued.
+    pub pending_config_activation_timestamp: i64, // 8 bytes
+    // Reward deposits vest linearly from vesting_start_timestamp to vesting_end_timestamp.
+    // vesting_rewards_amount is the amount still unvested at vesting_start_timestamp.
+    pub vesting_rewards_amount: u64,  // 8 bytes
+    pub vesting_start_timestamp: i64, // 8 bytes
+    pub vesting_end_timestamp: i64,   // 8 bytes
+    // Only account allowed to deposit rewards through DepositRewards.
+    pub rewards_authority: Pubkey, // 32 bytes
+    // Cumulative ORCA deposited through DepositRewards.
+    pub total_rewards_deposited: u64, // 8 bytes
+    // Owner of the xORCA token account that receives performance fees.
+    pub treasury: Pubkey, // 32 bytes
+    // Non-escrowed ORCA amount and xORCA supply at the highest exchange rate seen by Harvest.
+    // Yield is measured against this high-water mark. A zero supply means no checkpoint yet.
+    pub fee_checkpoint_orca_amount: u64,  // 8 bytes
+    pub fee_checkpoint_xorca_supply: u64, // 8 bytes
+    // Share of yield minted to the treasury as xORCA on Harvest, in basis points. Yield is any
+    // rise in the non-escrowed ORCA per xORCA: vested rewards, instant-unstake fees kept in the
+    // vault and ORCA sent to the vault directly.
+    pub performance_fee_bps: u16, // 2 bytes
+    // Explicit padding to ensure that the next field (u64) is 8-byte aligned.
+    pub padding4: [u8; 6],
+    // Maximum ORCA the vault may hold after a stake. 0 means unlimited.
+    pub max_vault_orca: u64, // 8 bytes
+    // Maximum ORCA a single stake may deposit. 0 means unlimited. When both caps are set
+    // it must not exceed max_vault_orca.
+    pub max_stake_per_tx: u64, // 8 bytes
+    // Smallest ORCA amount a stake may deposit. 0 means no minimum.
+    pub min_stake_orca: u64, // 8 bytes
+    // Smallest xORCA amount an unstake may burn. 0 means no minimum.
+    pub min_unstake_xorca: u64, // 8 bytes
+    // STATE_ACCOUNT_LEN (2048 bytes) - (1 + 1 + 4 + 1 + 1 + 8 + 8 + 32 + 2 + 2 + 32 + 4 + 8 + 8 + 8 + 8 + 8 + 32 + 8 + 32 + 8 + 8 + 2 + 6 + 8 + 8 + 8 + 8) = 1784 bytes.
+    pub padding2: [u8; 1784],
 }
 
 impl Default for State {
     fn default() -> Self {
         Self {
             discriminator: AccountDiscriminator::State,
-            padding1: [0; 5],
+            version: State::VERSION,
+            padding1: [0; 4],
             bump: 0,
             vault_bump: 0,
             escrowed_orca_amount: 0,
             update_authority: Pubkey::default(),
             cool_down_period_s: 0,
-            padding2: [0; 1992],
+            instant_unstake_fee_bps: 0,
+            pause_flags: 0,
+            pending_update_authority: Pubkey::default(),
+            padding3: [0; 4],
+            pending_cool_down_period_s: 0,
+            pending_config_activation_timestamp: 0,
+            vesting_rewards_amount: 0,
+            vesting_start_timestamp: 0,
+            vesting_end_timestamp: 0,
+            rewards_authority: Pubkey::default(),
+            total_rewards_deposited: 0,
+            treasury: Pubkey::default(),
+            fee_checkpoint_orca_amount: 0,
+            fee_checkpoint_xorca_supply: 0,
+            performance_fee_bps: 0,
+            padding4: [0; 6],
+            max_vault_orca: 0,
+            max_stake_per_tx: 0,
+            min_stake_orca: 0,
+            min_unstake_xorca: 0,
+            padding2: [0; 1784],
         }
     }
 }
 
 impl State {
+    /// Upgrades the layout one version at a time up to `State::VERSION`.
+    pub fn migrate(&mut self) {
+        if self.version == 0 {
+            // v1: the version byte is set; no fields change
+            self.version = 1;
+        }
+    }
+
+    pub fn is_paused(&self, pause_flag: u16) -> bool {
+        self.pause_flags & pause_flag != 0
+    }
+
+    /// Caps of 0 are unlimited; when both are set the per-transaction cap must fit in the vault cap.
+    pub fn assert_valid_stake_caps(&self) -> Result<(), ErrorCode> {
+        if self.max_vault_orca != 0
+            && self.max_stake_per_tx != 0
+            && self.max_stake_per_tx > self.max_vault_orca
+        {
+            return Err(ErrorCode::InvalidStakeCaps);
+        }
+        Ok(())
+    }
+
+    /// Checks a stake of `orca_stake_amount` into a vault currently holding `vault_orca_amount`
+    /// against the minimum stake and the deposit caps.
+    pub fn assert_stake_limits(
+        &self,
+        vault_orca_amount: u64,
+        orca_stake_amount: u64,
+    ) -> Result<(), ErrorCode> {
+        if orca_stake_amount < self.min_stake_orca {
+            return Err(ErrorCode::StakeAmountBelowMinimum);
+        }
+        self.assert_within_stake_caps(vault_orca_amount, orca_stake_amount)
+    }
+
+    /// Checks a stake of `orca_stake_amount` into a vault currently holding `vault_orca_amount`
+    /// against the configured deposit caps.
+    pub fn assert_within_stake_caps(
+        &self,
+        vault_orca_amount: u64,
+        orca_stake_amount: u64,
+    ) -> Result<(), ErrorCode> {
+        if self.max_stake_per_tx != 0 && orca_stake_amount > self.max_stake_per_tx {
+            return Err(ErrorCode::MaxStakePerTxExceeded);
+        }
+        if self.max_vault_orca != 0 {
+            let final_vault_orca_amount = vault_orca_amount
+                .checked_add(orca_stake_amount)
+                .ok_or(ErrorCode::ArithmeticError)?;
+            if final_vault_orca_amount > self.max_vault_orca {
+                return Err(ErrorCode::MaxVaultOrcaExceeded);
+            }
+        }
+        Ok(())
+    }
+
+    /// Portion of deposited rewards that has not vested yet at `current_unix_timestamp`.
+    /// It sits in the vault but is excluded from the exchange rate.
+    pub fn unvested_rewards_amount(&self, current_unix_timestamp: i64) -> Result<u64, ErrorCode> {
+        if self.vesting_rewards_amount == 0 || current_unix_timestamp >= self.vesting_end_timestamp
+        {
+            return Ok(0);
+        }
+        if current_unix_timestamp <= self.vesting_start_timestamp {
+            return Ok(self.vesting_rewards_amount);
+        }
+        let remaining_s = (self.vesting_end_timestamp - current_unix_timestamp) as u128;
+        let duration_s = (self.vesting_end_timestamp - self.vesting_start_timestamp) as u128;
+        let unvested_rewards_amount = (self.vesting_rewards_amount as u128)
+            .checked_mul(remaining_s)
+            .ok_or(ErrorCode::ArithmeticError)?
+            .checked_div(duration_s)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        unvested_rewards_amount
+            .try_into()
+            .map_err(|_| ErrorCode::ArithmeticError)
+    }
+
+    /// Adds a reward deposit to the vesting schedule. Rewards still vesting are rolled into
+    /// the new schedule, whose duration is the amount-weighted average of the remaining
+    /// duration and `vesting_period_s`, so small deposits cannot stretch out large ones.
+    pub fn add_vesting_rewards(
+        &mut self,
+        rewards_amount: u64,
+        vesting_period_s: i64,
+        current_unix_timestamp: i64,
+    ) -> Result<(), ErrorCode> {
+        let unvested_rewards_amount = self.unvested_rewards_amount(current_unix_timestamp)?;
+        let remaining_s = if unvested_rewards_amount == 0 {
+            0
+        } else {
+            self.vesting_end_timestamp - current_unix_timestamp
+        };
+        let vesting_rewards_amount = unvested_rewards_amount
+            .checked_add(rewards_amount)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        let duration_s = (unvested_rewards_amount as u128)
+            .checked_mul(remaining_s as u128)
+            .and_then(|weighted| {
+                weighted
+                    .checked_add((rewards_amount as u128).checked_mul(vesting_period_s as u128)?)
+            })
+            .and_then(|weighted| weighted.checked_div(vesting_rewards_amount as u128))
+            .ok_or(ErrorCode::ArithmeticError)?;
+        let duration_s: i64 = duration_s
+            .try_into()
+            .map_err(|_| ErrorCode::ArithmeticError)?;
+
+        self.vesting_rewards_amount = vesting_rewards_amount;
+        self.vesting_start_timestamp = current_unix_timestamp;
+        self.vesting_end_timestamp = current_unix_timestamp
+            .checked_add(duration_s)
+            .ok_or(ErrorCode::ArithmeticError)?;
+        Ok(())
+    }
+
     pub fn seeds<'a>() -> Vec<Seed<'a>> {
         crate::pda::seeds::state_seeds()
     }
@@ -96,6 +295,8 @@ impl State {
 impl ProgramAccount for State {
     const LEN: usize = STATE_ACCOUNT_LEN;
     const DISCRIMINATOR: AccountDiscriminator = AccountDiscriminator::State;
+    const VERSION: u8 = 1;
+    const VERSION_OFFSET: usize = 1;
 }
 
 #[cfg(test)]
@@ -110,13 +311,34 @@ mod tests {
         // are correctly serialized/deserialized and reinterpreted.
         let expected = State {
             discriminator: AccountDiscriminator::State,
-            padding1: [0xAA; 5],
+            version: 0x01,
+            padding1: [0xAA; 4],
             bump: 0x42,
             vault_bump: 0x43,
             escrowed_orca_amount: 0x1122334455667788,
             cool_down_period_s: 7 * 24 * 60 * 60,
             update_authority: Pubkey::default(),
-            padding2: [0xCC; 1992],
+            instant_unstake_fee_bps: 0x1234,
+            pause_flags: 0x5678,
+            pending_update_authority: [0xBB; 32],
+            padding3: [0xDD; 4],
+            pending_cool_down_period_s: 14 * 24 * 60 * 60,
+            pending_config_activation_timestamp: 0x0102030405060708,
+            vesting_rewards_amount: 0x1111222233334444,
+            vesting_start_timestamp: 1_700_000_000,
+            vesting_end_timestamp: 1_700_604_800,
+            rewards_authority: [0xEE; 32],
+            total_rewards_deposited: 0x5555666677778888,
+            treasury: [0x99; 32],
+            fee_checkpoint_orca_amount: 0x0A0B0C0D0E0F1011,
+            fee_checkpoint_xorca_supply: 0x1213141516171819,
+            performance_fee_bps: 0x0FA0,
+            padding4: [0xFF; 6],
+            max_vault_orca: 0x2122232425262728,
+            max_stake_per_tx: 0x3132333435363738,
+            min_stake_orca: 0x4142434445464748,
+            min_unstake_xorca: 0x5152535455565758,
+            padding2: [0xCC; 1784],
         };
 
         // 1. Serialize the struct using Borsh.
@@ -153,10 +375,58 @@ mod tests {
 
         // 4. Assert that all fields (including padding) match.
         assert_eq!(actual.discriminator, expected.discriminator);
+        assert_eq!(actual.version, expected.version, "Version mismatch");
         assert_eq!(actual.padding1, expected.padding1, "Padding1 mismatch");
         assert_eq!(actual.escrowed_orca_amount, expected.escrowed_orca_amount);
         assert_eq!(actual.cool_down_period_s, expected.cool_down_period_s);
         assert_eq!(actual.update_authority, expected.update_authority);
+        assert_eq!(
+            actual.instant_unstake_fee_bps,
+            expected.instant_unstake_fee_bps
+        );
+        assert_eq!(actual.pause_flags, expected.pause_flags);
+        assert_eq!(
+            actual.pending_update_authority,
+            expected.pending_update_authority
+        );
+        assert_eq!(actual.padding3, expected.padding3, "Padding3 mismatch");
+        assert_eq!(
+            actual.pending_cool_down_period_s,
+            expected.pending_cool_down_period_s
+        );
+        assert_eq!(
+            actual.pending_config_activation_timestamp,
+            expected.pending_config_activation_timestamp
+        );
+        assert_eq!(
+            actual.vesting_rewards_amount,
+            expected.vesting_rewards_amount
+        );
+        assert_eq!(
+            actual.vesting_start_timestamp,
+            expected.vesting_start_timestamp
+        );
+        assert_eq!(actual.vesting_end_timestamp, expected.vesting_end_timestamp);
+        assert_eq!(actual.rewards_authority, expected.rewards_authority);
+        assert_eq!(
+            actual.total_rewards_deposited,
+            expected.total_rewards_deposited
+        );
+        assert_eq!(actual.treasury, expected.treasury);
+        assert_eq!(
+            actual.fee_checkpoint_orca_amount,
+            expected.fee_checkpoint_orca_amount
+        );
+        assert_eq!(
+            actual.fee_checkpoint_xorca_supply,
+            expected.fee_checkpoint_xorca_supply
+        );
+        assert_eq!(actual.performance_fee_bps, expected.performance_fee_bps);
+        assert_eq!(actual.padding4, expected.padding4, "Padding4 mismatch");
+        assert_eq!(actual.max_vault_orca, expected.max_vault_orca);
+        assert_eq!(actual.max_stake_per_tx, expected.max_stake_per_tx);
+        assert_eq!(actual.min_stake_orca, expected.min_stake_orca);
+        assert_eq!(actual.min_unstake_xorca, expected.min_unstake_xorca);
         assert_eq!(actual.padding2, expected.padding2, "Padding2 mismatch");
 
         // 5. Sanity check: Ensure standard Borsh deserialization also works as expected.
@@ -164,22 +434,130 @@ mod tests {
         assert_eq!(deserialized_state, expected);
     }
 
+    #[test]
+    fn test_assert_within_stake_caps() {
+        let mut state = State::default();
+        assert_eq!(state.assert_within_stake_caps(u64::MAX - 1, 1), Ok(()));
+
+        state.max_stake_per_tx = 100;
+        state.max_vault_orca = 1_000;
+        assert_eq!(state.assert_within_stake_caps(900, 100), Ok(()));
+        assert_eq!(
+            state.assert_within_stake_caps(0, 101),
+            Err(ErrorCode::MaxStakePerTxExceeded)
+        );
+        assert_eq!(
+            state.assert_within_stake_caps(901, 100),
+            Err(ErrorCode::MaxVaultOrcaExceeded)
+        );
+    }
+
+    #[test]
+    fn test_assert_stake_limits() {
+        let state = State {
+            min_stake_orca: 10,
+            max_vault_orca: 1_000,
+            ..State::default()
+        };
+        assert_eq!(state.assert_stake_limits(0, 10), Ok(()));
+        assert_eq!(
+            state.assert_stake_limits(0, 9),
+            Err(ErrorCode::StakeAmountBelowMinimum)
+        );
+        assert_eq!(
+            state.assert_stake_limits(991, 10),
+            Err(ErrorCode::MaxVaultOrcaExceeded)
+        );
+    }
+
+    #[test]
+    fn test_assert_valid_stake_caps() {
+        let mut state = State {
+            max_stake_per_tx: u64::MAX,
+            ..State::default()
+        };
+        assert_eq!(state.assert_valid_stake_caps(), Ok(()));
+
+        state.max_vault_orca = 1_000;
+        assert_eq!(
+            state.assert_valid_stake_caps(),
+            Err(ErrorCode::InvalidStakeCaps)
+        );
+        state.max_stake_per_tx = 1_000;
+        assert_eq!(state.assert_valid_stake_caps(), Ok(()));
+    }
+
+    #[test]
+    fn test_unvested_rewards_amount_vests_linearly() {
+        let mut state = State::default();
+        state.add_vesting_rewards(1_000, 100, 1_000).unwrap();
+        assert_eq!(state.unvested_rewards_amount(1_000).unwrap(), 1_000);
+        assert_eq!(state.unvested_rewards_amount(1_025).unwrap(), 750);
+        assert_eq!(state.unvested_rewards_amount(1_099).unwrap(), 10);
+        assert_eq!(state.unvested_rewards_amount(1_100).unwrap(), 0);
+        assert_eq!(state.unvested_rewards_amount(9_999).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_add_vesting_rewards_rolls_over_unvested_rewards() {
+        let mut state = State::default();
+        state.add_vesting_rewards(1_000, 100, 1_000).unwrap();
+        // Halfway through, 500 is unvested with 50s left. Adding 500 over 150s gives
+        // (500 * 50 + 500 * 150) / 1000 = 100s for the combined 1000.
+        state.add_vesting_rewards(500, 150, 1_050).unwrap();
+        assert_eq!(state.vesting_rewards_amount, 1_000);
+        assert_eq!(state.vesting_start_timestamp, 1_050);
+        assert_eq!(state.vesting_end_timestamp, 1_150);
+
+        // A dust deposit with a longer period does not stretch the schedule
+        state.add_vesting_rewards(1, 1_000, 1_050).unwrap();
+        assert_eq!(state.vesting_rewards_amount, 1_001);
+        assert_eq!(state.vesting_end_timestamp, 1_150);
+
+        // Without vesting the deposit is available immediately
+        let mut state = State::default();
+        state.add_vesting_rewards(1_000, 0, 1_000).unwrap();
+        assert_eq!(state.unvested_rewards_amount(1_000).unwrap(), 0);
+    }
+
     #[test]
     fn test_state_calculated_sizes() {
         // 1. Calculate the expected size of the core data fields
         //    (excluding final padding2, but including padding1)
         let core_data_with_internal_padding_size: usize = size_of::<AccountDiscriminator>() // 1 byte
-            + size_of::<[u8; 6]>() // 6 bytes (padding1)
+            + size_of::<u8>() // 1 byte (version)
+            + size_of::<[u8; 4]>() // 4 bytes (padding1)
             + size_of::<u8>() // 1 byte (bump)
+            + size_of::<u8>() // 1 byte (vault_bump)
+            + size_of::<u64>() // 8 bytes
+            + size_of::<u64>() // 8 bytes
+            + size_of::<Pubkey>() // 32 bytes
+            + size_of::<u16>() // 2 bytes
+            + size_of::<u16>() // 2 bytes
+            + size_of::<Pubkey>() // 32 bytes
+            + size_of::<[u8; 4]>() // 4 bytes (padding3)
+            + size_of::<i64>() // 8 bytes
+            + size_of::<i64>() // 8 bytes
+            + size_of::<u64>() // 8 bytes
+            + size_of::<i64>() // 8 bytes
+            + size_of::<i64>() // 8 bytes
+            + size_of::<Pubkey>() // 32 bytes
+            + size_of::<u64>() // 8 bytes
+            + size_of::<Pubkey>() // 32 bytes
+            + size_of::<u64>() // 8 bytes
+            + size_of::<u64>() // 8 bytes
+            + size_of::<u16>() // 2 bytes
+            + size_of::<[u8; 6]>() // 6 bytes (padding4)
+            + size_of::<u64>() // 8 bytes
             + size_of::<u64>() // 8 bytes
             + size_of::<u64>() // 8 bytes
-            + size_of::<Pubkey>(); // 32 bytes
+            + size_of::<u64>(); // 8 bytes
 
-        // Expected sum: 1 + 7 + 8 + 8 + 32 = 56 bytes
-        assert_eq!(core_data_with_internal_padding_size, 56);
+        // Expected sum: 1 + 7 + 8 + 8 + 32 + 2 + 2 + 32 + 4 + 8 + 8 + 8 + 8 + 8 + 32 + 8 + 32 + 8 + 8 + 2 + 6 + 8 + 8 + 8 + 8 = 264 bytes
+        assert_eq!(core_data_with_internal_padding_size, 264);
 
         let total_calculated_struct_size =
-            core_data_with_internal_padding_size + size_of::<[u8; 1992]>();
+            core_data_with_internal_padding_size + size_of::<[u8; 1784]>();
 
         assert_eq!(total_calculated_struct_size, STATE_ACCOUNT_LEN);
         assert_eq!(size_of::<State>(), STATE_ACCOUNT_LEN);
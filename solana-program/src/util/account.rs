@@ -24,6 +24,14 @@ pub fn get_account_info(
     Ok(&accounts[index])
 }
 
+/// Returns an optional trailing account. Clients pass the program ID in place of an omitted
+/// optional account, so that is treated the same as a missing one.
+pub fn get_optional_account_info(accounts: &[AccountInfo], index: usize) -> Option<&AccountInfo> {
+    accounts
+        .get(index)
+        .filter(|account| account.key() != &crate::ID)
+}
+
 /// Secure account creation that handles pre-funded accounts to prevent DoS attacks.
 /// If the account already has lamports, uses transfer + allocate + assign pattern.
 /// This prevents attackers from pre-funding accounts to block creation.
@@ -94,6 +102,7 @@ pub fn create_program_account_secure<'a, T: ProgramAccount>(
     create_account_secure(funder, new_account, T::LEN, &crate::ID, signers)?;
     let mut data = new_account.try_borrow_mut_data()?;
     data[0] = T::DISCRIMINATOR as u8;
+    data[T::VERSION_OFFSET] = T::VERSION;
     Ok(T::from_bytes_mut(data))
 }
 
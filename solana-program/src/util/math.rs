@@ -4,6 +4,8 @@ use pinocchio::program_error::ProgramError;
 static VIRTUAL_XORCA_SUPPLY: u128 = 100;
 static VIRTUAL_NON_ESCROWED_ORCA_AMOUNT: u128 = 100;
 
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
 pub fn convert_orca_to_xorca(
     orca_amount_to_convert: u64,
     non_escrowed_orca_amount: u64,
@@ -83,3 +85,118 @@ pub fn convert_xorca_to_orca(
 
     Ok(out_orca_amount)
 }
+
+/// Round-up counterpart of `convert_orca_to_xorca`: the smallest xORCA amount that
+/// `convert_xorca_to_orca` turns into at least `orca_amount_to_convert`.
+pub fn convert_orca_to_xorca_round_up(
+    orca_amount_to_convert: u64,
+    non_escrowed_orca_amount: u64,
+    xorca_supply: u64,
+) -> Result<u64, ProgramError> {
+    if (xorca_supply == 0) || (non_escrowed_orca_amount == 0) {
+        return Ok(orca_amount_to_convert);
+    }
+
+    let xorca_supply_with_virtual_amount_u128 = (xorca_supply as u128)
+        .checked_add(VIRTUAL_XORCA_SUPPLY)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    let non_escrowed_orca_amount_with_virtual_amount_u128 = (non_escrowed_orca_amount as u128)
+        .checked_add(VIRTUAL_NON_ESCROWED_ORCA_AMOUNT)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    let out_xorca_amount_u128 = (orca_amount_to_convert as u128)
+        .checked_mul(xorca_supply_with_virtual_amount_u128)
+        .ok_or(ErrorCode::ArithmeticError)?
+        .checked_add(non_escrowed_orca_amount_with_virtual_amount_u128 - 1)
+        .ok_or(ErrorCode::ArithmeticError)?
+        .checked_div(non_escrowed_orca_amount_with_virtual_amount_u128)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    let out_xorca_amount: u64 = out_xorca_amount_u128
+        .try_into()
+        .map_err(|_| ErrorCode::ArithmeticError)?;
+
+    Ok(out_xorca_amount)
+}
+
+/// Round-up counterpart of `convert_xorca_to_orca`: the smallest ORCA amount that
+/// `convert_orca_to_xorca` turns into at least `xorca_amount_to_convert`.
+pub fn convert_xorca_to_orca_round_up(
+    xorca_amount_to_convert: u64,
+    non_escrowed_orca_amount: u64,
+    xorca_supply: u64,
+) -> Result<u64, ProgramError> {
+    // An empty vault stakes 1:1, as in `convert_orca_to_xorca`
+    if (xorca_supply == 0) || (non_escrowed_orca_amount == 0) {
+        return Ok(xorca_amount_to_convert);
+    }
+
+    let xorca_supply_with_virtual_amount_u128 = (xorca_supply as u128)
+        .checked_add(VIRTUAL_XORCA_SUPPLY)
+        .ok_or(ErrorCode::ArithmeticError)?;
+    let non_escrowed_orca_amount_with_virtual_amount_u128 = (non_escrowed_orca_amount as u128)
+        .checked_add(VIRTUAL_NON_ESCROWED_ORCA_AMOUNT)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    let out_orca_amount_u128 = (xorca_amount_to_convert as u128)
+        .checked_mul(non_escrowed_orca_amount_with_virtual_amount_u128)
+        .ok_or(ErrorCode::ArithmeticError)?
+        .checked_add(xorca_supply_with_virtual_amount_u128 - 1)
+        .ok_or(ErrorCode::ArithmeticError)?
+        .checked_div(xorca_supply_with_virtual_amount_u128)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    let out_orca_amount: u64 = out_orca_amount_u128
+        .try_into()
+        .map_err(|_| ErrorCode::ArithmeticError)?;
+
+    Ok(out_orca_amount)
+}
+
+pub fn calculate_instant_unstake_fee(
+    orca_amount: u64,
+    instant_unstake_fee_bps: u16,
+) -> Result<u64, ProgramError> {
+    if instant_unstake_fee_bps > BPS_DENOMINATOR {
+        return Err(ErrorCode::InvalidInstantUnstakeFee.into());
+    }
+
+    // Round the fee up so that dust always stays in the vault with the remaining stakers.
+    let bps_denominator_u128 = BPS_DENOMINATOR as u128;
+    let fee_orca_amount_u128 = (orca_amount as u128)
+        .checked_mul(instant_unstake_fee_bps as u128)
+        .ok_or(ErrorCode::ArithmeticError)?
+        .checked_add(bps_denominator_u128 - 1)
+        .ok_or(ErrorCode::ArithmeticError)?
+        .checked_div(bps_denominator_u128)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    // Cast the final u128 result back to u64.
+    let fee_orca_amount: u64 = fee_orca_amount_u128
+        .try_into()
+        .map_err(|_| ErrorCode::ArithmeticError)?;
+
+    Ok(fee_orca_amount)
+}
+
+pub fn calculate_performance_fee(
+    yield_orca_amount: u64,
+    performance_fee_bps: u16,
+) -> Result<u64, ProgramError> {
+    if performance_fee_bps > BPS_DENOMINATOR {
+        return Err(ErrorCode::InvalidPerformanceFee.into());
+    }
+
+    // Round the fee down so that dust always stays with the stakers.
+    let fee_orca_amount_u128 = (yield_orca_amount as u128)
+        .checked_mul(performance_fee_bps as u128)
+        .ok_or(ErrorCode::ArithmeticError)?
+        .checked_div(BPS_DENOMINATOR as u128)
+        .ok_or(ErrorCode::ArithmeticError)?;
+
+    let fee_orca_amount: u64 = fee_orca_amount_u128
+        .try_into()
+        .map_err(|_| ErrorCode::ArithmeticError)?;
+
+    Ok(fee_orca_amount)
+}
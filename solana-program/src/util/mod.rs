@@ -1,2 +1,5 @@
 pub mod account;
 pub mod math;
+pub mod stake;
+pub mod staker_account;
+pub mod unstake;
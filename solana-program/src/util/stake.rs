@@ -0,0 +1,208 @@
+use crate::{
+    assertions::account::{
+        assert_account_address, assert_account_data, assert_account_owner, assert_account_role,
+        assert_external_account_data, make_owner_token_account_assertions, AccountRole,
+    },
+    assertions::state::assert_not_paused,
+    cpi::{
+        system::get_current_unix_timestamp,
+        token::{TokenAccount, TokenMint, ORCA_MINT_ID, XORCA_MINT_ID},
+    },
+    error::ErrorCode,
+    event::Event,
+    state::state::{State, PAUSE_STAKE},
+    util::account::get_account_info,
+};
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, pubkey::Pubkey,
+    ProgramResult,
+};
+use pinocchio_token::{
+    instructions::{MintTo, Transfer},
+    ID as SPL_TOKEN_PROGRAM_ID,
+};
+
+/// Asserted accounts of a stake instruction together with the pool amounts the exchange
+/// rate is computed from. All stake variants share the same account layout.
+pub struct StakeAccounts<'a> {
+    staker_account: &'a AccountInfo,
+    vault_account: &'a AccountInfo,
+    staker_orca_ata: &'a AccountInfo,
+    staker_xorca_ata: &'a AccountInfo,
+    xorca_mint_account: &'a AccountInfo,
+    state_account: &'a AccountInfo,
+    state_bump: u8,
+    staker_orca_amount: u64,
+    beneficiary: Pubkey,
+    vault_orca_amount: u64,
+    escrowed_orca_amount: u64,
+    pub non_escrowed_orca_amount: u64,
+    pub xorca_supply: u64,
+}
+
+impl<'a> StakeAccounts<'a> {
+    /// Runs the account assertions shared by all stake variants. `stake_for` accepts any
+    /// xORCA token account as the destination; otherwise it must be the staker's own.
+    pub fn load(accounts: &'a [AccountInfo], stake_for: bool) -> Result<Self, ProgramError> {
+        let staker_account = get_account_info(accounts, 0)?;
+        let vault_account = get_account_info(accounts, 1)?;
+        let staker_orca_ata = get_account_info(accounts, 2)?;
+        let staker_xorca_ata = get_account_info(accounts, 3)?;
+        let xorca_mint_account = get_account_info(accounts, 4)?;
+        let state_account = get_account_info(accounts, 5)?;
+        let orca_mint_account = get_account_info(accounts, 6)?;
+        let token_program_account = get_account_info(accounts, 7)?;
+
+        // 0. Pause Assertions
+        assert_not_paused(state_account, PAUSE_STAKE)?;
+
+        // 1. Staker Account Assertions
+        assert_account_role(
+            staker_account,
+            &[AccountRole::Signer, AccountRole::Writable],
+        )?;
+
+        // 2. Account Address Assertions
+        assert_account_address(orca_mint_account, &ORCA_MINT_ID)?;
+        assert_account_address(xorca_mint_account, &XORCA_MINT_ID)?;
+        assert_account_address(token_program_account, &SPL_TOKEN_PROGRAM_ID)?;
+
+        // 3. Staker Orca ATA Assertions
+        let staker_orca_ata_data = make_owner_token_account_assertions(
+            staker_orca_ata,
+            staker_account,
+            orca_mint_account,
+            true,
+        )?;
+
+        // 4. Staker xORCA ATA Assertions
+        // StakeFor mints to any xORCA token account; the other variants require the staker's own
+        let beneficiary = if stake_for {
+            assert_account_role(staker_xorca_ata, &[AccountRole::Writable])?;
+            assert_account_owner(staker_xorca_ata, &SPL_TOKEN_PROGRAM_ID)?;
+            let beneficiary_xorca_ata_data =
+                assert_external_account_data::<TokenAccount>(staker_xorca_ata)?;
+            if beneficiary_xorca_ata_data.mint != *xorca_mint_account.key() {
+                return Err(ErrorCode::InvalidAccountData.into());
+            }
+            beneficiary_xorca_ata_data.owner
+        } else {
+            make_owner_token_account_assertions(
+                staker_xorca_ata,
+                staker_account,
+                xorca_mint_account,
+                true,
+            )?;
+            *staker_account.key()
+        };
+
+        // 5. xOrca Mint Account Assertions
+        assert_account_role(xorca_mint_account, &[AccountRole::Writable])?;
+        assert_account_owner(xorca_mint_account, &SPL_TOKEN_PROGRAM_ID)?;
+        let xorca_mint_data = assert_external_account_data::<TokenMint>(xorca_mint_account)?;
+
+        // 6. Orca Mint Account Assertions
+        assert_account_owner(orca_mint_account, &SPL_TOKEN_PROGRAM_ID)?;
+
+        // 7. State Account Assertions
+        assert_account_owner(state_account, &crate::ID)?;
+        let state = assert_account_data::<State>(state_account)?;
+        State::verify_address_with_bump(state_account, &crate::ID, state.bump)
+            .map_err(|_| ErrorCode::InvalidSeeds)?;
+
+        // Verify vault address using stored vault_bump
+        State::verify_vault_address_with_bump(
+            state_account,
+            vault_account,
+            orca_mint_account,
+            state.vault_bump,
+        )
+        .map_err(|_| ErrorCode::InvalidSeeds)?;
+        let unvested_rewards_amount =
+            state.unvested_rewards_amount(get_current_unix_timestamp()?)?;
+
+        // 8. Vault Account Assertions
+        let vault_account_data = make_owner_token_account_assertions(
+            vault_account,
+            state_account,
+            orca_mint_account,
+            true,
+        )?;
+
+        // Unvested rewards sit in the vault but do not count towards the exchange rate yet
+        // Use checked math to guard against vault < escrow (should not happen, but defensive)
+        let non_escrowed_orca_amount = vault_account_data
+            .amount
+            .checked_sub(state.escrowed_orca_amount)
+            .and_then(|amount| amount.checked_sub(unvested_rewards_amount))
+            .ok_or(ErrorCode::InsufficientVaultBacking)?;
+
+        Ok(Self {
+            staker_account,
+            vault_account,
+            staker_orca_ata,
+            staker_xorca_ata,
+            xorca_mint_account,
+            state_account,
+            state_bump: state.bump,
+            staker_orca_amount: staker_orca_ata_data.amount,
+            beneficiary,
+            vault_orca_amount: vault_account_data.amount,
+            escrowed_orca_amount: state.escrowed_orca_amount,
+            non_escrowed_orca_amount,
+            xorca_supply: xorca_mint_data.supply,
+        })
+    }
+
+    /// Checks `orca_stake_amount` against the staker's balance, the minimum stake and the
+    /// deposit caps.
+    pub fn assert_stake_amount(&self, orca_stake_amount: u64) -> ProgramResult {
+        if self.staker_orca_amount < orca_stake_amount {
+            return Err(ErrorCode::InsufficientFunds.into());
+        }
+        let state = assert_account_data::<State>(self.state_account)?;
+        state.assert_stake_limits(self.vault_orca_amount, orca_stake_amount)?;
+        Ok(())
+    }
+
+    /// Moves `orca_stake_amount` into the vault, mints `xorca_to_mint` to the xORCA
+    /// destination and emits the stake event.
+    pub fn stake(&self, orca_stake_amount: u64, xorca_to_mint: u64) -> ProgramResult {
+        // Transfer Orca from staker ATA to vault
+        let transfer_instruction = Transfer {
+            from: self.staker_orca_ata,
+            to: self.vault_account,
+            authority: self.staker_account,
+            amount: orca_stake_amount,
+        };
+        transfer_instruction.invoke()?;
+
+        // Mint xOrca to the destination xOrca ATA
+        let mut state_seeds = State::seeds();
+        let bump_bytes = [self.state_bump];
+        state_seeds.push(Seed::from(&bump_bytes));
+        let mint_to_instruction = MintTo {
+            mint: self.xorca_mint_account,
+            account: self.staker_xorca_ata,
+            mint_authority: self.state_account,
+            amount: xorca_to_mint,
+        };
+        mint_to_instruction.invoke_signed(&[state_seeds.as_slice().into()])?;
+
+        let final_vault_amount = self.vault_orca_amount + orca_stake_amount;
+        let final_xorca_supply = self.xorca_supply + xorca_to_mint;
+
+        Event::Stake {
+            orca_stake_amount: &orca_stake_amount,
+            vault_orca_amount: &final_vault_amount,
+            vault_escrowed_orca_amount: &self.escrowed_orca_amount,
+            xorca_mint_supply: &final_xorca_supply,
+            xorca_to_mint: &xorca_to_mint,
+            payer: self.staker_account.key(),
+            beneficiary: &self.beneficiary,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+}
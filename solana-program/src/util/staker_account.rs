@@ -0,0 +1,48 @@
+use crate::{
+    assertions::staker_account::{assert_staker_account_mut, is_staker_account},
+    error::ErrorCode,
+    state::{staker_account::StakerAccount, ProgramAccount},
+};
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+/// Picks the index seeding a new pending withdraw of `unstaker`. With a staker account it is
+/// the account's next withdraw index, whose lowest byte must match `withdraw_index`; without
+/// one the pending withdraw is seeded by `withdraw_index` itself and `None` is returned.
+pub fn next_staker_withdraw_index(
+    staker_account: Option<&AccountInfo>,
+    unstaker: &Pubkey,
+    withdraw_index: u8,
+) -> Result<Option<u64>, ProgramError> {
+    let Some(staker_account) = staker_account else {
+        return Ok(None);
+    };
+    let staker_account_data = assert_staker_account_mut(staker_account, unstaker)?;
+    if staker_account_data.next_withdraw_index as u8 != withdraw_index {
+        return Err(ErrorCode::InvalidSeeds.into());
+    }
+    Ok(Some(staker_account_data.next_withdraw_index))
+}
+
+/// Records ORCA paid out of a staker-indexed pending withdraw on the staker account of its
+/// unstaker, found among `staker_accounts`.
+pub fn record_staker_settlement(
+    staker_accounts: &[AccountInfo],
+    unstaker: &Pubkey,
+    orca_withdrawn: u64,
+    closed: bool,
+) -> ProgramResult {
+    let staker_account = staker_accounts
+        .iter()
+        .find(|account| {
+            is_staker_account(account)
+                && account
+                    .try_borrow_data()
+                    .is_ok_and(|data| StakerAccount::from_bytes(data).staker == *unstaker)
+        })
+        .ok_or(ErrorCode::StakerAccountRequired)?;
+    let mut staker_account_data = assert_staker_account_mut(staker_account, unstaker)?;
+    staker_account_data.record_settlement(orca_withdrawn, closed)?;
+    Ok(())
+}
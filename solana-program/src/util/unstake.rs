@@ -0,0 +1,296 @@
+use crate::{
+    assertions::account::{
+        assert_account_address, assert_account_data, assert_account_data_mut, assert_account_owner,
+        assert_account_role, assert_account_seeds, assert_external_account_data,
+        make_owner_token_account_assertions, AccountRole,
+    },
+    assertions::{staker_account::assert_staker_account_mut, state::assert_not_paused},
+    cpi::{
+        system::get_current_unix_timestamp,
+        token::{TokenMint, ORCA_MINT_ID, XORCA_MINT_ID},
+    },
+    error::ErrorCode,
+    event::Event,
+    state::{
+        pending_withdraw::PendingWithdraw,
+        state::{State, PAUSE_UNSTAKE},
+    },
+    util::{
+        account::{create_program_account_secure, get_account_info, get_optional_account_info},
+        staker_account::next_staker_withdraw_index,
+    },
+};
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use pinocchio_system::ID as SYSTEM_PROGRAM_ID;
+use pinocchio_token::{instructions::Burn, ID as SPL_TOKEN_PROGRAM_ID};
+
+/// Asserted accounts of an unstake instruction together with the pool amounts the exchange
+/// rate is computed from. All unstake variants share the same account layout.
+pub struct UnstakeAccounts<'a> {
+    unstaker_account: &'a AccountInfo,
+    state_account: &'a AccountInfo,
+    pending_withdraw_account: &'a AccountInfo,
+    unstaker_xorca_ata: &'a AccountInfo,
+    xorca_mint_account: &'a AccountInfo,
+    rent_payer_account: &'a AccountInfo,
+    staker_account_pda: Option<&'a AccountInfo>,
+    withdraw_index: u8,
+    staker_withdraw_index: Option<u64>,
+    pending_withdraw_bump: u8,
+    unstaker_xorca_amount: u64,
+    vault_orca_amount: u64,
+    pub non_escrowed_orca_amount: u64,
+    pub xorca_supply: u64,
+}
+
+impl<'a> UnstakeAccounts<'a> {
+    /// Runs the account assertions shared by all unstake variants. With a staker account the
+    /// pending withdraw is seeded by its next withdraw index, whose lowest byte must match
+    /// `withdraw_index`; otherwise by `withdraw_index` itself.
+    pub fn load(accounts: &'a [AccountInfo], withdraw_index: u8) -> Result<Self, ProgramError> {
+        let unstaker_account = get_account_info(accounts, 0)?;
+        let state_account = get_account_info(accounts, 1)?;
+        let pending_withdraw_account = get_account_info(accounts, 2)?;
+        let unstaker_xorca_ata = get_account_info(accounts, 3)?;
+        let xorca_mint_account = get_account_info(accounts, 4)?;
+        let orca_mint_account = get_account_info(accounts, 5)?;
+        let vault_account = get_account_info(accounts, 6)?;
+        let system_program_account = get_account_info(accounts, 7)?;
+        let token_program_account = get_account_info(accounts, 8)?;
+        // Optional rent payer; omitted or passed as the program ID, the unstaker pays the rent
+        let rent_payer_account = get_optional_account_info(accounts, 9).unwrap_or(unstaker_account);
+        // Optional staker account; when passed it picks the pending withdraw index
+        let staker_account_pda = get_optional_account_info(accounts, 10);
+
+        // 0. Pause Assertions
+        assert_not_paused(state_account, PAUSE_UNSTAKE)?;
+
+        // 1. Unstaker Account Assertions
+        assert_account_role(
+            unstaker_account,
+            &[AccountRole::Signer, AccountRole::Writable],
+        )?;
+        assert_account_role(
+            rent_payer_account,
+            &[AccountRole::Signer, AccountRole::Writable],
+        )?;
+
+        // 2. Account Address Assertions
+        assert_account_address(xorca_mint_account, &XORCA_MINT_ID)?;
+        assert_account_address(orca_mint_account, &ORCA_MINT_ID)?;
+        assert_account_address(token_program_account, &SPL_TOKEN_PROGRAM_ID)?;
+        assert_account_address(system_program_account, &SYSTEM_PROGRAM_ID)?;
+
+        // 3. xOrca State Account Assertions
+        assert_account_role(state_account, &[AccountRole::Writable])?;
+        assert_account_owner(state_account, &crate::ID)?;
+
+        // 4. Vault Account Assertions
+        let vault_account_data = make_owner_token_account_assertions(
+            vault_account,
+            state_account,
+            orca_mint_account,
+            false,
+        )?;
+
+        // 5. Pending Withdraw Account Assertions
+        assert_account_role(pending_withdraw_account, &[AccountRole::Writable])?;
+        assert_account_owner(pending_withdraw_account, &SYSTEM_PROGRAM_ID)?;
+        let staker_withdraw_index =
+            next_staker_withdraw_index(staker_account_pda, unstaker_account.key(), withdraw_index)?;
+        let pending_withdraw_bump = {
+            let staker_withdraw_index_bytes =
+                staker_withdraw_index.unwrap_or_default().to_le_bytes();
+            let withdraw_index_bytes = [withdraw_index];
+            let pending_withdraw_seeds = PendingWithdraw::seeds(
+                unstaker_account.key(),
+                withdraw_index_seed(
+                    staker_withdraw_index,
+                    &staker_withdraw_index_bytes,
+                    &withdraw_index_bytes,
+                ),
+            );
+            assert_account_seeds(
+                pending_withdraw_account,
+                &crate::ID,
+                &pending_withdraw_seeds,
+            )?[0]
+        };
+
+        // 6. Unstaker xOrca ATA Assertions
+        let unstaker_xorca_ata_data = make_owner_token_account_assertions(
+            unstaker_xorca_ata,
+            unstaker_account,
+            xorca_mint_account,
+            true,
+        )?;
+
+        // 7. xOrca Mint Account Assertions
+        assert_account_owner(xorca_mint_account, &SPL_TOKEN_PROGRAM_ID)?;
+        assert_account_role(xorca_mint_account, &[AccountRole::Writable])?;
+        let xorca_mint_data = assert_external_account_data::<TokenMint>(xorca_mint_account)?;
+        // Enforce xORCA mint authority must be the state and freeze authority must be None
+        if xorca_mint_data.mint_authority_flag == 0
+            || xorca_mint_data.mint_authority != *state_account.key()
+        {
+            return Err(ErrorCode::InvalidAccountData.into());
+        }
+        if xorca_mint_data.freeze_authority_flag != 0 {
+            return Err(ErrorCode::InvalidAccountData.into());
+        }
+
+        // 8. Orca Mint Account Assertions
+        assert_account_owner(orca_mint_account, &SPL_TOKEN_PROGRAM_ID)?;
+        assert_external_account_data::<TokenMint>(orca_mint_account)?;
+
+        // 9. State Data Assertions
+        let state = assert_account_data::<State>(state_account)?;
+        State::verify_address_with_bump(state_account, &crate::ID, state.bump)
+            .map_err(|_| ErrorCode::InvalidSeeds)?;
+
+        // Verify vault address using stored vault_bump
+        State::verify_vault_address_with_bump(
+            state_account,
+            vault_account,
+            orca_mint_account,
+            state.vault_bump,
+        )
+        .map_err(|_| ErrorCode::InvalidSeeds)?;
+        let unvested_rewards_amount =
+            state.unvested_rewards_amount(get_current_unix_timestamp()?)?;
+
+        // Unvested rewards sit in the vault but do not count towards the exchange rate yet
+        let non_escrowed_orca_amount = vault_account_data
+            .amount
+            .checked_sub(state.escrowed_orca_amount)
+            .and_then(|amount| amount.checked_sub(unvested_rewards_amount))
+            .ok_or(ErrorCode::InsufficientVaultBacking)?;
+
+        Ok(Self {
+            unstaker_account,
+            state_account,
+            pending_withdraw_account,
+            unstaker_xorca_ata,
+            xorca_mint_account,
+            rent_payer_account,
+            staker_account_pda,
+            withdraw_index,
+            staker_withdraw_index,
+            pending_withdraw_bump,
+            unstaker_xorca_amount: unstaker_xorca_ata_data.amount,
+            vault_orca_amount: vault_account_data.amount,
+            non_escrowed_orca_amount,
+            xorca_supply: xorca_mint_data.supply,
+        })
+    }
+
+    /// Checks `xorca_unstake_amount` against the unstaker's balance and the minimum unstake.
+    pub fn assert_unstake_amount(&self, xorca_unstake_amount: u64) -> ProgramResult {
+        if self.unstaker_xorca_amount < xorca_unstake_amount {
+            return Err(ErrorCode::InsufficientFunds.into());
+        }
+        let state = assert_account_data::<State>(self.state_account)?;
+        if xorca_unstake_amount < state.min_unstake_xorca {
+            return Err(ErrorCode::UnstakeAmountBelowMinimum.into());
+        }
+        Ok(())
+    }
+
+    /// Burns `xorca_unstake_amount`, moves `withdrawable_orca_amount` into escrow, creates the
+    /// pending withdraw and emits the unstake event.
+    pub fn unstake(
+        &self,
+        xorca_unstake_amount: u64,
+        withdrawable_orca_amount: u64,
+    ) -> ProgramResult {
+        // Burn unstaker's LST tokens
+        let burn_instruction = Burn {
+            mint: self.xorca_mint_account,
+            account: self.unstaker_xorca_ata,
+            authority: self.unstaker_account,
+            amount: xorca_unstake_amount,
+        };
+        burn_instruction.invoke()?;
+
+        // Add the unstake ORCA amount to escrowed ORCA amount
+        let mut state = assert_account_data_mut::<State>(self.state_account)?;
+        state.escrowed_orca_amount = state
+            .escrowed_orca_amount
+            .checked_add(withdrawable_orca_amount)
+            .ok_or(ErrorCode::ArithmeticError)?;
+
+        // Take the withdraw index and add the unstake to the staker account totals
+        if let Some(staker_account_pda) = self.staker_account_pda {
+            let mut staker_account_data =
+                assert_staker_account_mut(staker_account_pda, self.unstaker_account.key())?;
+            staker_account_data.record_unstake(xorca_unstake_amount, withdrawable_orca_amount)?;
+        }
+
+        // Create new pending withdraw account funded by the rent payer (secure against DoS attacks)
+        let staker_withdraw_index_bytes =
+            self.staker_withdraw_index.unwrap_or_default().to_le_bytes();
+        let withdraw_index_bytes = [self.withdraw_index];
+        let mut pending_withdraw_seeds = PendingWithdraw::seeds(
+            self.unstaker_account.key(),
+            withdraw_index_seed(
+                self.staker_withdraw_index,
+                &staker_withdraw_index_bytes,
+                &withdraw_index_bytes,
+            ),
+        );
+        let bump_bytes = [self.pending_withdraw_bump];
+        pending_withdraw_seeds.push(Seed::from(&bump_bytes));
+        let mut pending_withdraw_data = create_program_account_secure::<PendingWithdraw>(
+            self.rent_payer_account,
+            self.pending_withdraw_account,
+            &[pending_withdraw_seeds.as_slice().into()],
+        )?;
+
+        // Populate pending withdraw account data
+        pending_withdraw_data.bump = self.pending_withdraw_bump;
+        pending_withdraw_data.withdraw_index = self.withdraw_index;
+        if let Some(staker_withdraw_index) = self.staker_withdraw_index {
+            pending_withdraw_data.staker_indexed = 1;
+            pending_withdraw_data.staker_withdraw_index = staker_withdraw_index;
+        }
+        pending_withdraw_data.unstaker = *self.unstaker_account.key();
+        pending_withdraw_data.owner = *self.unstaker_account.key();
+        pending_withdraw_data.withdrawable_orca_amount = withdrawable_orca_amount;
+        let current_unix_timestamp = get_current_unix_timestamp()?;
+        let withdrawable_timestamp = current_unix_timestamp
+            .checked_add(state.cool_down_period_s)
+            .ok_or(ErrorCode::CoolDownOverflow)?;
+        pending_withdraw_data.withdrawable_timestamp = withdrawable_timestamp;
+
+        let final_xorca_supply = self.xorca_supply - xorca_unstake_amount;
+
+        Event::Unstake {
+            xorca_unstake_amount: &xorca_unstake_amount,
+            vault_orca_amount: &self.vault_orca_amount,
+            vault_escrowed_orca_amount: &state.escrowed_orca_amount,
+            xorca_mint_supply: &final_xorca_supply,
+            withdrawable_orca_amount: &withdrawable_orca_amount,
+            cool_down_period_s: &state.cool_down_period_s,
+            withdraw_index: &self.withdraw_index,
+            staker_withdraw_index: &self.staker_withdraw_index,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+}
+
+/// Staker-indexed pending withdraws are seeded by the 8-byte staker withdraw index, the others
+/// by the one-byte withdraw index.
+fn withdraw_index_seed<'b>(
+    staker_withdraw_index: Option<u64>,
+    staker_withdraw_index_bytes: &'b [u8; 8],
+    withdraw_index_bytes: &'b [u8; 1],
+) -> &'b [u8] {
+    match staker_withdraw_index {
+        Some(_) => staker_withdraw_index_bytes,
+        None => withdraw_index_bytes,
+    }
+}
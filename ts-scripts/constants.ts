@@ -38,12 +38,9 @@ export const TOKEN_PROGRAM_ID = new PublicKey('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9
 export const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey(
   'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
 );
-
-// ============================================================================
-// AUTHORIZATION
-// ============================================================================
-
-export const DEPLOYER_ADDRESS = new PublicKey('94kZD71sbTKhqhcvY9D9Ra5BsLzKRZgznbBbQpBWmKrT');
+export const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey(
+  'BPFLoaderUpgradeab1e11111111111111111111111'
+);
 
 // ============================================================================
 // PROGRAM SEEDS
@@ -12,11 +12,11 @@ import {
   XORCA_STAKING_PROGRAM_ID,
   ORCA_MINT_ADDRESS,
   XORCA_MINT_ADDRESS,
-  DEPLOYER_ADDRESS,
   RPC_URL,
   TOKEN_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
   SYSTEM_PROGRAM_ID,
+  BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
 } from './constants';
 
 // Parse command line arguments
@@ -59,10 +59,24 @@ async function main() {
     console.log(`Deployer address: ${deployerAddress}`);
     console.log(`Update authority address: ${updateAuthorityAddress}`);
 
-    // Verify deployer address matches the expected deployer
-    if (!deployerAddress.equals(DEPLOYER_ADDRESS)) {
+    // Verify the deployer is the program's upgrade authority, which initialize requires
+    const [programDataAddress] = PublicKey.findProgramAddressSync(
+      [XORCA_STAKING_PROGRAM_ID.toBuffer()],
+      BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+    );
+    const programDataAccount = await connection.getAccountInfo(programDataAddress);
+    if (!programDataAccount) {
+      console.error(`❌ Program data account ${programDataAddress} not found`);
+      process.exit(1);
+    }
+    // ProgramData layout: u32 account type, u64 slot, u8 authority flag, 32-byte authority
+    const hasUpgradeAuthority = programDataAccount.data[12] === 1;
+    const upgradeAuthority = new PublicKey(programDataAccount.data.subarray(13, 45));
+    if (!hasUpgradeAuthority || !deployerAddress.equals(upgradeAuthority)) {
       console.error(
-        `❌ Deployer address ${deployerAddress} does not match expected deployer ${DEPLOYER_ADDRESS}`
+        `❌ Deployer address ${deployerAddress} is not the program upgrade authority ${
+          hasUpgradeAuthority ? upgradeAuthority : '(none, program is immutable)'
+        }`
       );
       process.exit(1);
     }
@@ -83,6 +97,7 @@ async function main() {
     console.log(`Vault: ${vaultAddress}`);
     console.log(`xORCA Mint: ${XORCA_MINT_ADDRESS}`);
     console.log(`ORCA Mint: ${ORCA_MINT_ADDRESS}`);
+    console.log(`Program Data: ${programDataAddress}`);
 
     // Create initialize instruction data
     const instructionData = Buffer.concat([
@@ -108,6 +123,7 @@ async function main() {
         { pubkey: SYSTEM_PROGRAM_ID, isSigner: false, isWritable: false }, // system_program_account
         { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false }, // token_program_account
         { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false }, // associated_token_program_account
+        { pubkey: programDataAddress, isSigner: false, isWritable: false }, // program_data_account
       ],
       data: instructionData,
     };
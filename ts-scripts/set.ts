@@ -17,12 +17,14 @@ if (args.length < 3) {
   console.error('Usage: tsx set.ts <payer-keypair-path> <operation> <value>');
   console.error('Operations:');
   console.error('  update-cooldown <seconds>     - Update the cooldown period in seconds');
-  console.error('  update-authority <address>    - Update the update authority address');
+  console.error(
+    '  propose-authority <address>   - Propose a new update authority (it must accept to take over)'
+  );
   console.error('');
   console.error('Examples:');
   console.error('  tsx set.ts keypairs/deployer.json update-cooldown 3600');
   console.error(
-    '  tsx set.ts keypairs/deployer.json update-authority BQGjVjG8ZJW4m4hXybjLRB367idYyAHWbyjPBeL2w1hq'
+    '  tsx set.ts keypairs/deployer.json propose-authority BQGjVjG8ZJW4m4hXybjLRB367idYyAHWbyjPBeL2w1hq'
   );
   process.exit(1);
 }
@@ -84,7 +86,7 @@ async function main() {
       instructionData.writeBigInt64LE(BigInt(cooldownSeconds), 2); // new_cool_down_period_s as little-endian i64
 
       console.log(`Setting cooldown period to: ${cooldownSeconds} seconds`);
-    } else if (operation === 'update-authority') {
+    } else if (operation === 'propose-authority') {
       let newAuthority: PublicKey;
       try {
         newAuthority = new PublicKey(value);
@@ -93,18 +95,18 @@ async function main() {
         process.exit(1);
       }
 
-      // Create instruction data for UpdateUpdateAuthority
+      // Create instruction data for ProposeUpdateAuthority
       // Discriminator for Set instruction: 4 (based on the enum order: Stake=0, Unstake=1, Withdraw=2, Initialize=3, Set=4)
-      // Discriminator for UpdateUpdateAuthority: 1 (second variant of StateUpdateInstruction)
+      // Discriminator for ProposeUpdateAuthority: 4 (fifth variant of StateUpdateInstruction)
       instructionData = Buffer.alloc(34); // 1 byte for Set discriminator + 1 byte for StateUpdateInstruction discriminator + 32 bytes for Pubkey
       instructionData.writeUInt8(4, 0); // Set instruction discriminator
-      instructionData.writeUInt8(1, 1); // UpdateUpdateAuthority discriminator
+      instructionData.writeUInt8(4, 1); // ProposeUpdateAuthority discriminator
       instructionData.set(newAuthority.toBytes(), 2); // new_authority as 32-byte public key
 
-      console.log(`Setting new authority to: ${newAuthority.toString()}`);
+      console.log(`Proposing new authority: ${newAuthority.toString()}`);
     } else {
       console.error(
-        '❌ Invalid operation! Must be either "update-cooldown" or "update-authority".'
+        '❌ Invalid operation! Must be either "update-cooldown" or "propose-authority".'
       );
       process.exit(1);
     }
@@ -144,8 +146,8 @@ async function main() {
     // Display what was updated
     if (operation === 'update-cooldown') {
       console.log(`✅ Cooldown period updated to: ${value} seconds`);
-    } else if (operation === 'update-authority') {
-      console.log(`✅ Update authority updated to: ${value}`);
+    } else if (operation === 'propose-authority') {
+      console.log(`✅ Update authority proposed: ${value} (must sign AcceptUpdateAuthority)`);
     }
   } catch (error) {
     console.error('❌ Error executing set instruction:', error);